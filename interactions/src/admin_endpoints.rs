@@ -1,5 +1,11 @@
-use crate::utils::get_function_hash;
-use anchor_client::{anchor_lang::AnchorSerialize, solana_sdk::signature::Signer};
+use crate::utils::{
+    get_function_hash, get_migration_accounts, get_multisig_accounts, get_queued_change_account,
+    get_token_config_account, get_token_program,
+};
+use anchor_client::{
+    anchor_lang::{AnchorDeserialize, AnchorSerialize},
+    solana_sdk::signature::Signer,
+};
 
 use solana_client::rpc_config::RpcSendTransactionConfig;
 
@@ -13,15 +19,22 @@ use {
 use solana_sdk::instruction::AccountMeta;
 
 use anchor_client::anchor_lang::system_program;
-use solana_program::pubkey::Pubkey;
-use spl_associated_token_account::get_associated_token_address;
-
-use bridge_program::instruction as bridge_program_instructions;
+use solana_program::{bpf_loader_upgradeable, pubkey::Pubkey};
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+
+use bridge_program::{
+    instruction as bridge_program_instructions,
+    states::{
+        bridge::{Role, State},
+        BridgeState, ConfigChange, Multisig, ProposalAction, RelayerCommittee,
+    },
+};
 
 pub async fn process_initialize_contract(
     rpc_client: &RpcClient,
     signer: &dyn Signer,
     program_id: Pubkey,
+    admin: Pubkey,
     relayer_pubkey: Pubkey,
     fee_collector: Pubkey,
     mint_of_token_whitelisted: Pubkey,
@@ -31,11 +44,28 @@ pub async fn process_initialize_contract(
 ) -> Result<Signature, Box<dyn std::error::Error>> {
     let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
 
-    let vault_ata = get_associated_token_address(&bridge_pda, &mint_of_token_whitelisted);
+    let token_program = get_token_program(rpc_client, &mint_of_token_whitelisted).await?;
+
+    let vault_ata = get_associated_token_address_with_program_id(
+        &bridge_pda,
+        &mint_of_token_whitelisted,
+        &token_program,
+    );
+
+    let (token_config_pda, _) = Pubkey::find_program_address(
+        &[b"token_config", mint_of_token_whitelisted.as_ref()],
+        &program_id,
+    );
+
+    let (fee_vault_pda, _) = Pubkey::find_program_address(&[b"fee_vault"], &program_id);
+
+    let (program_data, _) =
+        Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::ID);
 
     let method = get_function_hash("global", "initialize_contract");
 
     let init_another = bridge_program_instructions::InitializeContract {
+        admin,
         fee_collector,
         fee_amount,
         relayer_pubkey,
@@ -52,11 +82,15 @@ pub async fn process_initialize_contract(
         &method_bytes,
         vec![
             AccountMeta::new(bridge_pda, false),
+            AccountMeta::new(token_config_pda, false),
             AccountMeta::new(vault_ata, false),
+            AccountMeta::new(fee_vault_pda, false),
             AccountMeta::new_readonly(mint_of_token_whitelisted, false),
-            AccountMeta::new_readonly(signer.pubkey(), true),
+            AccountMeta::new(signer.pubkey(), true),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(program_data, false),
             AccountMeta::new_readonly(system_program::ID, false),
-            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(token_program, false),
             AccountMeta::new_readonly(spl_associated_token_account::ID, false),
         ],
     );
@@ -84,28 +118,53 @@ pub async fn process_initialize_contract(
     Ok(signature)
 }
 
-pub async fn process_update_relayer(
+pub async fn process_migrate_bridge_state(
     rpc_client: &RpcClient,
     signer: &dyn Signer,
     program_id: Pubkey,
-    relayer_pubkey: Pubkey,
+    admin: Pubkey,
+    mint_of_token_whitelisted: Pubkey,
 ) -> Result<Signature, Box<dyn std::error::Error>> {
     let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
 
-    let method = get_function_hash("global", "update_relayer");
+    let token_program = get_token_program(rpc_client, &mint_of_token_whitelisted).await?;
 
-    let update_relayer = bridge_program_instructions::UpdateRelayer { relayer_pubkey };
+    let vault_ata = get_associated_token_address_with_program_id(
+        &bridge_pda,
+        &mint_of_token_whitelisted,
+        &token_program,
+    );
+
+    let (token_config_pda, _) = Pubkey::find_program_address(
+        &[b"token_config", mint_of_token_whitelisted.as_ref()],
+        &program_id,
+    );
+
+    let (fee_vault_pda, _) = Pubkey::find_program_address(&[b"fee_vault"], &program_id);
+
+    let (program_data, _) =
+        Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::ID);
+
+    let method = get_function_hash("global", "migrate_bridge_state");
+
+    let migrate_bridge_state = bridge_program_instructions::MigrateBridgeState { admin };
 
     let mut method_bytes = method.to_vec();
 
-    method_bytes.append(&mut update_relayer.try_to_vec()?);
+    method_bytes.append(&mut migrate_bridge_state.try_to_vec()?);
 
     let ix = Instruction::new_with_bytes(
         program_id,
         &method_bytes,
         vec![
             AccountMeta::new(bridge_pda, false),
-            AccountMeta::new_readonly(signer.pubkey(), true),
+            AccountMeta::new(token_config_pda, false),
+            AccountMeta::new_readonly(vault_ata, false),
+            AccountMeta::new_readonly(mint_of_token_whitelisted, false),
+            AccountMeta::new(fee_vault_pda, false),
+            AccountMeta::new(signer.pubkey(), true),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(program_data, false),
             AccountMeta::new_readonly(system_program::ID, false),
         ],
     );
@@ -133,34 +192,29 @@ pub async fn process_update_relayer(
     Ok(signature)
 }
 
-pub async fn process_update_whitelisted_mint(
+pub async fn process_propose_admin(
     rpc_client: &RpcClient,
     signer: &dyn Signer,
     program_id: Pubkey,
-    mint_of_token_whitelisted: Pubkey,
+    pending_admin: Pubkey,
 ) -> Result<Signature, Box<dyn std::error::Error>> {
     let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
-    let vault_ata = get_associated_token_address(&bridge_pda, &mint_of_token_whitelisted);
 
-    let method = get_function_hash("global", "update_whitelisted_mint");
+    let method = get_function_hash("global", "propose_admin");
 
-    let update_whitelisted_mint = bridge_program_instructions::UpdateWhitelistedMint {};
+    let propose_admin = bridge_program_instructions::ProposeAdmin { pending_admin };
 
     let mut method_bytes = method.to_vec();
 
-    method_bytes.append(&mut update_whitelisted_mint.try_to_vec()?);
+    method_bytes.append(&mut propose_admin.try_to_vec()?);
 
     let ix = Instruction::new_with_bytes(
         program_id,
         &method_bytes,
         vec![
             AccountMeta::new(bridge_pda, false),
-            AccountMeta::new(vault_ata, false),
-            AccountMeta::new_readonly(mint_of_token_whitelisted, false),
             AccountMeta::new_readonly(signer.pubkey(), true),
             AccountMeta::new_readonly(system_program::ID, false),
-            AccountMeta::new_readonly(spl_token::ID, false),
-            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
         ],
     );
 
@@ -187,39 +241,28 @@ pub async fn process_update_whitelisted_mint(
     Ok(signature)
 }
 
-pub async fn process_add_liquidity(
+pub async fn process_accept_admin(
     rpc_client: &RpcClient,
     signer: &dyn Signer,
     program_id: Pubkey,
-    amount: u64,
-    mint_of_token_sent: Pubkey,
 ) -> Result<Signature, Box<dyn std::error::Error>> {
     let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
 
-    let vault_ata = get_associated_token_address(&bridge_pda, &mint_of_token_sent);
-
-    let signer_ata = get_associated_token_address(&signer.pubkey(), &mint_of_token_sent);
-
-    let method = get_function_hash("global", "add_liquidity");
+    let method = get_function_hash("global", "accept_admin");
 
-    let add_liquidity = bridge_program_instructions::AddLiquidity { amount };
+    let accept_admin = bridge_program_instructions::AcceptAdmin {};
 
     let mut method_bytes = method.to_vec();
 
-    method_bytes.append(&mut add_liquidity.try_to_vec()?);
+    method_bytes.append(&mut accept_admin.try_to_vec()?);
 
     let ix = Instruction::new_with_bytes(
         program_id,
         &method_bytes,
         vec![
             AccountMeta::new(bridge_pda, false),
-            AccountMeta::new(vault_ata, false),
             AccountMeta::new_readonly(signer.pubkey(), true),
-            AccountMeta::new_readonly(mint_of_token_sent, false),
-            AccountMeta::new(signer_ata, false),
             AccountMeta::new_readonly(system_program::ID, false),
-            AccountMeta::new_readonly(spl_token::ID, false),
-            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
         ],
     );
 
@@ -246,41 +289,39 @@ pub async fn process_add_liquidity(
     Ok(signature)
 }
 
-pub async fn process_remove_liquidity(
+pub async fn process_update_relayer(
     rpc_client: &RpcClient,
     signer: &dyn Signer,
     program_id: Pubkey,
-    amount: u64,
-    mint_of_token_sent: Pubkey,
+    relayer_pubkey: Pubkey,
+    proposal_id: Option<u64>,
 ) -> Result<Signature, Box<dyn std::error::Error>> {
     let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
 
-    let vault_ata = get_associated_token_address(&bridge_pda, &mint_of_token_sent);
-
-    let signer_ata = get_associated_token_address(&signer.pubkey(), &mint_of_token_sent);
-
-    let method = get_function_hash("global", "remove_liquidity");
+    let method = get_function_hash("global", "update_relayer");
 
-    let remove_liquidity = bridge_program_instructions::RemoveLiquidity { amount };
+    let update_relayer = bridge_program_instructions::UpdateRelayer { relayer_pubkey };
 
     let mut method_bytes = method.to_vec();
 
-    method_bytes.append(&mut remove_liquidity.try_to_vec()?);
+    method_bytes.append(&mut update_relayer.try_to_vec()?);
 
-    let ix = Instruction::new_with_bytes(
-        program_id,
-        &method_bytes,
-        vec![
-            AccountMeta::new(bridge_pda, false),
-            AccountMeta::new(vault_ata, false),
-            AccountMeta::new_readonly(signer.pubkey(), true),
-            AccountMeta::new_readonly(mint_of_token_sent, false),
-            AccountMeta::new(signer_ata, false),
-            AccountMeta::new_readonly(system_program::ID, false),
-            AccountMeta::new_readonly(spl_token::ID, false),
-            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
-        ],
+    let mut accounts = vec![
+        AccountMeta::new(bridge_pda, false),
+        AccountMeta::new_readonly(signer.pubkey(), true),
+    ];
+    accounts.extend(get_multisig_accounts(&program_id, proposal_id));
+    accounts.push(
+        get_queued_change_account(
+            rpc_client,
+            &program_id,
+            &ConfigChange::Relayer { relayer_pubkey },
+        )
+        .await,
     );
+    accounts.push(AccountMeta::new_readonly(system_program::ID, false));
+
+    let ix = Instruction::new_with_bytes(program_id, &method_bytes, accounts);
 
     let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(&signer.pubkey())));
 
@@ -305,35 +346,50 @@ pub async fn process_remove_liquidity(
     Ok(signature)
 }
 
-pub async fn process_set_deposit_limits(
+pub async fn process_set_relayer_committee(
     rpc_client: &RpcClient,
     signer: &dyn Signer,
     program_id: Pubkey,
-    minimum_deposit: u64,
-    maximum_deposit: u64,
+    relayers: Vec<Pubkey>,
+    threshold: u8,
+    proposal_id: Option<u64>,
 ) -> Result<Signature, Box<dyn std::error::Error>> {
     let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
 
-    let method = get_function_hash("global", "set_deposit_limits");
+    let (relayer_committee_pda, _) =
+        Pubkey::find_program_address(&[b"relayer_committee"], &program_id);
 
-    let set_deposit_limits = bridge_program_instructions::SetDepositLimits {
-        minimum_deposit,
-        maximum_deposit,
+    let method = get_function_hash("global", "set_relayer_committee");
+
+    let set_relayer_committee = bridge_program_instructions::SetRelayerCommittee {
+        relayers: relayers.clone(),
+        threshold,
     };
 
     let mut method_bytes = method.to_vec();
 
-    method_bytes.append(&mut set_deposit_limits.try_to_vec()?);
-
-    let ix = Instruction::new_with_bytes(
-        program_id,
-        &method_bytes,
-        vec![
-            AccountMeta::new(bridge_pda, false),
-            AccountMeta::new_readonly(signer.pubkey(), true),
-            AccountMeta::new_readonly(system_program::ID, false),
-        ],
+    method_bytes.append(&mut set_relayer_committee.try_to_vec()?);
+
+    let mut accounts = vec![
+        AccountMeta::new(bridge_pda, false),
+        AccountMeta::new(relayer_committee_pda, false),
+        AccountMeta::new(signer.pubkey(), true),
+    ];
+    accounts.extend(get_multisig_accounts(&program_id, proposal_id));
+    accounts.push(
+        get_queued_change_account(
+            rpc_client,
+            &program_id,
+            &ConfigChange::RelayerCommittee {
+                relayers_hash: RelayerCommittee::relayers_hash(&relayers),
+                threshold,
+            },
+        )
+        .await,
     );
+    accounts.push(AccountMeta::new_readonly(system_program::ID, false));
+
+    let ix = Instruction::new_with_bytes(program_id, &method_bytes, accounts);
 
     let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(&signer.pubkey())));
 
@@ -358,31 +414,78 @@ pub async fn process_set_deposit_limits(
     Ok(signature)
 }
 
-pub async fn process_set_fee_amount(
+pub async fn process_update_whitelisted_mint(
     rpc_client: &RpcClient,
     signer: &dyn Signer,
     program_id: Pubkey,
-    fee_amount: u64,
+    mint_of_token_whitelisted: Pubkey,
+    proposal_id: Option<u64>,
+    previous_mint: Option<Pubkey>,
+    sweep_token_account: Option<Pubkey>,
 ) -> Result<Signature, Box<dyn std::error::Error>> {
     let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
+    let token_program = get_token_program(rpc_client, &mint_of_token_whitelisted).await?;
 
-    let method = get_function_hash("global", "set_fee_amount");
+    let vault_ata = get_associated_token_address_with_program_id(
+        &bridge_pda,
+        &mint_of_token_whitelisted,
+        &token_program,
+    );
 
-    let set_fee_amount = bridge_program_instructions::SetFeeAmount { fee_amount };
+    let (token_config_pda, _) = Pubkey::find_program_address(
+        &[b"token_config", mint_of_token_whitelisted.as_ref()],
+        &program_id,
+    );
+
+    let method = get_function_hash("global", "update_whitelisted_mint");
+
+    let update_whitelisted_mint = bridge_program_instructions::UpdateWhitelistedMint {};
 
     let mut method_bytes = method.to_vec();
 
-    method_bytes.append(&mut set_fee_amount.try_to_vec()?);
+    method_bytes.append(&mut update_whitelisted_mint.try_to_vec()?);
 
-    let ix = Instruction::new_with_bytes(
-        program_id,
-        &method_bytes,
-        vec![
-            AccountMeta::new(bridge_pda, false),
-            AccountMeta::new_readonly(signer.pubkey(), true),
-            AccountMeta::new_readonly(system_program::ID, false),
-        ],
+    let mut accounts = vec![
+        AccountMeta::new_readonly(bridge_pda, false),
+        AccountMeta::new(token_config_pda, false),
+        AccountMeta::new(vault_ata, false),
+        AccountMeta::new_readonly(mint_of_token_whitelisted, false),
+        AccountMeta::new_readonly(signer.pubkey(), true),
+    ];
+    accounts.extend(get_multisig_accounts(&program_id, proposal_id));
+    accounts.push(
+        get_queued_change_account(
+            rpc_client,
+            &program_id,
+            &ConfigChange::WhitelistedMint {
+                mint_of_token_whitelisted,
+                vault: vault_ata,
+                previous_mint: previous_mint.unwrap_or_default(),
+                sweep_token_account: sweep_token_account.unwrap_or_default(),
+            },
+        )
+        .await,
     );
+    let previous_mint = match previous_mint {
+        Some(previous_mint) => Some((
+            previous_mint,
+            get_token_program(rpc_client, &previous_mint).await?,
+        )),
+        None => None,
+    };
+    accounts.extend(get_migration_accounts(
+        &program_id,
+        &token_program,
+        previous_mint,
+        sweep_token_account,
+    ));
+    accounts.extend([
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(token_program, false),
+        AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+    ]);
+
+    let ix = Instruction::new_with_bytes(program_id, &method_bytes, accounts);
 
     let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(&signer.pubkey())));
 
@@ -407,28 +510,53 @@ pub async fn process_set_fee_amount(
     Ok(signature)
 }
 
-pub async fn process_public_pause_contract(
+pub async fn process_add_liquidity(
     rpc_client: &RpcClient,
     signer: &dyn Signer,
     program_id: Pubkey,
+    amount: u64,
+    mint_of_token_sent: Pubkey,
 ) -> Result<Signature, Box<dyn std::error::Error>> {
     let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
 
-    let method = get_function_hash("global", "public_pause");
+    let token_program = get_token_program(rpc_client, &mint_of_token_sent).await?;
 
-    let pause_contract = bridge_program_instructions::PublicPause {};
+    let vault_ata = get_associated_token_address_with_program_id(
+        &bridge_pda,
+        &mint_of_token_sent,
+        &token_program,
+    );
+
+    let (token_config_pda, _) =
+        Pubkey::find_program_address(&[b"token_config", mint_of_token_sent.as_ref()], &program_id);
+
+    let signer_ata = get_associated_token_address_with_program_id(
+        &signer.pubkey(),
+        &mint_of_token_sent,
+        &token_program,
+    );
+
+    let method = get_function_hash("global", "add_liquidity");
+
+    let add_liquidity = bridge_program_instructions::AddLiquidity { amount };
 
     let mut method_bytes = method.to_vec();
 
-    method_bytes.append(&mut pause_contract.try_to_vec()?);
+    method_bytes.append(&mut add_liquidity.try_to_vec()?);
 
     let ix = Instruction::new_with_bytes(
         program_id,
         &method_bytes,
         vec![
-            AccountMeta::new(bridge_pda, false),
+            AccountMeta::new_readonly(bridge_pda, false),
+            AccountMeta::new(token_config_pda, false),
+            AccountMeta::new(vault_ata, false),
             AccountMeta::new_readonly(signer.pubkey(), true),
+            AccountMeta::new_readonly(mint_of_token_sent, false),
+            AccountMeta::new(signer_ata, false),
             AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(token_program, false),
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
         ],
     );
 
@@ -455,30 +583,57 @@ pub async fn process_public_pause_contract(
     Ok(signature)
 }
 
-pub async fn process_relayer_pause(
+pub async fn process_remove_liquidity(
     rpc_client: &RpcClient,
     signer: &dyn Signer,
     program_id: Pubkey,
+    amount: u64,
+    mint_of_token_sent: Pubkey,
+    proposal_id: Option<u64>,
 ) -> Result<Signature, Box<dyn std::error::Error>> {
     let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
 
-    let method = get_function_hash("global", "relayer_pause");
+    let token_program = get_token_program(rpc_client, &mint_of_token_sent).await?;
 
-    let pause_contract = bridge_program_instructions::RelayerPause {};
+    let vault_ata = get_associated_token_address_with_program_id(
+        &bridge_pda,
+        &mint_of_token_sent,
+        &token_program,
+    );
+
+    let (token_config_pda, _) =
+        Pubkey::find_program_address(&[b"token_config", mint_of_token_sent.as_ref()], &program_id);
+
+    let signer_ata = get_associated_token_address_with_program_id(
+        &signer.pubkey(),
+        &mint_of_token_sent,
+        &token_program,
+    );
+
+    let method = get_function_hash("global", "remove_liquidity");
+
+    let remove_liquidity = bridge_program_instructions::RemoveLiquidity { amount };
 
     let mut method_bytes = method.to_vec();
 
-    method_bytes.append(&mut pause_contract.try_to_vec()?);
+    method_bytes.append(&mut remove_liquidity.try_to_vec()?);
 
-    let ix = Instruction::new_with_bytes(
-        program_id,
-        &method_bytes,
-        vec![
-            AccountMeta::new(bridge_pda, false),
-            AccountMeta::new_readonly(signer.pubkey(), true),
-            AccountMeta::new_readonly(system_program::ID, false),
-        ],
-    );
+    let mut accounts = vec![
+        AccountMeta::new_readonly(bridge_pda, false),
+        AccountMeta::new(token_config_pda, false),
+        AccountMeta::new(vault_ata, false),
+        AccountMeta::new_readonly(signer.pubkey(), true),
+        AccountMeta::new_readonly(mint_of_token_sent, false),
+        AccountMeta::new(signer_ata, false),
+    ];
+    accounts.extend(get_multisig_accounts(&program_id, proposal_id));
+    accounts.extend([
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(token_program, false),
+        AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+    ]);
+
+    let ix = Instruction::new_with_bytes(program_id, &method_bytes, accounts);
 
     let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(&signer.pubkey())));
 
@@ -503,27 +658,47 @@ pub async fn process_relayer_pause(
     Ok(signature)
 }
 
-pub async fn process_relayer_unpause(
+pub async fn process_set_deposit_limits(
     rpc_client: &RpcClient,
     signer: &dyn Signer,
     program_id: Pubkey,
+    mint: Pubkey,
+    minimum_deposit: u64,
+    maximum_deposit: u64,
 ) -> Result<Signature, Box<dyn std::error::Error>> {
     let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
 
-    let method = get_function_hash("global", "relayer_unpause");
+    let (token_config_pda, _) =
+        Pubkey::find_program_address(&[b"token_config", mint.as_ref()], &program_id);
 
-    let unpause_contract = bridge_program_instructions::RelayerUnpause {};
+    let method = get_function_hash("global", "set_deposit_limits");
+
+    let set_deposit_limits = bridge_program_instructions::SetDepositLimits {
+        minimum_deposit,
+        maximum_deposit,
+    };
 
     let mut method_bytes = method.to_vec();
 
-    method_bytes.append(&mut unpause_contract.try_to_vec()?);
+    method_bytes.append(&mut set_deposit_limits.try_to_vec()?);
 
     let ix = Instruction::new_with_bytes(
         program_id,
         &method_bytes,
         vec![
-            AccountMeta::new(bridge_pda, false),
+            AccountMeta::new_readonly(bridge_pda, false),
+            AccountMeta::new(token_config_pda, false),
             AccountMeta::new_readonly(signer.pubkey(), true),
+            get_queued_change_account(
+                rpc_client,
+                &program_id,
+                &ConfigChange::DepositLimits {
+                    mint,
+                    minimum_deposit,
+                    maximum_deposit,
+                },
+            )
+            .await,
             AccountMeta::new_readonly(system_program::ID, false),
         ],
     );
@@ -551,27 +726,39 @@ pub async fn process_relayer_unpause(
     Ok(signature)
 }
 
-pub async fn process_public_unpause_contract(
+pub async fn process_set_fee_amount(
     rpc_client: &RpcClient,
     signer: &dyn Signer,
     program_id: Pubkey,
+    mint: Pubkey,
+    fee_amount: u64,
 ) -> Result<Signature, Box<dyn std::error::Error>> {
     let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
 
-    let method = get_function_hash("global", "public_unpause");
+    let (token_config_pda, _) =
+        Pubkey::find_program_address(&[b"token_config", mint.as_ref()], &program_id);
 
-    let unpause_contract = bridge_program_instructions::PublicUnpause {};
+    let method = get_function_hash("global", "set_fee_amount");
+
+    let set_fee_amount = bridge_program_instructions::SetFeeAmount { fee_amount };
 
     let mut method_bytes = method.to_vec();
 
-    method_bytes.append(&mut unpause_contract.try_to_vec()?);
+    method_bytes.append(&mut set_fee_amount.try_to_vec()?);
 
     let ix = Instruction::new_with_bytes(
         program_id,
         &method_bytes,
         vec![
-            AccountMeta::new(bridge_pda, false),
+            AccountMeta::new_readonly(bridge_pda, false),
+            AccountMeta::new(token_config_pda, false),
             AccountMeta::new_readonly(signer.pubkey(), true),
+            get_queued_change_account(
+                rpc_client,
+                &program_id,
+                &ConfigChange::FeeAmount { mint, fee_amount },
+            )
+            .await,
             AccountMeta::new_readonly(system_program::ID, false),
         ],
     );
@@ -599,27 +786,39 @@ pub async fn process_public_unpause_contract(
     Ok(signature)
 }
 
-pub async fn process_set_whitelist_active(
+pub async fn process_set_vault_cap(
     rpc_client: &RpcClient,
     signer: &dyn Signer,
     program_id: Pubkey,
+    mint: Pubkey,
+    vault_cap: u64,
 ) -> Result<Signature, Box<dyn std::error::Error>> {
     let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
 
-    let method = get_function_hash("global", "set_whitelist_active");
+    let (token_config_pda, _) =
+        Pubkey::find_program_address(&[b"token_config", mint.as_ref()], &program_id);
 
-    let set_whitelist_active = bridge_program_instructions::SetWhitelistActive {};
+    let method = get_function_hash("global", "set_vault_cap");
+
+    let set_vault_cap = bridge_program_instructions::SetVaultCap { vault_cap };
 
     let mut method_bytes = method.to_vec();
 
-    method_bytes.append(&mut set_whitelist_active.try_to_vec()?);
+    method_bytes.append(&mut set_vault_cap.try_to_vec()?);
 
     let ix = Instruction::new_with_bytes(
         program_id,
         &method_bytes,
         vec![
-            AccountMeta::new(bridge_pda, false),
+            AccountMeta::new_readonly(bridge_pda, false),
+            AccountMeta::new(token_config_pda, false),
             AccountMeta::new_readonly(signer.pubkey(), true),
+            get_queued_change_account(
+                rpc_client,
+                &program_id,
+                &ConfigChange::VaultCap { mint, vault_cap },
+            )
+            .await,
             AccountMeta::new_readonly(system_program::ID, false),
         ],
     );
@@ -647,27 +846,50 @@ pub async fn process_set_whitelist_active(
     Ok(signature)
 }
 
-pub async fn process_set_whitelist_inactive(
+pub async fn process_set_bps_fee(
     rpc_client: &RpcClient,
     signer: &dyn Signer,
     program_id: Pubkey,
+    mint: Pubkey,
+    fee_bps: u16,
+    min_bps_fee: u64,
+    max_bps_fee: u64,
 ) -> Result<Signature, Box<dyn std::error::Error>> {
     let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
 
-    let method = get_function_hash("global", "set_whitelist_inactive");
+    let (token_config_pda, _) =
+        Pubkey::find_program_address(&[b"token_config", mint.as_ref()], &program_id);
 
-    let set_whitelist_inactive = bridge_program_instructions::SetWhitelistInactive {};
+    let method = get_function_hash("global", "set_bps_fee");
+
+    let set_bps_fee = bridge_program_instructions::SetBpsFee {
+        fee_bps,
+        min_bps_fee,
+        max_bps_fee,
+    };
 
     let mut method_bytes = method.to_vec();
 
-    method_bytes.append(&mut set_whitelist_inactive.try_to_vec()?);
+    method_bytes.append(&mut set_bps_fee.try_to_vec()?);
 
     let ix = Instruction::new_with_bytes(
         program_id,
         &method_bytes,
         vec![
-            AccountMeta::new(bridge_pda, false),
+            AccountMeta::new_readonly(bridge_pda, false),
+            AccountMeta::new(token_config_pda, false),
             AccountMeta::new_readonly(signer.pubkey(), true),
+            get_queued_change_account(
+                rpc_client,
+                &program_id,
+                &ConfigChange::BpsFee {
+                    mint,
+                    fee_bps,
+                    min_bps_fee,
+                    max_bps_fee,
+                },
+            )
+            .await,
             AccountMeta::new_readonly(system_program::ID, false),
         ],
     );
@@ -695,32 +917,50 @@ pub async fn process_set_whitelist_inactive(
     Ok(signature)
 }
 
-pub async fn process_add_to_whitelist(
+pub async fn process_set_user_deposit_limits(
     rpc_client: &RpcClient,
     signer: &dyn Signer,
     program_id: Pubkey,
-    entry_pk: Pubkey,
+    mint: Pubkey,
+    user_deposit_window: i64,
+    user_deposit_cap: u64,
+    user_deposit_cooldown: i64,
 ) -> Result<Signature, Box<dyn std::error::Error>> {
     let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
 
-    let (whitelist_entry_pda, _) =
-        Pubkey::find_program_address(&[entry_pk.as_ref(), bridge_pda.as_ref()], &program_id);
+    let (token_config_pda, _) =
+        Pubkey::find_program_address(&[b"token_config", mint.as_ref()], &program_id);
 
-    let method = get_function_hash("global", "add_to_whitelist");
+    let method = get_function_hash("global", "set_user_deposit_limits");
 
-    let add_to_whitelist = bridge_program_instructions::AddToWhitelist { address: entry_pk };
+    let set_user_deposit_limits = bridge_program_instructions::SetUserDepositLimits {
+        user_deposit_window,
+        user_deposit_cap,
+        user_deposit_cooldown,
+    };
 
     let mut method_bytes = method.to_vec();
 
-    method_bytes.append(&mut add_to_whitelist.try_to_vec()?);
+    method_bytes.append(&mut set_user_deposit_limits.try_to_vec()?);
 
     let ix = Instruction::new_with_bytes(
         program_id,
         &method_bytes,
         vec![
-            AccountMeta::new(whitelist_entry_pda, false),
+            AccountMeta::new_readonly(bridge_pda, false),
+            AccountMeta::new(token_config_pda, false),
             AccountMeta::new_readonly(signer.pubkey(), true),
-            AccountMeta::new(bridge_pda, false),
+            get_queued_change_account(
+                rpc_client,
+                &program_id,
+                &ConfigChange::UserDepositLimits {
+                    mint,
+                    user_deposit_window,
+                    user_deposit_cap,
+                    user_deposit_cooldown,
+                },
+            )
+            .await,
             AccountMeta::new_readonly(system_program::ID, false),
         ],
     );
@@ -748,33 +988,29 @@ pub async fn process_add_to_whitelist(
     Ok(signature)
 }
 
-pub async fn process_remove_from_whitelist(
+pub async fn process_public_pause_contract(
     rpc_client: &RpcClient,
     signer: &dyn Signer,
     program_id: Pubkey,
-    entry_pk: Pubkey,
+    mint: Option<Pubkey>,
 ) -> Result<Signature, Box<dyn std::error::Error>> {
     let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
 
-    let (entry_pda, _) =
-        Pubkey::find_program_address(&[entry_pk.as_ref(), bridge_pda.as_ref()], &program_id);
-
-    let method = get_function_hash("global", "remove_from_whitelist");
+    let method = get_function_hash("global", "public_pause");
 
-    let remove_from_whitelist =
-        bridge_program_instructions::RemoveFromWhitelist { address: entry_pk };
+    let pause_contract = bridge_program_instructions::PublicPause {};
 
     let mut method_bytes = method.to_vec();
 
-    method_bytes.append(&mut remove_from_whitelist.try_to_vec()?);
+    method_bytes.append(&mut pause_contract.try_to_vec()?);
 
     let ix = Instruction::new_with_bytes(
         program_id,
         &method_bytes,
         vec![
-            AccountMeta::new(entry_pda, false),
-            AccountMeta::new_readonly(signer.pubkey(), true),
             AccountMeta::new(bridge_pda, false),
+            AccountMeta::new_readonly(signer.pubkey(), true),
+            get_token_config_account(&program_id, mint),
             AccountMeta::new_readonly(system_program::ID, false),
         ],
     );
@@ -801,3 +1037,1413 @@ pub async fn process_remove_from_whitelist(
 
     Ok(signature)
 }
+
+pub async fn process_relayer_pause(
+    rpc_client: &RpcClient,
+    signer: &dyn Signer,
+    program_id: Pubkey,
+    mint: Option<Pubkey>,
+) -> Result<Signature, Box<dyn std::error::Error>> {
+    let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
+
+    let method = get_function_hash("global", "relayer_pause");
+
+    let pause_contract = bridge_program_instructions::RelayerPause {};
+
+    let mut method_bytes = method.to_vec();
+
+    method_bytes.append(&mut pause_contract.try_to_vec()?);
+
+    let ix = Instruction::new_with_bytes(
+        program_id,
+        &method_bytes,
+        vec![
+            AccountMeta::new(bridge_pda, false),
+            AccountMeta::new_readonly(signer.pubkey(), true),
+            get_token_config_account(&program_id, mint),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+    );
+
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(&signer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {err}"))?;
+
+    tx.try_sign(&vec![signer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {err}"))?;
+
+    let config = RpcSendTransactionConfig {
+        skip_preflight: true,
+        ..RpcSendTransactionConfig::default()
+    };
+
+    let signature = rpc_client
+        .send_transaction_with_config(&tx, config)
+        .await
+        .map_err(|err| format!("error: send transaction: {err}"))?;
+
+    Ok(signature)
+}
+
+pub async fn process_relayer_unpause(
+    rpc_client: &RpcClient,
+    signer: &dyn Signer,
+    program_id: Pubkey,
+    mint: Option<Pubkey>,
+) -> Result<Signature, Box<dyn std::error::Error>> {
+    let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
+
+    let method = get_function_hash("global", "relayer_unpause");
+
+    let unpause_contract = bridge_program_instructions::RelayerUnpause {};
+
+    let mut method_bytes = method.to_vec();
+
+    method_bytes.append(&mut unpause_contract.try_to_vec()?);
+
+    let ix = Instruction::new_with_bytes(
+        program_id,
+        &method_bytes,
+        vec![
+            AccountMeta::new(bridge_pda, false),
+            AccountMeta::new_readonly(signer.pubkey(), true),
+            get_token_config_account(&program_id, mint),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+    );
+
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(&signer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {err}"))?;
+
+    tx.try_sign(&vec![signer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {err}"))?;
+
+    let config = RpcSendTransactionConfig {
+        skip_preflight: true,
+        ..RpcSendTransactionConfig::default()
+    };
+
+    let signature = rpc_client
+        .send_transaction_with_config(&tx, config)
+        .await
+        .map_err(|err| format!("error: send transaction: {err}"))?;
+
+    Ok(signature)
+}
+
+pub async fn process_public_unpause_contract(
+    rpc_client: &RpcClient,
+    signer: &dyn Signer,
+    program_id: Pubkey,
+    mint: Option<Pubkey>,
+) -> Result<Signature, Box<dyn std::error::Error>> {
+    let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
+
+    let method = get_function_hash("global", "public_unpause");
+
+    let unpause_contract = bridge_program_instructions::PublicUnpause {};
+
+    let mut method_bytes = method.to_vec();
+
+    method_bytes.append(&mut unpause_contract.try_to_vec()?);
+
+    let ix = Instruction::new_with_bytes(
+        program_id,
+        &method_bytes,
+        vec![
+            AccountMeta::new(bridge_pda, false),
+            AccountMeta::new_readonly(signer.pubkey(), true),
+            get_token_config_account(&program_id, mint),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+    );
+
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(&signer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {err}"))?;
+
+    tx.try_sign(&vec![signer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {err}"))?;
+
+    let config = RpcSendTransactionConfig {
+        skip_preflight: true,
+        ..RpcSendTransactionConfig::default()
+    };
+
+    let signature = rpc_client
+        .send_transaction_with_config(&tx, config)
+        .await
+        .map_err(|err| format!("error: send transaction: {err}"))?;
+
+    Ok(signature)
+}
+
+pub async fn process_set_whitelist_active(
+    rpc_client: &RpcClient,
+    signer: &dyn Signer,
+    program_id: Pubkey,
+) -> Result<Signature, Box<dyn std::error::Error>> {
+    let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
+
+    let method = get_function_hash("global", "set_whitelist_active");
+
+    let set_whitelist_active = bridge_program_instructions::SetWhitelistActive {};
+
+    let mut method_bytes = method.to_vec();
+
+    method_bytes.append(&mut set_whitelist_active.try_to_vec()?);
+
+    let ix = Instruction::new_with_bytes(
+        program_id,
+        &method_bytes,
+        vec![
+            AccountMeta::new(bridge_pda, false),
+            AccountMeta::new_readonly(signer.pubkey(), true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+    );
+
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(&signer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {err}"))?;
+
+    tx.try_sign(&vec![signer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {err}"))?;
+
+    let config = RpcSendTransactionConfig {
+        skip_preflight: true,
+        ..RpcSendTransactionConfig::default()
+    };
+
+    let signature = rpc_client
+        .send_transaction_with_config(&tx, config)
+        .await
+        .map_err(|err| format!("error: send transaction: {err}"))?;
+
+    Ok(signature)
+}
+
+pub async fn process_set_whitelist_inactive(
+    rpc_client: &RpcClient,
+    signer: &dyn Signer,
+    program_id: Pubkey,
+) -> Result<Signature, Box<dyn std::error::Error>> {
+    let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
+
+    let method = get_function_hash("global", "set_whitelist_inactive");
+
+    let set_whitelist_inactive = bridge_program_instructions::SetWhitelistInactive {};
+
+    let mut method_bytes = method.to_vec();
+
+    method_bytes.append(&mut set_whitelist_inactive.try_to_vec()?);
+
+    let ix = Instruction::new_with_bytes(
+        program_id,
+        &method_bytes,
+        vec![
+            AccountMeta::new(bridge_pda, false),
+            AccountMeta::new_readonly(signer.pubkey(), true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+    );
+
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(&signer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {err}"))?;
+
+    tx.try_sign(&vec![signer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {err}"))?;
+
+    let config = RpcSendTransactionConfig {
+        skip_preflight: true,
+        ..RpcSendTransactionConfig::default()
+    };
+
+    let signature = rpc_client
+        .send_transaction_with_config(&tx, config)
+        .await
+        .map_err(|err| format!("error: send transaction: {err}"))?;
+
+    Ok(signature)
+}
+
+pub async fn process_set_destination_signature_active(
+    rpc_client: &RpcClient,
+    signer: &dyn Signer,
+    program_id: Pubkey,
+) -> Result<Signature, Box<dyn std::error::Error>> {
+    let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
+
+    let method = get_function_hash("global", "set_destination_signature_active");
+
+    let set_destination_signature_active =
+        bridge_program_instructions::SetDestinationSignatureActive {};
+
+    let mut method_bytes = method.to_vec();
+
+    method_bytes.append(&mut set_destination_signature_active.try_to_vec()?);
+
+    let ix = Instruction::new_with_bytes(
+        program_id,
+        &method_bytes,
+        vec![
+            AccountMeta::new(bridge_pda, false),
+            AccountMeta::new(signer.pubkey(), true),
+            get_queued_change_account(
+                rpc_client,
+                &program_id,
+                &ConfigChange::DestinationSignature {
+                    destination_signature_state: State::Active.to_code(),
+                },
+            )
+            .await,
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+    );
+
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(&signer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {err}"))?;
+
+    tx.try_sign(&vec![signer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {err}"))?;
+
+    let config = RpcSendTransactionConfig {
+        skip_preflight: true,
+        ..RpcSendTransactionConfig::default()
+    };
+
+    let signature = rpc_client
+        .send_transaction_with_config(&tx, config)
+        .await
+        .map_err(|err| format!("error: send transaction: {err}"))?;
+
+    Ok(signature)
+}
+
+pub async fn process_set_destination_signature_inactive(
+    rpc_client: &RpcClient,
+    signer: &dyn Signer,
+    program_id: Pubkey,
+) -> Result<Signature, Box<dyn std::error::Error>> {
+    let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
+
+    let method = get_function_hash("global", "set_destination_signature_inactive");
+
+    let set_destination_signature_inactive =
+        bridge_program_instructions::SetDestinationSignatureInactive {};
+
+    let mut method_bytes = method.to_vec();
+
+    method_bytes.append(&mut set_destination_signature_inactive.try_to_vec()?);
+
+    let ix = Instruction::new_with_bytes(
+        program_id,
+        &method_bytes,
+        vec![
+            AccountMeta::new(bridge_pda, false),
+            AccountMeta::new(signer.pubkey(), true),
+            get_queued_change_account(
+                rpc_client,
+                &program_id,
+                &ConfigChange::DestinationSignature {
+                    destination_signature_state: State::Inactive.to_code(),
+                },
+            )
+            .await,
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+    );
+
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(&signer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {err}"))?;
+
+    tx.try_sign(&vec![signer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {err}"))?;
+
+    let config = RpcSendTransactionConfig {
+        skip_preflight: true,
+        ..RpcSendTransactionConfig::default()
+    };
+
+    let signature = rpc_client
+        .send_transaction_with_config(&tx, config)
+        .await
+        .map_err(|err| format!("error: send transaction: {err}"))?;
+
+    Ok(signature)
+}
+
+pub async fn process_add_to_whitelist(
+    rpc_client: &RpcClient,
+    signer: &dyn Signer,
+    program_id: Pubkey,
+    entry_pk: Pubkey,
+) -> Result<Signature, Box<dyn std::error::Error>> {
+    let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
+
+    let (whitelist_entry_pda, _) =
+        Pubkey::find_program_address(&[entry_pk.as_ref(), bridge_pda.as_ref()], &program_id);
+
+    let method = get_function_hash("global", "add_to_whitelist");
+
+    let add_to_whitelist = bridge_program_instructions::AddToWhitelist { address: entry_pk };
+
+    let mut method_bytes = method.to_vec();
+
+    method_bytes.append(&mut add_to_whitelist.try_to_vec()?);
+
+    let ix = Instruction::new_with_bytes(
+        program_id,
+        &method_bytes,
+        vec![
+            AccountMeta::new(whitelist_entry_pda, false),
+            AccountMeta::new_readonly(signer.pubkey(), true),
+            AccountMeta::new(bridge_pda, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+    );
+
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(&signer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {err}"))?;
+
+    tx.try_sign(&vec![signer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {err}"))?;
+
+    let config = RpcSendTransactionConfig {
+        skip_preflight: true,
+        ..RpcSendTransactionConfig::default()
+    };
+
+    let signature = rpc_client
+        .send_transaction_with_config(&tx, config)
+        .await
+        .map_err(|err| format!("error: send transaction: {err}"))?;
+
+    Ok(signature)
+}
+
+pub async fn process_remove_from_whitelist(
+    rpc_client: &RpcClient,
+    signer: &dyn Signer,
+    program_id: Pubkey,
+    entry_pk: Pubkey,
+) -> Result<Signature, Box<dyn std::error::Error>> {
+    let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
+
+    let (entry_pda, _) =
+        Pubkey::find_program_address(&[entry_pk.as_ref(), bridge_pda.as_ref()], &program_id);
+
+    let method = get_function_hash("global", "remove_from_whitelist");
+
+    let remove_from_whitelist =
+        bridge_program_instructions::RemoveFromWhitelist { address: entry_pk };
+
+    let mut method_bytes = method.to_vec();
+
+    method_bytes.append(&mut remove_from_whitelist.try_to_vec()?);
+
+    let ix = Instruction::new_with_bytes(
+        program_id,
+        &method_bytes,
+        vec![
+            AccountMeta::new(entry_pda, false),
+            AccountMeta::new_readonly(signer.pubkey(), true),
+            AccountMeta::new(bridge_pda, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+    );
+
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(&signer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {err}"))?;
+
+    tx.try_sign(&vec![signer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {err}"))?;
+
+    let config = RpcSendTransactionConfig {
+        skip_preflight: true,
+        ..RpcSendTransactionConfig::default()
+    };
+
+    let signature = rpc_client
+        .send_transaction_with_config(&tx, config)
+        .await
+        .map_err(|err| format!("error: send transaction: {err}"))?;
+
+    Ok(signature)
+}
+
+pub async fn process_grant_role(
+    rpc_client: &RpcClient,
+    signer: &dyn Signer,
+    program_id: Pubkey,
+    role: Role,
+    account: Pubkey,
+) -> Result<Signature, Box<dyn std::error::Error>> {
+    let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
+
+    let method = get_function_hash("global", "grant_role");
+
+    let grant_role = bridge_program_instructions::GrantRole { role, account };
+
+    let mut method_bytes = method.to_vec();
+
+    method_bytes.append(&mut grant_role.try_to_vec()?);
+
+    let ix = Instruction::new_with_bytes(
+        program_id,
+        &method_bytes,
+        vec![
+            AccountMeta::new(bridge_pda, false),
+            AccountMeta::new_readonly(signer.pubkey(), true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+    );
+
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(&signer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {err}"))?;
+
+    tx.try_sign(&vec![signer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {err}"))?;
+
+    let config = RpcSendTransactionConfig {
+        skip_preflight: true,
+        ..RpcSendTransactionConfig::default()
+    };
+
+    let signature = rpc_client
+        .send_transaction_with_config(&tx, config)
+        .await
+        .map_err(|err| format!("error: send transaction: {err}"))?;
+
+    Ok(signature)
+}
+
+pub async fn process_revoke_role(
+    rpc_client: &RpcClient,
+    signer: &dyn Signer,
+    program_id: Pubkey,
+    role: Role,
+) -> Result<Signature, Box<dyn std::error::Error>> {
+    let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
+
+    let method = get_function_hash("global", "revoke_role");
+
+    let revoke_role = bridge_program_instructions::RevokeRole { role };
+
+    let mut method_bytes = method.to_vec();
+
+    method_bytes.append(&mut revoke_role.try_to_vec()?);
+
+    let ix = Instruction::new_with_bytes(
+        program_id,
+        &method_bytes,
+        vec![
+            AccountMeta::new(bridge_pda, false),
+            AccountMeta::new_readonly(signer.pubkey(), true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+    );
+
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(&signer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {err}"))?;
+
+    tx.try_sign(&vec![signer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {err}"))?;
+
+    let config = RpcSendTransactionConfig {
+        skip_preflight: true,
+        ..RpcSendTransactionConfig::default()
+    };
+
+    let signature = rpc_client
+        .send_transaction_with_config(&tx, config)
+        .await
+        .map_err(|err| format!("error: send transaction: {err}"))?;
+
+    Ok(signature)
+}
+
+pub async fn process_create_multisig(
+    rpc_client: &RpcClient,
+    signer: &dyn Signer,
+    program_id: Pubkey,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<Signature, Box<dyn std::error::Error>> {
+    let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
+
+    let (multisig_pda, _) = Pubkey::find_program_address(&[b"multisig"], &program_id);
+
+    let method = get_function_hash("global", "create_multisig");
+
+    let create_multisig = bridge_program_instructions::CreateMultisig { signers, threshold };
+
+    let mut method_bytes = method.to_vec();
+
+    method_bytes.append(&mut create_multisig.try_to_vec()?);
+
+    let ix = Instruction::new_with_bytes(
+        program_id,
+        &method_bytes,
+        vec![
+            AccountMeta::new(multisig_pda, false),
+            AccountMeta::new(bridge_pda, false),
+            AccountMeta::new(signer.pubkey(), true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+    );
+
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(&signer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {err}"))?;
+
+    tx.try_sign(&vec![signer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {err}"))?;
+
+    let config = RpcSendTransactionConfig {
+        skip_preflight: true,
+        ..RpcSendTransactionConfig::default()
+    };
+
+    let signature = rpc_client
+        .send_transaction_with_config(&tx, config)
+        .await
+        .map_err(|err| format!("error: send transaction: {err}"))?;
+
+    Ok(signature)
+}
+
+pub async fn process_create_proposal(
+    rpc_client: &RpcClient,
+    signer: &dyn Signer,
+    program_id: Pubkey,
+    action: ProposalAction,
+) -> Result<Signature, Box<dyn std::error::Error>> {
+    let (multisig_pda, _) = Pubkey::find_program_address(&[b"multisig"], &program_id);
+
+    let multisig_data = rpc_client.get_account_data(&multisig_pda).await?;
+
+    // skip the account discriminator, the next proposal id is the multisig proposal_count
+    let proposal_id = Multisig::deserialize(&mut &multisig_data[8..])?.proposal_count;
+
+    let (proposal_pda, _) =
+        Pubkey::find_program_address(&[b"proposal", &proposal_id.to_le_bytes()], &program_id);
+
+    let method = get_function_hash("global", "create_proposal");
+
+    let create_proposal = bridge_program_instructions::CreateProposal { action };
+
+    let mut method_bytes = method.to_vec();
+
+    method_bytes.append(&mut create_proposal.try_to_vec()?);
+
+    let ix = Instruction::new_with_bytes(
+        program_id,
+        &method_bytes,
+        vec![
+            AccountMeta::new(proposal_pda, false),
+            AccountMeta::new(multisig_pda, false),
+            AccountMeta::new(signer.pubkey(), true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+    );
+
+    println!("Proposal id: {proposal_id}");
+
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(&signer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {err}"))?;
+
+    tx.try_sign(&vec![signer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {err}"))?;
+
+    let config = RpcSendTransactionConfig {
+        skip_preflight: true,
+        ..RpcSendTransactionConfig::default()
+    };
+
+    let signature = rpc_client
+        .send_transaction_with_config(&tx, config)
+        .await
+        .map_err(|err| format!("error: send transaction: {err}"))?;
+
+    Ok(signature)
+}
+
+pub async fn process_approve_proposal(
+    rpc_client: &RpcClient,
+    signer: &dyn Signer,
+    program_id: Pubkey,
+    proposal_id: u64,
+) -> Result<Signature, Box<dyn std::error::Error>> {
+    let (multisig_pda, _) = Pubkey::find_program_address(&[b"multisig"], &program_id);
+
+    let (proposal_pda, _) =
+        Pubkey::find_program_address(&[b"proposal", &proposal_id.to_le_bytes()], &program_id);
+
+    let method = get_function_hash("global", "approve_proposal");
+
+    let approve_proposal = bridge_program_instructions::ApproveProposal {};
+
+    let mut method_bytes = method.to_vec();
+
+    method_bytes.append(&mut approve_proposal.try_to_vec()?);
+
+    let ix = Instruction::new_with_bytes(
+        program_id,
+        &method_bytes,
+        vec![
+            AccountMeta::new(proposal_pda, false),
+            AccountMeta::new_readonly(multisig_pda, false),
+            AccountMeta::new(signer.pubkey(), true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+    );
+
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(&signer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {err}"))?;
+
+    tx.try_sign(&vec![signer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {err}"))?;
+
+    let config = RpcSendTransactionConfig {
+        skip_preflight: true,
+        ..RpcSendTransactionConfig::default()
+    };
+
+    let signature = rpc_client
+        .send_transaction_with_config(&tx, config)
+        .await
+        .map_err(|err| format!("error: send transaction: {err}"))?;
+
+    Ok(signature)
+}
+
+pub async fn process_queue_config_change(
+    rpc_client: &RpcClient,
+    signer: &dyn Signer,
+    program_id: Pubkey,
+    change: ConfigChange,
+) -> Result<Signature, Box<dyn std::error::Error>> {
+    let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
+
+    let (queued_change_pda, _) = Pubkey::find_program_address(
+        &[
+            b"config_change",
+            &change.kind().to_le_bytes(),
+            change.scope().as_ref(),
+        ],
+        &program_id,
+    );
+
+    let method = get_function_hash("global", "queue_config_change");
+
+    let queue_config_change = bridge_program_instructions::QueueConfigChange { change };
+
+    let mut method_bytes = method.to_vec();
+
+    method_bytes.append(&mut queue_config_change.try_to_vec()?);
+
+    let ix = Instruction::new_with_bytes(
+        program_id,
+        &method_bytes,
+        vec![
+            AccountMeta::new(queued_change_pda, false),
+            AccountMeta::new_readonly(bridge_pda, false),
+            AccountMeta::new(signer.pubkey(), true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+    );
+
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(&signer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {err}"))?;
+
+    tx.try_sign(&vec![signer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {err}"))?;
+
+    let config = RpcSendTransactionConfig {
+        skip_preflight: true,
+        ..RpcSendTransactionConfig::default()
+    };
+
+    let signature = rpc_client
+        .send_transaction_with_config(&tx, config)
+        .await
+        .map_err(|err| format!("error: send transaction: {err}"))?;
+
+    Ok(signature)
+}
+
+pub async fn process_cancel_config_change(
+    rpc_client: &RpcClient,
+    signer: &dyn Signer,
+    program_id: Pubkey,
+    kind: u8,
+) -> Result<Signature, Box<dyn std::error::Error>> {
+    let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
+
+    let (queued_change_pda, _) =
+        Pubkey::find_program_address(&[b"config_change", &kind.to_le_bytes()], &program_id);
+
+    let method = get_function_hash("global", "cancel_config_change");
+
+    let cancel_config_change = bridge_program_instructions::CancelConfigChange {};
+
+    let mut method_bytes = method.to_vec();
+
+    method_bytes.append(&mut cancel_config_change.try_to_vec()?);
+
+    let ix = Instruction::new_with_bytes(
+        program_id,
+        &method_bytes,
+        vec![
+            AccountMeta::new(queued_change_pda, false),
+            AccountMeta::new_readonly(bridge_pda, false),
+            AccountMeta::new(signer.pubkey(), true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+    );
+
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(&signer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {err}"))?;
+
+    tx.try_sign(&vec![signer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {err}"))?;
+
+    let config = RpcSendTransactionConfig {
+        skip_preflight: true,
+        ..RpcSendTransactionConfig::default()
+    };
+
+    let signature = rpc_client
+        .send_transaction_with_config(&tx, config)
+        .await
+        .map_err(|err| format!("error: send transaction: {err}"))?;
+
+    Ok(signature)
+}
+
+pub async fn process_set_timelock_delay(
+    rpc_client: &RpcClient,
+    signer: &dyn Signer,
+    program_id: Pubkey,
+    timelock_delay: i64,
+) -> Result<Signature, Box<dyn std::error::Error>> {
+    let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
+
+    let method = get_function_hash("global", "set_timelock_delay");
+
+    let set_timelock_delay = bridge_program_instructions::SetTimelockDelay { timelock_delay };
+
+    let mut method_bytes = method.to_vec();
+
+    method_bytes.append(&mut set_timelock_delay.try_to_vec()?);
+
+    let ix = Instruction::new_with_bytes(
+        program_id,
+        &method_bytes,
+        vec![
+            AccountMeta::new(bridge_pda, false),
+            AccountMeta::new(signer.pubkey(), true),
+            get_queued_change_account(
+                rpc_client,
+                &program_id,
+                &ConfigChange::TimelockDelay { timelock_delay },
+            )
+            .await,
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+    );
+
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(&signer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {err}"))?;
+
+    tx.try_sign(&vec![signer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {err}"))?;
+
+    let config = RpcSendTransactionConfig {
+        skip_preflight: true,
+        ..RpcSendTransactionConfig::default()
+    };
+
+    let signature = rpc_client
+        .send_transaction_with_config(&tx, config)
+        .await
+        .map_err(|err| format!("error: send transaction: {err}"))?;
+
+    Ok(signature)
+}
+
+pub async fn process_set_chain_id(
+    rpc_client: &RpcClient,
+    signer: &dyn Signer,
+    program_id: Pubkey,
+    chain_id: u64,
+) -> Result<Signature, Box<dyn std::error::Error>> {
+    let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
+
+    let method = get_function_hash("global", "set_chain_id");
+
+    let set_chain_id = bridge_program_instructions::SetChainId { chain_id };
+
+    let mut method_bytes = method.to_vec();
+
+    method_bytes.append(&mut set_chain_id.try_to_vec()?);
+
+    let ix = Instruction::new_with_bytes(
+        program_id,
+        &method_bytes,
+        vec![
+            AccountMeta::new(bridge_pda, false),
+            AccountMeta::new(signer.pubkey(), true),
+            get_queued_change_account(rpc_client, &program_id, &ConfigChange::ChainId { chain_id })
+                .await,
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+    );
+
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(&signer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {err}"))?;
+
+    tx.try_sign(&vec![signer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {err}"))?;
+
+    let config = RpcSendTransactionConfig {
+        skip_preflight: true,
+        ..RpcSendTransactionConfig::default()
+    };
+
+    let signature = rpc_client
+        .send_transaction_with_config(&tx, config)
+        .await
+        .map_err(|err| format!("error: send transaction: {err}"))?;
+
+    Ok(signature)
+}
+
+pub async fn process_set_refund_policy(
+    rpc_client: &RpcClient,
+    signer: &dyn Signer,
+    program_id: Pubkey,
+    refund_timeout: i64,
+    refund_fee: bool,
+) -> Result<Signature, Box<dyn std::error::Error>> {
+    let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
+
+    let method = get_function_hash("global", "set_refund_policy");
+
+    let set_refund_policy = bridge_program_instructions::SetRefundPolicy {
+        refund_timeout,
+        refund_fee,
+    };
+
+    let mut method_bytes = method.to_vec();
+
+    method_bytes.append(&mut set_refund_policy.try_to_vec()?);
+
+    let ix = Instruction::new_with_bytes(
+        program_id,
+        &method_bytes,
+        vec![
+            AccountMeta::new(bridge_pda, false),
+            AccountMeta::new(signer.pubkey(), true),
+            get_queued_change_account(
+                rpc_client,
+                &program_id,
+                &ConfigChange::RefundPolicy {
+                    refund_timeout,
+                    refund_fee,
+                },
+            )
+            .await,
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+    );
+
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(&signer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {err}"))?;
+
+    tx.try_sign(&vec![signer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {err}"))?;
+
+    let config = RpcSendTransactionConfig {
+        skip_preflight: true,
+        ..RpcSendTransactionConfig::default()
+    };
+
+    let signature = rpc_client
+        .send_transaction_with_config(&tx, config)
+        .await
+        .map_err(|err| format!("error: send transaction: {err}"))?;
+
+    Ok(signature)
+}
+
+pub async fn process_set_outflow_limit(
+    rpc_client: &RpcClient,
+    signer: &dyn Signer,
+    program_id: Pubkey,
+    mint: Pubkey,
+    outflow_window: i64,
+    outflow_cap: u64,
+) -> Result<Signature, Box<dyn std::error::Error>> {
+    let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
+
+    let (token_config_pda, _) =
+        Pubkey::find_program_address(&[b"token_config", mint.as_ref()], &program_id);
+
+    let method = get_function_hash("global", "set_outflow_limit");
+
+    let set_outflow_limit = bridge_program_instructions::SetOutflowLimit {
+        outflow_window,
+        outflow_cap,
+    };
+
+    let mut method_bytes = method.to_vec();
+
+    method_bytes.append(&mut set_outflow_limit.try_to_vec()?);
+
+    let ix = Instruction::new_with_bytes(
+        program_id,
+        &method_bytes,
+        vec![
+            AccountMeta::new_readonly(bridge_pda, false),
+            AccountMeta::new(token_config_pda, false),
+            AccountMeta::new(signer.pubkey(), true),
+            get_queued_change_account(
+                rpc_client,
+                &program_id,
+                &ConfigChange::OutflowLimit {
+                    mint,
+                    outflow_window,
+                    outflow_cap,
+                },
+            )
+            .await,
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+    );
+
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(&signer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {err}"))?;
+
+    tx.try_sign(&vec![signer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {err}"))?;
+
+    let config = RpcSendTransactionConfig {
+        skip_preflight: true,
+        ..RpcSendTransactionConfig::default()
+    };
+
+    let signature = rpc_client
+        .send_transaction_with_config(&tx, config)
+        .await
+        .map_err(|err| format!("error: send transaction: {err}"))?;
+
+    Ok(signature)
+}
+
+pub async fn process_set_circuit_breaker(
+    rpc_client: &RpcClient,
+    signer: &dyn Signer,
+    program_id: Pubkey,
+    mint: Pubkey,
+    breaker_release_bps: u16,
+    breaker_window: i64,
+    breaker_threshold: u64,
+) -> Result<Signature, Box<dyn std::error::Error>> {
+    let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
+
+    let (token_config_pda, _) =
+        Pubkey::find_program_address(&[b"token_config", mint.as_ref()], &program_id);
+
+    let method = get_function_hash("global", "set_circuit_breaker");
+
+    let set_circuit_breaker = bridge_program_instructions::SetCircuitBreaker {
+        breaker_release_bps,
+        breaker_window,
+        breaker_threshold,
+    };
+
+    let mut method_bytes = method.to_vec();
+
+    method_bytes.append(&mut set_circuit_breaker.try_to_vec()?);
+
+    let ix = Instruction::new_with_bytes(
+        program_id,
+        &method_bytes,
+        vec![
+            AccountMeta::new_readonly(bridge_pda, false),
+            AccountMeta::new(token_config_pda, false),
+            AccountMeta::new(signer.pubkey(), true),
+            get_queued_change_account(
+                rpc_client,
+                &program_id,
+                &ConfigChange::CircuitBreaker {
+                    mint,
+                    breaker_release_bps,
+                    breaker_window,
+                    breaker_threshold,
+                },
+            )
+            .await,
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+    );
+
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(&signer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {err}"))?;
+
+    tx.try_sign(&vec![signer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {err}"))?;
+
+    let config = RpcSendTransactionConfig {
+        skip_preflight: true,
+        ..RpcSendTransactionConfig::default()
+    };
+
+    let signature = rpc_client
+        .send_transaction_with_config(&tx, config)
+        .await
+        .map_err(|err| format!("error: send transaction: {err}"))?;
+
+    Ok(signature)
+}
+
+pub async fn process_reconcile(
+    rpc_client: &RpcClient,
+    signer: &dyn Signer,
+    program_id: Pubkey,
+    mint: Pubkey,
+) -> Result<Signature, Box<dyn std::error::Error>> {
+    let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
+
+    let (token_config_pda, _) =
+        Pubkey::find_program_address(&[b"token_config", mint.as_ref()], &program_id);
+
+    let token_program = get_token_program(rpc_client, &mint).await?;
+
+    let vault_ata =
+        get_associated_token_address_with_program_id(&bridge_pda, &mint, &token_program);
+
+    let method = get_function_hash("global", "reconcile");
+
+    let ix = Instruction::new_with_bytes(
+        program_id,
+        &method,
+        vec![
+            AccountMeta::new_readonly(bridge_pda, false),
+            AccountMeta::new(token_config_pda, false),
+            AccountMeta::new_readonly(vault_ata, false),
+            AccountMeta::new_readonly(signer.pubkey(), true),
+        ],
+    );
+
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(&signer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {err}"))?;
+
+    tx.try_sign(&vec![signer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {err}"))?;
+
+    let config = RpcSendTransactionConfig {
+        skip_preflight: true,
+        ..RpcSendTransactionConfig::default()
+    };
+
+    let signature = rpc_client
+        .send_transaction_with_config(&tx, config)
+        .await
+        .map_err(|err| format!("error: send transaction: {err}"))?;
+
+    Ok(signature)
+}
+
+pub async fn process_set_fee_override(
+    rpc_client: &RpcClient,
+    signer: &dyn Signer,
+    program_id: Pubkey,
+    mint: Pubkey,
+    address: Pubkey,
+    fee_amount: u64,
+    fee_bps: u16,
+) -> Result<Signature, Box<dyn std::error::Error>> {
+    let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
+
+    let (token_config_pda, _) =
+        Pubkey::find_program_address(&[b"token_config", mint.as_ref()], &program_id);
+
+    let (fee_override_pda, _) = Pubkey::find_program_address(
+        &[b"fee_override", mint.as_ref(), address.as_ref()],
+        &program_id,
+    );
+
+    let method = get_function_hash("global", "set_fee_override");
+
+    let set_fee_override = bridge_program_instructions::SetFeeOverride {
+        mint,
+        address,
+        fee_amount,
+        fee_bps,
+    };
+
+    let mut method_bytes = method.to_vec();
+
+    method_bytes.append(&mut set_fee_override.try_to_vec()?);
+
+    let ix = Instruction::new_with_bytes(
+        program_id,
+        &method_bytes,
+        vec![
+            AccountMeta::new(fee_override_pda, false),
+            AccountMeta::new(signer.pubkey(), true),
+            AccountMeta::new_readonly(bridge_pda, false),
+            AccountMeta::new_readonly(token_config_pda, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+    );
+
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(&signer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {err}"))?;
+
+    tx.try_sign(&vec![signer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {err}"))?;
+
+    let config = RpcSendTransactionConfig {
+        skip_preflight: true,
+        ..RpcSendTransactionConfig::default()
+    };
+
+    let signature = rpc_client
+        .send_transaction_with_config(&tx, config)
+        .await
+        .map_err(|err| format!("error: send transaction: {err}"))?;
+
+    Ok(signature)
+}
+
+pub async fn process_remove_fee_override(
+    rpc_client: &RpcClient,
+    signer: &dyn Signer,
+    program_id: Pubkey,
+    mint: Pubkey,
+    address: Pubkey,
+) -> Result<Signature, Box<dyn std::error::Error>> {
+    let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
+
+    let (fee_override_pda, _) = Pubkey::find_program_address(
+        &[b"fee_override", mint.as_ref(), address.as_ref()],
+        &program_id,
+    );
+
+    let method = get_function_hash("global", "remove_fee_override");
+
+    let remove_fee_override = bridge_program_instructions::RemoveFeeOverride { mint, address };
+
+    let mut method_bytes = method.to_vec();
+
+    method_bytes.append(&mut remove_fee_override.try_to_vec()?);
+
+    let ix = Instruction::new_with_bytes(
+        program_id,
+        &method_bytes,
+        vec![
+            AccountMeta::new(fee_override_pda, false),
+            AccountMeta::new(signer.pubkey(), true),
+            AccountMeta::new_readonly(bridge_pda, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+    );
+
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(&signer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {err}"))?;
+
+    tx.try_sign(&vec![signer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {err}"))?;
+
+    let config = RpcSendTransactionConfig {
+        skip_preflight: true,
+        ..RpcSendTransactionConfig::default()
+    };
+
+    let signature = rpc_client
+        .send_transaction_with_config(&tx, config)
+        .await
+        .map_err(|err| format!("error: send transaction: {err}"))?;
+
+    Ok(signature)
+}
+
+pub async fn process_withdraw_fees(
+    rpc_client: &RpcClient,
+    signer: &dyn Signer,
+    program_id: Pubkey,
+    mint: Option<Pubkey>,
+    amount: u64,
+) -> Result<Signature, Box<dyn std::error::Error>> {
+    let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], &program_id);
+
+    let (fee_vault_pda, _) = Pubkey::find_program_address(&[b"fee_vault"], &program_id);
+
+    let bridge_state_data = rpc_client.get_account_data(&bridge_pda).await?;
+
+    // skip the account discriminator, fees are always paid out to the configured fee collector
+    let fee_collector = BridgeState::deserialize(&mut &bridge_state_data[8..])?.fee_collector;
+
+    let method = get_function_hash("global", "withdraw_fees");
+
+    let withdraw_fees = bridge_program_instructions::WithdrawFees { amount };
+
+    let mut method_bytes = method.to_vec();
+
+    method_bytes.append(&mut withdraw_fees.try_to_vec()?);
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(bridge_pda, false),
+        AccountMeta::new(signer.pubkey(), true),
+        AccountMeta::new(fee_vault_pda, false),
+        AccountMeta::new(fee_collector, false),
+    ];
+
+    // lamport withdrawals still need a token program, the legacy one is passed
+    let token_program = match mint {
+        Some(mint) => get_token_program(rpc_client, &mint).await?,
+        None => spl_token::ID,
+    };
+
+    // Anchor treats the program id as a missing optional account
+    accounts.append(&mut match mint {
+        Some(mint) => vec![
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new(
+                get_associated_token_address_with_program_id(&fee_vault_pda, &mint, &token_program),
+                false,
+            ),
+            AccountMeta::new(
+                get_associated_token_address_with_program_id(&fee_collector, &mint, &token_program),
+                false,
+            ),
+        ],
+        None => vec![AccountMeta::new_readonly(program_id, false); 3],
+    });
+
+    accounts.append(&mut vec![
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(token_program, false),
+        AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+    ]);
+
+    let ix = Instruction::new_with_bytes(program_id, &method_bytes, accounts);
+
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(&signer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {err}"))?;
+
+    tx.try_sign(&vec![signer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {err}"))?;
+
+    let config = RpcSendTransactionConfig {
+        skip_preflight: true,
+        ..RpcSendTransactionConfig::default()
+    };
+
+    let signature = rpc_client
+        .send_transaction_with_config(&tx, config)
+        .await
+        .map_err(|err| format!("error: send transaction: {err}"))?;
+
+    Ok(signature)
+}
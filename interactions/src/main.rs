@@ -1,28 +1,159 @@
 use crate::admin_endpoints::{
-    process_add_liquidity, process_add_to_whitelist, process_initialize_contract,
-    process_public_pause_contract, process_public_unpause_contract, process_relayer_pause,
-    process_relayer_unpause, process_remove_from_whitelist, process_remove_liquidity,
-    process_set_deposit_limits, process_set_fee_amount, process_set_whitelist_active,
-    process_set_whitelist_inactive, process_update_relayer, process_update_whitelisted_mint,
+    process_accept_admin, process_add_liquidity, process_add_to_whitelist,
+    process_approve_proposal, process_cancel_config_change, process_create_multisig,
+    process_create_proposal, process_grant_role, process_initialize_contract,
+    process_migrate_bridge_state, process_propose_admin, process_public_pause_contract,
+    process_public_unpause_contract, process_queue_config_change, process_reconcile,
+    process_relayer_pause, process_relayer_unpause, process_remove_fee_override,
+    process_remove_from_whitelist, process_remove_liquidity, process_revoke_role,
+    process_set_bps_fee, process_set_chain_id, process_set_circuit_breaker,
+    process_set_deposit_limits, process_set_destination_signature_active,
+    process_set_destination_signature_inactive, process_set_fee_amount, process_set_fee_override,
+    process_set_outflow_limit, process_set_refund_policy, process_set_relayer_committee,
+    process_set_timelock_delay, process_set_user_deposit_limits, process_set_vault_cap,
+    process_set_whitelist_active, process_set_whitelist_inactive, process_update_relayer,
+    process_update_whitelisted_mint, process_withdraw_fees,
 };
 use anchor_client::solana_sdk::signature::Signer;
+use bridge_program::states::{bridge::Role, ConfigChange, ProposalAction, RelayerCommittee};
 
 use {
     clap::{crate_description, crate_name, crate_version, Arg, Command},
     solana_clap_v3_utils::{
-        input_parsers::{parse_url_or_moniker, pubkey_of},
+        input_parsers::{parse_url_or_moniker, pubkey_of, pubkeys_of},
         input_validators::{is_valid_signer, normalize_to_url_if_moniker},
         keypair::DefaultSigner,
     },
     solana_client::nonblocking::rpc_client::RpcClient,
     solana_remote_wallet::remote_wallet::RemoteWalletManager,
     solana_sdk::commitment_config::CommitmentConfig,
+    solana_sdk::pubkey::Pubkey,
+    spl_associated_token_account::get_associated_token_address,
     std::{process::exit, rc::Rc},
 };
 
 mod admin_endpoints;
 mod utils;
 
+const ROLES: [&str; 4] = [
+    "pauser",
+    "feeManager",
+    "liquidityManager",
+    "whitelistManager",
+];
+
+fn parse_role(role: &str) -> Role {
+    match role {
+        "pauser" => Role::Pauser,
+        "feeManager" => Role::FeeManager,
+        "liquidityManager" => Role::LiquidityManager,
+        "whitelistManager" => Role::WhitelistManager,
+        _ => unreachable!(),
+    }
+}
+
+// in the order of the on-chain ConfigChange kinds
+const CONFIG_CHANGES: [&str; 14] = [
+    "feeAmount",
+    "depositLimits",
+    "relayer",
+    "feeCollector",
+    "whitelistedMint",
+    "timelockDelay",
+    "bpsFee",
+    "outflowLimit",
+    "circuitBreaker",
+    "userDepositLimits",
+    "vaultCap",
+    "relayerCommittee",
+    "chainId",
+    "refundPolicy",
+];
+
+fn parse_config_change(change: &str, values: &[String]) -> ConfigChange {
+    match change {
+        "feeAmount" => ConfigChange::FeeAmount {
+            mint: values[0].parse().unwrap(),
+            fee_amount: values[1].parse().unwrap(),
+        },
+        "depositLimits" => ConfigChange::DepositLimits {
+            mint: values[0].parse().unwrap(),
+            minimum_deposit: values[1].parse().unwrap(),
+            maximum_deposit: values[2].parse().unwrap(),
+        },
+        "relayer" => ConfigChange::Relayer {
+            relayer_pubkey: values[0].parse().unwrap(),
+        },
+        "feeCollector" => ConfigChange::FeeCollector {
+            fee_collector: values[0].parse().unwrap(),
+        },
+        "whitelistedMint" => {
+            let mint_of_token_whitelisted: Pubkey = values[0].parse().unwrap();
+            let (bridge_pda, _) =
+                Pubkey::find_program_address(&[b"bridge_state"], &bridge_program::ID);
+
+            ConfigChange::WhitelistedMint {
+                mint_of_token_whitelisted,
+                vault: get_associated_token_address(&bridge_pda, &mint_of_token_whitelisted),
+                previous_mint: values
+                    .get(1)
+                    .map(|v| v.parse().unwrap())
+                    .unwrap_or_default(),
+                sweep_token_account: values
+                    .get(2)
+                    .map(|v| v.parse().unwrap())
+                    .unwrap_or_default(),
+            }
+        }
+        "timelockDelay" => ConfigChange::TimelockDelay {
+            timelock_delay: values[0].parse().unwrap(),
+        },
+        "bpsFee" => ConfigChange::BpsFee {
+            mint: values[0].parse().unwrap(),
+            fee_bps: values[1].parse().unwrap(),
+            min_bps_fee: values[2].parse().unwrap(),
+            max_bps_fee: values[3].parse().unwrap(),
+        },
+        "outflowLimit" => ConfigChange::OutflowLimit {
+            mint: values[0].parse().unwrap(),
+            outflow_window: values[1].parse().unwrap(),
+            outflow_cap: values[2].parse().unwrap(),
+        },
+        "circuitBreaker" => ConfigChange::CircuitBreaker {
+            mint: values[0].parse().unwrap(),
+            breaker_release_bps: values[1].parse().unwrap(),
+            breaker_window: values[2].parse().unwrap(),
+            breaker_threshold: values[3].parse().unwrap(),
+        },
+        "userDepositLimits" => ConfigChange::UserDepositLimits {
+            mint: values[0].parse().unwrap(),
+            user_deposit_window: values[1].parse().unwrap(),
+            user_deposit_cap: values[2].parse().unwrap(),
+            user_deposit_cooldown: values[3].parse().unwrap(),
+        },
+        "vaultCap" => ConfigChange::VaultCap {
+            mint: values[0].parse().unwrap(),
+            vault_cap: values[1].parse().unwrap(),
+        },
+        "relayerCommittee" => {
+            let relayers: Vec<Pubkey> = values[1..].iter().map(|v| v.parse().unwrap()).collect();
+
+            ConfigChange::RelayerCommittee {
+                relayers_hash: RelayerCommittee::relayers_hash(&relayers),
+                threshold: values[0].parse().unwrap(),
+            }
+        }
+        "chainId" => ConfigChange::ChainId {
+            chain_id: values[0].parse().unwrap(),
+        },
+        "refundPolicy" => ConfigChange::RefundPolicy {
+            refund_timeout: values[0].parse().unwrap(),
+            refund_fee: values[1].parse().unwrap(),
+        },
+        _ => unreachable!(),
+    }
+}
+
 struct Config {
     commitment_config: CommitmentConfig,
     default_signer: Box<dyn Signer>,
@@ -82,6 +213,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .subcommand(
             Command::new("initializeContract")
                 .about("Send an initialize contract transaction")
+                .arg(
+                    Arg::new("admin_pk")
+                        .required(true)
+                        .value_name("ADMIN_PK")
+                        .takes_value(true)
+                        .help("Admin public key"),
+                )
                 .arg(
                     Arg::new("relayer_pk")
                         .required(true)
@@ -125,6 +263,153 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         .help("Mint of token whitelisted"),
                 ),
         )
+        .subcommand(
+            Command::new("migrateBridgeState")
+                .about("Send a migrate bridge state transaction for a bridge deployed before roles")
+                .arg(
+                    Arg::new("admin_pk")
+                        .required(true)
+                        .value_name("ADMIN_PK")
+                        .takes_value(true)
+                        .help("Admin public key"),
+                )
+                .arg(
+                    Arg::new("mint_of_token_whitelisted")
+                        .required(true)
+                        .value_name("MINT_OF_TOKEN_WHITELISTED")
+                        .takes_value(true)
+                        .help("Mint of the token whitelisted by the legacy bridge state"),
+                ),
+        )
+        .subcommand(
+            Command::new("proposeAdmin")
+                .about("Send a propose admin transaction")
+                .arg(
+                    Arg::new("admin_pk")
+                        .required(true)
+                        .value_name("ADMIN_PK")
+                        .takes_value(true)
+                        .help("New admin public key"),
+                ),
+        )
+        .subcommand(
+            Command::new("acceptAdmin")
+                .about("Send an accept admin transaction, signed by the proposed admin"),
+        )
+        .subcommand(
+            Command::new("grantRole")
+                .about("Send a grant role transaction")
+                .arg(
+                    Arg::new("role")
+                        .required(true)
+                        .value_name("ROLE")
+                        .takes_value(true)
+                        .possible_values(ROLES)
+                        .help("Role to grant"),
+                )
+                .arg(
+                    Arg::new("account_pk")
+                        .required(true)
+                        .value_name("ACCOUNT_PK")
+                        .takes_value(true)
+                        .help("Account public key receiving the role"),
+                ),
+        )
+        .subcommand(
+            Command::new("revokeRole")
+                .about("Send a revoke role transaction")
+                .arg(
+                    Arg::new("role")
+                        .required(true)
+                        .value_name("ROLE")
+                        .takes_value(true)
+                        .possible_values(ROLES)
+                        .help("Role to revoke"),
+                ),
+        )
+        .subcommand(
+            Command::new("createMultisig")
+                .about("Send a create multisig transaction")
+                .arg(
+                    Arg::new("threshold")
+                        .required(true)
+                        .value_name("THRESHOLD")
+                        .takes_value(true)
+                        .help("Number of approvals needed to execute a proposal"),
+                )
+                .arg(
+                    Arg::new("signers")
+                        .required(true)
+                        .value_name("SIGNER_PK")
+                        .takes_value(true)
+                        .multiple_values(true)
+                        .help("Multisig signer public keys"),
+                ),
+        )
+        .subcommand(
+            Command::new("createProposal")
+                .about("Send a create multisig proposal transaction")
+                .arg(
+                    Arg::new("action")
+                        .required(true)
+                        .value_name("ACTION")
+                        .takes_value(true)
+                        .possible_values([
+                            "removeLiquidity",
+                            "updateRelayer",
+                            "updateWhitelistedMint",
+                            "updateRelayerCommittee",
+                        ])
+                        .help("Action to propose"),
+                )
+                .arg(
+                    Arg::new("values")
+                        .required(true)
+                        .value_name("VALUES")
+                        .takes_value(true)
+                        .multiple_values(true)
+                        .help(
+                            "Mint and amount to remove, new relayer public key, new mint, \
+                             or threshold and relayer public keys",
+                        ),
+                ),
+        )
+        .subcommand(
+            Command::new("approveProposal")
+                .about("Send an approve multisig proposal transaction")
+                .arg(
+                    Arg::new("proposal_id")
+                        .required(true)
+                        .value_name("PROPOSAL_ID")
+                        .takes_value(true)
+                        .help("Proposal id"),
+                ),
+        )
+        .subcommand(
+            Command::new("setRelayerCommittee")
+                .about("Send a set relayer committee transaction")
+                .arg(
+                    Arg::new("threshold")
+                        .required(true)
+                        .value_name("THRESHOLD")
+                        .takes_value(true)
+                        .help("Number of relayer approvals needed to release, 0 to disable"),
+                )
+                .arg(
+                    Arg::new("relayers")
+                        .value_name("RELAYER_PK")
+                        .takes_value(true)
+                        .multiple_values(true)
+                        .help("Relayer committee public keys, none to disable"),
+                )
+                .arg(
+                    Arg::new("proposal_id")
+                        .long("proposal-id")
+                        .value_name("PROPOSAL_ID")
+                        .takes_value(true)
+                        .help("Approved multisig proposal to execute"),
+                ),
+        )
         .subcommand(
             Command::new("updateRelayer")
                 .about("Send an update relayer transaction")
@@ -134,6 +419,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         .value_name("RELAYER_PK")
                         .takes_value(true)
                         .help("New relayer public key"),
+                )
+                .arg(
+                    Arg::new("proposal_id")
+                        .long("proposal-id")
+                        .value_name("PROPOSAL_ID")
+                        .takes_value(true)
+                        .help("Approved multisig proposal to execute"),
                 ),
         )
         .subcommand(
@@ -145,6 +437,29 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         .value_name("MINT_OF_TOKEN_WHITELISTED")
                         .takes_value(true)
                         .help("Mint of token whitelisted"),
+                )
+                .arg(
+                    Arg::new("proposal_id")
+                        .long("proposal-id")
+                        .value_name("PROPOSAL_ID")
+                        .takes_value(true)
+                        .help("Approved multisig proposal to execute"),
+                )
+                .arg(
+                    Arg::new("previous_mint")
+                        .long("previous-mint")
+                        .value_name("PREVIOUS_MINT")
+                        .takes_value(true)
+                        .help("Previously whitelisted mint to migrate from"),
+                )
+                .arg(
+                    Arg::new("sweep_token_account")
+                        .long("sweep-to")
+                        .value_name("SWEEP_TOKEN_ACCOUNT")
+                        .takes_value(true)
+                        .help(
+                            "Liquidity manager token account receiving the previous vault balance",
+                        ),
                 ),
         )
         .subcommand(
@@ -179,6 +494,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         .required(true)
                         .value_name("MINT_OF_TOKEN_sent")
                         .help("Mint of token sent by the program"),
+                )
+                .arg(
+                    Arg::new("proposal_id")
+                        .long("proposal-id")
+                        .value_name("PROPOSAL_ID")
+                        .takes_value(true)
+                        .help("Approved multisig proposal to execute"),
                 ),
         )
         .subcommand(
@@ -197,6 +519,174 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         .value_name("MAXIMUM_DEPOSIT")
                         .takes_value(true)
                         .help("Maximum deposit"),
+                )
+                .arg(
+                    Arg::new("mint")
+                        .required(true)
+                        .value_name("MINT")
+                        .takes_value(true)
+                        .help("Mint of the bridged token"),
+                ),
+        )
+        .subcommand(
+            Command::new("queueConfigChange")
+                .about("Send a queue config change transaction")
+                .arg(
+                    Arg::new("change")
+                        .required(true)
+                        .value_name("CHANGE")
+                        .takes_value(true)
+                        .possible_values(CONFIG_CHANGES)
+                        .help("Config change to queue"),
+                )
+                .arg(
+                    Arg::new("values")
+                        .required(true)
+                        .value_name("VALUES")
+                        .takes_value(true)
+                        .multiple_values(true)
+                        .help("New value(s), e.g. mint, minimum and maximum for depositLimits"),
+                ),
+        )
+        .subcommand(
+            Command::new("cancelConfigChange")
+                .about("Send a cancel config change transaction")
+                .arg(
+                    Arg::new("change")
+                        .required(true)
+                        .value_name("CHANGE")
+                        .takes_value(true)
+                        .possible_values(CONFIG_CHANGES)
+                        .help("Queued config change to cancel"),
+                ),
+        )
+        .subcommand(
+            Command::new("setTimelockDelay")
+                .about("Send a set timelock delay transaction")
+                .arg(
+                    Arg::new("timelock_delay")
+                        .required(true)
+                        .value_name("TIMELOCK_DELAY")
+                        .takes_value(true)
+                        .help("Delay in seconds between queueing and applying a config change"),
+                ),
+        )
+        .subcommand(
+            Command::new("setChainId")
+                .about("Send a set chain id transaction")
+                .arg(
+                    Arg::new("chain_id")
+                        .required(true)
+                        .value_name("CHAIN_ID")
+                        .takes_value(true)
+                        .help("Chain id covered by release attestations"),
+                ),
+        )
+        .subcommand(
+            Command::new("setRefundPolicy")
+                .about("Send a set refund policy transaction")
+                .arg(
+                    Arg::new("refund_timeout")
+                        .required(true)
+                        .value_name("REFUND_TIMEOUT")
+                        .takes_value(true)
+                        .help("Seconds after which a pending deposit can be refunded, 0 to disable"),
+                )
+                .arg(
+                    Arg::new("refund_fee")
+                        .required(true)
+                        .value_name("REFUND_FEE")
+                        .takes_value(true)
+                        .help("Whether the deposit fees are refunded as well (true/false)"),
+                ),
+        )
+        .subcommand(
+            Command::new("setCircuitBreaker")
+                .about("Send a set circuit breaker transaction")
+                .arg(
+                    Arg::new("mint")
+                        .required(true)
+                        .value_name("MINT")
+                        .takes_value(true)
+                        .help("Mint of the bridged token"),
+                )
+                .arg(
+                    Arg::new("breaker_release_bps")
+                        .required(true)
+                        .value_name("BREAKER_RELEASE_BPS")
+                        .takes_value(true)
+                        .help("Largest single release in basis points of the vault, 0 to disable"),
+                )
+                .arg(
+                    Arg::new("breaker_window")
+                        .required(true)
+                        .value_name("BREAKER_WINDOW")
+                        .takes_value(true)
+                        .help("Window in seconds over which releases are summed"),
+                )
+                .arg(
+                    Arg::new("breaker_threshold")
+                        .required(true)
+                        .value_name("BREAKER_THRESHOLD")
+                        .takes_value(true)
+                        .help("Largest amount released per window, 0 to disable"),
+                ),
+        )
+        .subcommand(
+            Command::new("setOutflowLimit")
+                .about("Send a set outflow limit transaction")
+                .arg(
+                    Arg::new("mint")
+                        .required(true)
+                        .value_name("MINT")
+                        .takes_value(true)
+                        .help("Mint of the bridged token"),
+                )
+                .arg(
+                    Arg::new("outflow_window")
+                        .required(true)
+                        .value_name("OUTFLOW_WINDOW")
+                        .takes_value(true)
+                        .help("Window in seconds over which releases are summed"),
+                )
+                .arg(
+                    Arg::new("outflow_cap")
+                        .required(true)
+                        .value_name("OUTFLOW_CAP")
+                        .takes_value(true)
+                        .help("Maximum amount released per window, 0 to disable"),
+                ),
+        )
+        .subcommand(
+            Command::new("setUserDepositLimits")
+                .about("Send a set per-user deposit limits transaction")
+                .arg(
+                    Arg::new("mint")
+                        .required(true)
+                        .value_name("MINT")
+                        .takes_value(true)
+                        .help("Mint of the bridged token"),
+                )
+                .arg(
+                    Arg::new("user_deposit_window")
+                        .required(true)
+                        .value_name("USER_DEPOSIT_WINDOW")
+                        .takes_value(true)
+                        .help("Window in seconds over which a user's deposits are summed"),
+                )
+                .arg(
+                    Arg::new("user_deposit_cap")
+                        .required(true)
+                        .value_name("USER_DEPOSIT_CAP")
+                        .takes_value(true)
+                        .help("Maximum amount a user can deposit per window, 0 to disable"),
+                )
+                .arg(
+                    Arg::new("user_deposit_cooldown")
+                        .required(true)
+                        .value_name("USER_DEPOSIT_COOLDOWN")
+                        .takes_value(true)
+                        .help("Seconds a user has to wait between deposits, 0 to disable"),
                 ),
         )
         .subcommand(
@@ -208,51 +698,235 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         .value_name("FEE_AMOUNT")
                         .takes_value(true)
                         .help("Fee amount"),
+                )
+                .arg(
+                    Arg::new("mint")
+                        .required(true)
+                        .value_name("MINT")
+                        .takes_value(true)
+                        .help("Mint of the bridged token"),
                 ),
         )
-        .subcommand(Command::new("publicPause").about("Send a pause transaction"))
-        .subcommand(Command::new("publicUnpause").about("Send a unpause transaction"))
-        .subcommand(Command::new("relayerPause").about("Send a relayer pause transaction"))
-        .subcommand(Command::new("relayerUnpause").about("Send a relayer unpause transaction"))
         .subcommand(
-            Command::new("setWhitelistActive").about("Send a set whitelist active transaction"),
+            Command::new("setVaultCap")
+                .about("Send a set vault cap transaction")
+                .arg(
+                    Arg::new("mint")
+                        .required(true)
+                        .value_name("MINT")
+                        .takes_value(true)
+                        .help("Mint of the bridged token"),
+                )
+                .arg(
+                    Arg::new("vault_cap")
+                        .required(true)
+                        .value_name("VAULT_CAP")
+                        .takes_value(true)
+                        .help("Maximum amount held by the vault after a deposit, 0 to disable"),
+                ),
         )
         .subcommand(
-            Command::new("setWhitelistInactive").about("Send a set whitelist inactive transaction"),
+            Command::new("setBpsFee")
+                .about("Send a set basis points fee transaction")
+                .arg(
+                    Arg::new("mint")
+                        .required(true)
+                        .value_name("MINT")
+                        .takes_value(true)
+                        .help("Mint of the bridged token"),
+                )
+                .arg(
+                    Arg::new("fee_bps")
+                        .required(true)
+                        .value_name("FEE_BPS")
+                        .takes_value(true)
+                        .help("Fee in basis points of the bridged amount"),
+                )
+                .arg(
+                    Arg::new("min_bps_fee")
+                        .required(true)
+                        .value_name("MIN_BPS_FEE")
+                        .takes_value(true)
+                        .help("Minimum fee in the bridged token"),
+                )
+                .arg(
+                    Arg::new("max_bps_fee")
+                        .required(true)
+                        .value_name("MAX_BPS_FEE")
+                        .takes_value(true)
+                        .help("Maximum fee in the bridged token, 0 for no cap"),
+                ),
         )
         .subcommand(
-            Command::new("addToWhitelist")
-                .about("Send an add to whitelist transaction")
+            Command::new("publicPause")
+                .about("Send a pause transaction")
                 .arg(
-                    Arg::new("entry_pk")
-                        .required(true)
-                        .value_name("ENTRY_PK")
+                    Arg::new("mint")
+                        .long("mint")
+                        .value_name("MINT")
                         .takes_value(true)
-                        .help("Entry public key"),
+                        .help("Only pause/unpause this bridged token"),
                 ),
         )
         .subcommand(
-            Command::new("removeFromWhitelist")
-                .about("Send a remove from whitelist transaction")
+            Command::new("publicUnpause")
+                .about("Send a unpause transaction")
                 .arg(
-                    Arg::new("entry_pk")
-                        .required(true)
-                        .value_name("ENTRY_PK")
+                    Arg::new("mint")
+                        .long("mint")
+                        .value_name("MINT")
                         .takes_value(true)
-                        .help("Entry public key"),
+                        .help("Only pause/unpause this bridged token"),
                 ),
         )
-        .get_matches();
-
-    let (command, matches) = app_matches.subcommand().unwrap();
-    let mut wallet_manager: Option<Rc<RemoteWalletManager>> = None;
-
-    let config = {
-        let cli_config = if let Some(config_file) = matches.value_of("config_file") {
-            solana_cli_config::Config::load(config_file).unwrap_or_default()
-        } else {
-            solana_cli_config::Config::default()
-        };
+        .subcommand(
+            Command::new("relayerPause")
+                .about("Send a relayer pause transaction")
+                .arg(
+                    Arg::new("mint")
+                        .long("mint")
+                        .value_name("MINT")
+                        .takes_value(true)
+                        .help("Only pause/unpause this bridged token"),
+                ),
+        )
+        .subcommand(
+            Command::new("relayerUnpause")
+                .about("Send a relayer unpause transaction")
+                .arg(
+                    Arg::new("mint")
+                        .long("mint")
+                        .value_name("MINT")
+                        .takes_value(true)
+                        .help("Only pause/unpause this bridged token"),
+                ),
+        )
+        .subcommand(
+            Command::new("setWhitelistActive").about("Send a set whitelist active transaction"),
+        )
+        .subcommand(
+            Command::new("setWhitelistInactive").about("Send a set whitelist inactive transaction"),
+        )
+        .subcommand(
+            Command::new("setDestinationSignatureActive")
+                .about("Send a set destination signature verification active transaction"),
+        )
+        .subcommand(
+            Command::new("setDestinationSignatureInactive")
+                .about("Send a set destination signature verification inactive transaction"),
+        )
+        .subcommand(
+            Command::new("addToWhitelist")
+                .about("Send an add to whitelist transaction")
+                .arg(
+                    Arg::new("entry_pk")
+                        .required(true)
+                        .value_name("ENTRY_PK")
+                        .takes_value(true)
+                        .help("Entry public key"),
+                ),
+        )
+        .subcommand(
+            Command::new("removeFromWhitelist")
+                .about("Send a remove from whitelist transaction")
+                .arg(
+                    Arg::new("entry_pk")
+                        .required(true)
+                        .value_name("ENTRY_PK")
+                        .takes_value(true)
+                        .help("Entry public key"),
+                ),
+        )
+        .subcommand(
+            Command::new("setFeeOverride")
+                .about("Send a set fee override transaction")
+                .arg(
+                    Arg::new("mint")
+                        .required(true)
+                        .value_name("MINT")
+                        .takes_value(true)
+                        .help("Whitelisted mint the fee override applies to"),
+                )
+                .arg(
+                    Arg::new("address")
+                        .required(true)
+                        .value_name("ADDRESS")
+                        .takes_value(true)
+                        .help("Depositor paying the overridden fee"),
+                )
+                .arg(
+                    Arg::new("fee_amount")
+                        .required(true)
+                        .value_name("FEE_AMOUNT")
+                        .takes_value(true)
+                        .help("Flat fee amount charged instead of the token fee amount"),
+                )
+                .arg(
+                    Arg::new("fee_bps")
+                        .required(true)
+                        .value_name("FEE_BPS")
+                        .takes_value(true)
+                        .help("Fee in basis points charged instead of the token fee"),
+                ),
+        )
+        .subcommand(
+            Command::new("removeFeeOverride")
+                .about("Send a remove fee override transaction")
+                .arg(
+                    Arg::new("mint")
+                        .required(true)
+                        .value_name("MINT")
+                        .takes_value(true)
+                        .help("Mint the fee override applies to"),
+                )
+                .arg(
+                    Arg::new("address")
+                        .required(true)
+                        .value_name("ADDRESS")
+                        .takes_value(true)
+                        .help("Depositor whose fee override is removed"),
+                ),
+        )
+        .subcommand(
+            Command::new("withdrawFees")
+                .about("Send a withdraw fees transaction paying the fee vault out to the fee collector")
+                .arg(
+                    Arg::new("amount")
+                        .required(true)
+                        .value_name("AMOUNT")
+                        .takes_value(true)
+                        .help("Amount to withdraw, in lamports without --mint"),
+                )
+                .arg(
+                    Arg::new("mint")
+                        .long("mint")
+                        .value_name("MINT")
+                        .takes_value(true)
+                        .help("Withdraw the fees collected in this token instead of SOL"),
+                ),
+        )
+        .subcommand(
+            Command::new("reconcile")
+                .about("Send a reconcile transaction syncing vault_amount with the vault balance")
+                .arg(
+                    Arg::new("mint")
+                        .required(true)
+                        .value_name("MINT")
+                        .takes_value(true)
+                        .help("Mint of the bridged token"),
+                ),
+        )
+        .get_matches();
+
+    let (command, matches) = app_matches.subcommand().unwrap();
+    let mut wallet_manager: Option<Rc<RemoteWalletManager>> = None;
+
+    let config = {
+        let cli_config = if let Some(config_file) = matches.value_of("config_file") {
+            solana_cli_config::Config::load(config_file).unwrap_or_default()
+        } else {
+            solana_cli_config::Config::default()
+        };
 
         let default_signer = DefaultSigner::new(
             "keypair",
@@ -295,6 +969,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     match (command, matches) {
         ("initializeContract", arg_matches) => {
+            let admin_pk = pubkey_of(arg_matches, "admin_pk").unwrap();
             let relayer_pk = pubkey_of(arg_matches, "relayer_pk").unwrap();
             let fee_collector = pubkey_of(arg_matches, "fee_collector_pk").unwrap();
 
@@ -311,6 +986,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 &rpc_client,
                 config.default_signer.as_ref(),
                 bridge_program::ID,
+                admin_pk,
                 relayer_pk,
                 fee_collector,
                 mint_of_token_whitelisted,
@@ -325,6 +1001,186 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             });
             println!("Signature: {signature}");
         }
+        ("migrateBridgeState", arg_matches) => {
+            let admin_pk = pubkey_of(arg_matches, "admin_pk").unwrap();
+
+            let mint_of_token_whitelisted =
+                pubkey_of(arg_matches, "mint_of_token_whitelisted").unwrap();
+
+            let signature = process_migrate_bridge_state(
+                &rpc_client,
+                config.default_signer.as_ref(),
+                bridge_program::ID,
+                admin_pk,
+                mint_of_token_whitelisted,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: send transaction: {err}");
+                exit(1);
+            });
+            println!("Signature: {signature}");
+        }
+        ("proposeAdmin", arg_matches) => {
+            let admin_pk = pubkey_of(arg_matches, "admin_pk").unwrap();
+
+            let signature = process_propose_admin(
+                &rpc_client,
+                config.default_signer.as_ref(),
+                bridge_program::ID,
+                admin_pk,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: send transaction: {err}");
+                exit(1);
+            });
+            println!("Signature: {signature}");
+        }
+        ("acceptAdmin", _arg_matches) => {
+            let signature = process_accept_admin(
+                &rpc_client,
+                config.default_signer.as_ref(),
+                bridge_program::ID,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: send transaction: {err}");
+                exit(1);
+            });
+            println!("Signature: {signature}");
+        }
+        ("grantRole", arg_matches) => {
+            let role = parse_role(arg_matches.get_one::<String>("role").unwrap());
+            let account_pk = pubkey_of(arg_matches, "account_pk").unwrap();
+
+            let signature = process_grant_role(
+                &rpc_client,
+                config.default_signer.as_ref(),
+                bridge_program::ID,
+                role,
+                account_pk,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: send transaction: {err}");
+                exit(1);
+            });
+            println!("Signature: {signature}");
+        }
+        ("revokeRole", arg_matches) => {
+            let role = parse_role(arg_matches.get_one::<String>("role").unwrap());
+
+            let signature = process_revoke_role(
+                &rpc_client,
+                config.default_signer.as_ref(),
+                bridge_program::ID,
+                role,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: send transaction: {err}");
+                exit(1);
+            });
+            println!("Signature: {signature}");
+        }
+        ("createMultisig", arg_matches) => {
+            let threshold = arg_matches.get_one::<String>("threshold").unwrap();
+            let signers = pubkeys_of(arg_matches, "signers").unwrap();
+
+            let signature = process_create_multisig(
+                &rpc_client,
+                config.default_signer.as_ref(),
+                bridge_program::ID,
+                signers,
+                threshold.parse::<u8>().unwrap(),
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: send transaction: {err}");
+                exit(1);
+            });
+            println!("Signature: {signature}");
+        }
+        ("createProposal", arg_matches) => {
+            let values: Vec<&String> = arg_matches.get_many::<String>("values").unwrap().collect();
+
+            let action = match arg_matches.get_one::<String>("action").unwrap().as_str() {
+                "removeLiquidity" => ProposalAction::RemoveLiquidity {
+                    mint: values[0].parse().unwrap(),
+                    amount: values[1].parse::<u64>().unwrap(),
+                },
+                "updateRelayer" => ProposalAction::UpdateRelayer {
+                    relayer_pubkey: values[0].parse().unwrap(),
+                },
+                "updateWhitelistedMint" => ProposalAction::UpdateWhitelistedMint {
+                    mint_of_token_whitelisted: values[0].parse().unwrap(),
+                    previous_mint: values
+                        .get(1)
+                        .map(|v| v.parse().unwrap())
+                        .unwrap_or_default(),
+                    sweep_token_account: values
+                        .get(2)
+                        .map(|v| v.parse().unwrap())
+                        .unwrap_or_default(),
+                },
+                "updateRelayerCommittee" => ProposalAction::UpdateRelayerCommittee {
+                    relayers: values[1..].iter().map(|v| v.parse().unwrap()).collect(),
+                    threshold: values[0].parse::<u8>().unwrap(),
+                },
+                _ => unreachable!(),
+            };
+
+            let signature = process_create_proposal(
+                &rpc_client,
+                config.default_signer.as_ref(),
+                bridge_program::ID,
+                action,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: send transaction: {err}");
+                exit(1);
+            });
+            println!("Signature: {signature}");
+        }
+        ("approveProposal", arg_matches) => {
+            let proposal_id = arg_matches.get_one::<String>("proposal_id").unwrap();
+
+            let signature = process_approve_proposal(
+                &rpc_client,
+                config.default_signer.as_ref(),
+                bridge_program::ID,
+                proposal_id.parse::<u64>().unwrap(),
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: send transaction: {err}");
+                exit(1);
+            });
+            println!("Signature: {signature}");
+        }
+        ("setRelayerCommittee", arg_matches) => {
+            let threshold = arg_matches.get_one::<String>("threshold").unwrap();
+            let relayers = pubkeys_of(arg_matches, "relayers").unwrap_or_default();
+
+            let signature = process_set_relayer_committee(
+                &rpc_client,
+                config.default_signer.as_ref(),
+                bridge_program::ID,
+                relayers,
+                threshold.parse::<u8>().unwrap(),
+                arg_matches
+                    .get_one::<String>("proposal_id")
+                    .map(|id| id.parse::<u64>().unwrap()),
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: send transaction: {err}");
+                exit(1);
+            });
+            println!("Signature: {signature}");
+        }
         ("updateRelayer", arg_matches) => {
             let relayer_pk = pubkey_of(arg_matches, "relayer_pk").unwrap();
 
@@ -333,6 +1189,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 config.default_signer.as_ref(),
                 bridge_program::ID,
                 relayer_pk,
+                arg_matches
+                    .get_one::<String>("proposal_id")
+                    .map(|id| id.parse::<u64>().unwrap()),
             )
             .await
             .unwrap_or_else(|err| {
@@ -350,6 +1209,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 config.default_signer.as_ref(),
                 bridge_program::ID,
                 mint_of_token_whitelisted,
+                arg_matches
+                    .get_one::<String>("proposal_id")
+                    .map(|id| id.parse::<u64>().unwrap()),
+                pubkey_of(arg_matches, "previous_mint"),
+                pubkey_of(arg_matches, "sweep_token_account"),
             )
             .await
             .unwrap_or_else(|err| {
@@ -390,6 +1254,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 bridge_program::ID,
                 amount.parse::<u64>().unwrap(),
                 mint_of_token_sent,
+                arg_matches
+                    .get_one::<String>("proposal_id")
+                    .map(|id| id.parse::<u64>().unwrap()),
             )
             .await
             .unwrap_or_else(|err| {
@@ -407,6 +1274,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 &rpc_client,
                 config.default_signer.as_ref(),
                 bridge_program::ID,
+                pubkey_of(arg_matches, "mint").unwrap(),
                 minimum_deposit.parse::<u64>().unwrap(),
                 maximum_deposit.parse::<u64>().unwrap(),
             )
@@ -418,6 +1286,230 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
             println!("Signature: {signature}");
         }
+        ("queueConfigChange", arg_matches) => {
+            let values: Vec<String> = arg_matches
+                .get_many::<String>("values")
+                .unwrap()
+                .cloned()
+                .collect();
+
+            let change =
+                parse_config_change(arg_matches.get_one::<String>("change").unwrap(), &values);
+
+            let signature = process_queue_config_change(
+                &rpc_client,
+                config.default_signer.as_ref(),
+                bridge_program::ID,
+                change,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: send transaction: {err}");
+                exit(1);
+            });
+            println!("Signature: {signature}");
+        }
+        ("cancelConfigChange", arg_matches) => {
+            let change = arg_matches.get_one::<String>("change").unwrap();
+
+            let kind = CONFIG_CHANGES.iter().position(|c| c == change).unwrap();
+
+            let signature = process_cancel_config_change(
+                &rpc_client,
+                config.default_signer.as_ref(),
+                bridge_program::ID,
+                kind as u8,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: send transaction: {err}");
+                exit(1);
+            });
+            println!("Signature: {signature}");
+        }
+        ("setTimelockDelay", arg_matches) => {
+            let timelock_delay = arg_matches.get_one::<String>("timelock_delay").unwrap();
+
+            let signature = process_set_timelock_delay(
+                &rpc_client,
+                config.default_signer.as_ref(),
+                bridge_program::ID,
+                timelock_delay.parse::<i64>().unwrap(),
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: send transaction: {err}");
+                exit(1);
+            });
+            println!("Signature: {signature}");
+        }
+        ("setChainId", arg_matches) => {
+            let chain_id = arg_matches.get_one::<String>("chain_id").unwrap();
+
+            let signature = process_set_chain_id(
+                &rpc_client,
+                config.default_signer.as_ref(),
+                bridge_program::ID,
+                chain_id.parse::<u64>().unwrap(),
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: send transaction: {err}");
+                exit(1);
+            });
+            println!("Signature: {signature}");
+        }
+        ("setRefundPolicy", arg_matches) => {
+            let refund_timeout = arg_matches.get_one::<String>("refund_timeout").unwrap();
+            let refund_fee = arg_matches.get_one::<String>("refund_fee").unwrap();
+
+            let signature = process_set_refund_policy(
+                &rpc_client,
+                config.default_signer.as_ref(),
+                bridge_program::ID,
+                refund_timeout.parse::<i64>().unwrap(),
+                refund_fee.parse::<bool>().unwrap(),
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: send transaction: {err}");
+                exit(1);
+            });
+            println!("Signature: {signature}");
+        }
+        ("setFeeOverride", arg_matches) => {
+            let fee_amount = arg_matches.get_one::<String>("fee_amount").unwrap();
+            let fee_bps = arg_matches.get_one::<String>("fee_bps").unwrap();
+
+            let signature = process_set_fee_override(
+                &rpc_client,
+                config.default_signer.as_ref(),
+                bridge_program::ID,
+                pubkey_of(arg_matches, "mint").unwrap(),
+                pubkey_of(arg_matches, "address").unwrap(),
+                fee_amount.parse::<u64>().unwrap(),
+                fee_bps.parse::<u16>().unwrap(),
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: send transaction: {err}");
+                exit(1);
+            });
+            println!("Signature: {signature}");
+        }
+        ("removeFeeOverride", arg_matches) => {
+            let signature = process_remove_fee_override(
+                &rpc_client,
+                config.default_signer.as_ref(),
+                bridge_program::ID,
+                pubkey_of(arg_matches, "mint").unwrap(),
+                pubkey_of(arg_matches, "address").unwrap(),
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: send transaction: {err}");
+                exit(1);
+            });
+            println!("Signature: {signature}");
+        }
+        ("withdrawFees", arg_matches) => {
+            let amount = arg_matches.get_one::<String>("amount").unwrap();
+
+            let signature = process_withdraw_fees(
+                &rpc_client,
+                config.default_signer.as_ref(),
+                bridge_program::ID,
+                pubkey_of(arg_matches, "mint"),
+                amount.parse::<u64>().unwrap(),
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: send transaction: {err}");
+                exit(1);
+            });
+            println!("Signature: {signature}");
+        }
+        ("reconcile", arg_matches) => {
+            let signature = process_reconcile(
+                &rpc_client,
+                config.default_signer.as_ref(),
+                bridge_program::ID,
+                pubkey_of(arg_matches, "mint").unwrap(),
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: send transaction: {err}");
+                exit(1);
+            });
+            println!("Signature: {signature}");
+        }
+        ("setCircuitBreaker", arg_matches) => {
+            let breaker_release_bps = arg_matches
+                .get_one::<String>("breaker_release_bps")
+                .unwrap();
+            let breaker_window = arg_matches.get_one::<String>("breaker_window").unwrap();
+            let breaker_threshold = arg_matches.get_one::<String>("breaker_threshold").unwrap();
+
+            let signature = process_set_circuit_breaker(
+                &rpc_client,
+                config.default_signer.as_ref(),
+                bridge_program::ID,
+                pubkey_of(arg_matches, "mint").unwrap(),
+                breaker_release_bps.parse::<u16>().unwrap(),
+                breaker_window.parse::<i64>().unwrap(),
+                breaker_threshold.parse::<u64>().unwrap(),
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: send transaction: {err}");
+                exit(1);
+            });
+            println!("Signature: {signature}");
+        }
+        ("setOutflowLimit", arg_matches) => {
+            let outflow_window = arg_matches.get_one::<String>("outflow_window").unwrap();
+            let outflow_cap = arg_matches.get_one::<String>("outflow_cap").unwrap();
+
+            let signature = process_set_outflow_limit(
+                &rpc_client,
+                config.default_signer.as_ref(),
+                bridge_program::ID,
+                pubkey_of(arg_matches, "mint").unwrap(),
+                outflow_window.parse::<i64>().unwrap(),
+                outflow_cap.parse::<u64>().unwrap(),
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: send transaction: {err}");
+                exit(1);
+            });
+            println!("Signature: {signature}");
+        }
+        ("setUserDepositLimits", arg_matches) => {
+            let user_deposit_window = arg_matches
+                .get_one::<String>("user_deposit_window")
+                .unwrap();
+            let user_deposit_cap = arg_matches.get_one::<String>("user_deposit_cap").unwrap();
+            let user_deposit_cooldown = arg_matches
+                .get_one::<String>("user_deposit_cooldown")
+                .unwrap();
+
+            let signature = process_set_user_deposit_limits(
+                &rpc_client,
+                config.default_signer.as_ref(),
+                bridge_program::ID,
+                pubkey_of(arg_matches, "mint").unwrap(),
+                user_deposit_window.parse::<i64>().unwrap(),
+                user_deposit_cap.parse::<u64>().unwrap(),
+                user_deposit_cooldown.parse::<i64>().unwrap(),
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: send transaction: {err}");
+                exit(1);
+            });
+            println!("Signature: {signature}");
+        }
         ("setFeeAmount", arg_matches) => {
             let fee_amount = arg_matches.get_one::<String>("fee_amount").unwrap();
 
@@ -425,6 +1517,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 &rpc_client,
                 config.default_signer.as_ref(),
                 bridge_program::ID,
+                pubkey_of(arg_matches, "mint").unwrap(),
                 fee_amount.parse::<u64>().unwrap(),
             )
             .await
@@ -434,11 +1527,50 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             });
             println!("Signature: {signature}")
         }
-        ("publicPause", _arg_matches) => {
+        ("setVaultCap", arg_matches) => {
+            let vault_cap = arg_matches.get_one::<String>("vault_cap").unwrap();
+
+            let signature = process_set_vault_cap(
+                &rpc_client,
+                config.default_signer.as_ref(),
+                bridge_program::ID,
+                pubkey_of(arg_matches, "mint").unwrap(),
+                vault_cap.parse::<u64>().unwrap(),
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: send transaction: {err}");
+                exit(1);
+            });
+            println!("Signature: {signature}");
+        }
+        ("setBpsFee", arg_matches) => {
+            let fee_bps = arg_matches.get_one::<String>("fee_bps").unwrap();
+            let min_bps_fee = arg_matches.get_one::<String>("min_bps_fee").unwrap();
+            let max_bps_fee = arg_matches.get_one::<String>("max_bps_fee").unwrap();
+
+            let signature = process_set_bps_fee(
+                &rpc_client,
+                config.default_signer.as_ref(),
+                bridge_program::ID,
+                pubkey_of(arg_matches, "mint").unwrap(),
+                fee_bps.parse::<u16>().unwrap(),
+                min_bps_fee.parse::<u64>().unwrap(),
+                max_bps_fee.parse::<u64>().unwrap(),
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: send transaction: {err}");
+                exit(1);
+            });
+            println!("Signature: {signature}")
+        }
+        ("publicPause", arg_matches) => {
             let signature = process_public_pause_contract(
                 &rpc_client,
                 config.default_signer.as_ref(),
                 bridge_program::ID,
+                pubkey_of(arg_matches, "mint"),
             )
             .await
             .unwrap_or_else(|err| {
@@ -448,11 +1580,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
             println!("Signature: {signature}");
         }
-        ("publicUnpause", _arg_matches) => {
+        ("publicUnpause", arg_matches) => {
             let signature = process_public_unpause_contract(
                 &rpc_client,
                 config.default_signer.as_ref(),
                 bridge_program::ID,
+                pubkey_of(arg_matches, "mint"),
             )
             .await
             .unwrap_or_else(|err| {
@@ -462,11 +1595,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
             println!("Signature: {signature}");
         }
-        ("relayerPause", _arg_matches) => {
+        ("relayerPause", arg_matches) => {
             let signature = process_relayer_pause(
                 &rpc_client,
                 config.default_signer.as_ref(),
                 bridge_program::ID,
+                pubkey_of(arg_matches, "mint"),
             )
             .await
             .unwrap_or_else(|err| {
@@ -476,11 +1610,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
             println!("Signature: {signature}");
         }
-        ("relayerUnpause", _arg_matches) => {
+        ("relayerUnpause", arg_matches) => {
             let signature = process_relayer_unpause(
                 &rpc_client,
                 config.default_signer.as_ref(),
                 bridge_program::ID,
+                pubkey_of(arg_matches, "mint"),
             )
             .await
             .unwrap_or_else(|err| {
@@ -519,6 +1654,34 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
             println!("Signature: {signature}");
         }
+        ("setDestinationSignatureActive", _arg_matches) => {
+            let signature = process_set_destination_signature_active(
+                &rpc_client,
+                config.default_signer.as_ref(),
+                bridge_program::ID,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: send transaction: {err}");
+                exit(1);
+            });
+
+            println!("Signature: {signature}");
+        }
+        ("setDestinationSignatureInactive", _arg_matches) => {
+            let signature = process_set_destination_signature_inactive(
+                &rpc_client,
+                config.default_signer.as_ref(),
+                bridge_program::ID,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: send transaction: {err}");
+                exit(1);
+            });
+
+            println!("Signature: {signature}");
+        }
         ("addToWhitelist", arg_matches) => {
             let entry_pk = pubkey_of(arg_matches, "entry_pk").unwrap();
 
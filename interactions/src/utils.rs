@@ -1,4 +1,9 @@
 use anchor_client::anchor_lang;
+use bridge_program::states::ConfigChange;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::instruction::AccountMeta;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
 
 pub fn get_function_hash(namespace: &str, name: &str) -> [u8; 8] {
     let preimage = format!("{}:{}", namespace, name);
@@ -8,3 +13,120 @@ pub fn get_function_hash(namespace: &str, name: &str) -> [u8; 8] {
     );
     sighash
 }
+
+// Anchor treats the program id as a missing optional account
+pub fn get_multisig_accounts(program_id: &Pubkey, proposal_id: Option<u64>) -> Vec<AccountMeta> {
+    match proposal_id {
+        Some(proposal_id) => {
+            let (multisig_pda, _) = Pubkey::find_program_address(&[b"multisig"], program_id);
+            let (proposal_pda, _) = Pubkey::find_program_address(
+                &[b"proposal", &proposal_id.to_le_bytes()],
+                program_id,
+            );
+
+            vec![
+                AccountMeta::new_readonly(multisig_pda, false),
+                AccountMeta::new(proposal_pda, false),
+            ]
+        }
+        None => vec![
+            AccountMeta::new_readonly(*program_id, false),
+            AccountMeta::new_readonly(*program_id, false),
+        ],
+    }
+}
+
+// Anchor treats the program id as a missing optional account
+pub fn get_token_config_account(program_id: &Pubkey, mint: Option<Pubkey>) -> AccountMeta {
+    match mint {
+        Some(mint) => {
+            let (token_config_pda, _) =
+                Pubkey::find_program_address(&[b"token_config", mint.as_ref()], program_id);
+
+            AccountMeta::new(token_config_pda, false)
+        }
+        None => AccountMeta::new_readonly(*program_id, false),
+    }
+}
+
+// The mint account is owned by its token program, either the legacy one or Token-2022
+pub async fn get_token_program(
+    rpc_client: &RpcClient,
+    mint: &Pubkey,
+) -> Result<Pubkey, Box<dyn std::error::Error>> {
+    let mint_account = rpc_client
+        .get_account(mint)
+        .await
+        .map_err(|err| format!("error: unable to get mint account: {err}"))?;
+
+    Ok(mint_account.owner)
+}
+
+// Previous token config, vault and mint plus the sweep account when migrating from an old mint,
+// and the previous token program when it differs from the new mint's one
+pub fn get_migration_accounts(
+    program_id: &Pubkey,
+    token_program: &Pubkey,
+    previous_mint: Option<(Pubkey, Pubkey)>,
+    sweep_token_account: Option<Pubkey>,
+) -> Vec<AccountMeta> {
+    let mut accounts = match previous_mint {
+        Some((previous_mint, previous_token_program)) => {
+            let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge_state"], program_id);
+            let (previous_token_config_pda, _) = Pubkey::find_program_address(
+                &[b"token_config", previous_mint.as_ref()],
+                program_id,
+            );
+
+            vec![
+                AccountMeta::new(previous_token_config_pda, false),
+                AccountMeta::new(
+                    get_associated_token_address_with_program_id(
+                        &bridge_pda,
+                        &previous_mint,
+                        &previous_token_program,
+                    ),
+                    false,
+                ),
+                AccountMeta::new_readonly(previous_mint, false),
+            ]
+        }
+        None => vec![AccountMeta::new_readonly(*program_id, false); 3],
+    };
+
+    accounts.push(match sweep_token_account {
+        Some(sweep_token_account) => AccountMeta::new(sweep_token_account, false),
+        None => AccountMeta::new_readonly(*program_id, false),
+    });
+
+    accounts.push(match previous_mint {
+        Some((_, previous_token_program)) if previous_token_program != *token_program => {
+            AccountMeta::new_readonly(previous_token_program, false)
+        }
+        _ => AccountMeta::new_readonly(*program_id, false),
+    });
+
+    accounts
+}
+
+// Passes the queued change for this kind of config change if one exists, so the
+// same command works with and without a timelock delay set
+pub async fn get_queued_change_account(
+    rpc_client: &RpcClient,
+    program_id: &Pubkey,
+    change: &ConfigChange,
+) -> AccountMeta {
+    let (queued_change_pda, _) = Pubkey::find_program_address(
+        &[
+            b"config_change",
+            &change.kind().to_le_bytes(),
+            change.scope().as_ref(),
+        ],
+        program_id,
+    );
+
+    match rpc_client.get_account(&queued_change_pda).await {
+        Ok(_) => AccountMeta::new(queued_change_pda, false),
+        Err(_) => AccountMeta::new_readonly(*program_id, false),
+    }
+}
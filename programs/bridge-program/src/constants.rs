@@ -1,4 +1,16 @@
-use solana_program::pubkey;
-use solana_program::pubkey::Pubkey;
+// erd1 bech32 addresses are 62 characters long
+pub const MAX_DESTINATION_ADDRESS_LEN: usize = 62;
 
-pub const ADMIN_PUBKEY: Pubkey = pubkey!("AxDG4CDKrn8s3a1caY69nQYCjR8YnxqjhMPwhUGFKL2Q");
+// human readable part of MultiversX bech32 addresses
+pub const DESTINATION_ADDRESS_HRP: &str = "erd";
+
+// prefix MultiversX wallets put in front of a message before hashing and signing it
+pub const MULTIVERSX_MESSAGE_PREFIX: &[u8] = b"\x17Elrond Signed Message:\n";
+
+pub const MAX_MULTISIG_SIGNERS: usize = 10;
+
+// 100% expressed in basis points
+pub const MAX_FEE_BPS: u16 = 10_000;
+
+// a queued config change can be applied for this long after its eta, then it has to be queued again
+pub const CONFIG_CHANGE_GRACE_PERIOD: i64 = 14 * 24 * 60 * 60;
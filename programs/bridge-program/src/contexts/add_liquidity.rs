@@ -1,60 +1,93 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{transfer_checked, Mint, Token, TokenAccount, TransferChecked},
+    token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked},
 };
 
-use crate::{constants::ADMIN_PUBKEY, states::BridgeState, Errors};
+use crate::{
+    check_vault_amount,
+    events::LiquidityChanged,
+    states::{BridgeState, TokenConfig},
+    transfer_checked_with_hook, transfer_fee, Errors,
+};
 
 #[derive(Accounts)]
 #[instruction(amount: u64)]
 pub struct AddLiquidity<'info> {
     #[account(
-        mut,
         seeds=["bridge_state".as_ref()],
         bump=bridge_state.bump,
-        has_one=vault,
     )]
     pub bridge_state: Box<Account<'info, BridgeState>>,
 
     #[account(
         mut,
-        associated_token::mint=mint_of_token_sent,
-        associated_token::authority=bridge_state
+        seeds=["token_config".as_ref(), mint_of_token_sent.key().as_ref()],
+        bump=token_config.bump,
+        has_one=vault,
     )]
-    pub vault: Box<Account<'info, TokenAccount>>,
+    pub token_config: Box<Account<'info, TokenConfig>>,
 
     #[account(
         mut,
-        address=ADMIN_PUBKEY,
+        associated_token::mint=mint_of_token_sent,
+        associated_token::authority=bridge_state,
+        associated_token::token_program=token_program,
     )]
-    pub authority: Signer<'info>,
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
-        constraint=mint_of_token_sent.key()==bridge_state.mint_of_token_whitelisted,
+        mut,
+        address=bridge_state.liquidity_manager @ Errors::NotPrivileged,
     )]
-    pub mint_of_token_sent: Account<'info, Mint>,
+    pub authority: Signer<'info>,
+
+    pub mint_of_token_sent: InterfaceAccount<'info, Mint>,
 
     #[account(mut,
         constraint= authority_token_account.amount >= amount @ Errors::NotEnoughBalance,
         constraint=authority_token_account.owner==authority.key() @ Errors::OwnerMismatch,
         constraint=authority_token_account.mint==mint_of_token_sent.key() @ Errors::MintMismatch,
     )]
-    pub authority_token_account: Account<'info, TokenAccount>,
+    pub authority_token_account: InterfaceAccount<'info, TokenAccount>,
 
     system_program: Program<'info, System>,
-    token_program: Program<'info, Token>,
+    token_program: Interface<'info, TokenInterface>,
     associated_token_program: Program<'info, AssociatedToken>,
 }
 
 impl<'info> AddLiquidity<'info> {
-    pub fn add_liquidity(&mut self, amount: u64) -> Result<()> {
-        self.bridge_state.vault_amount += amount;
-        transfer_checked(
-            self.into_add_liquidity_context(),
+    pub fn add_liquidity(
+        &mut self,
+        amount: u64,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        // a Token-2022 transfer fee is withheld on the way in, the vault is only credited the rest
+        let received = amount - transfer_fee(&self.mint_of_token_sent.to_account_info(), amount)?;
+
+        self.token_config.vault_amount = self
+            .token_config
+            .vault_amount
+            .checked_add(received)
+            .ok_or(Errors::Overflow)?;
+        transfer_checked_with_hook(
+            self.into_add_liquidity_context()
+                .with_remaining_accounts(remaining_accounts.to_vec()),
             amount,
             self.mint_of_token_sent.decimals,
-        )
+        )?;
+
+        check_vault_amount(&mut self.vault, &self.token_config)?;
+
+        emit!(LiquidityChanged {
+            authority: self.authority.key(),
+            mint: self.mint_of_token_sent.key(),
+            amount,
+            added: true,
+            vault_amount: self.token_config.vault_amount,
+        });
+
+        Ok(())
     }
 
     fn into_add_liquidity_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
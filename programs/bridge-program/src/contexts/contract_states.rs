@@ -1,8 +1,8 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::ADMIN_PUBKEY,
-    states::{bridge::State, BridgeState},
+    events::PauseChanged,
+    states::{bridge::State, BridgeState, TokenConfig},
     Errors,
 };
 
@@ -17,21 +17,72 @@ pub struct RelayerState<'info> {
 
     #[account(
         mut,
-    address=ADMIN_PUBKEY @ Errors::NotPrivileged,
+        constraint= authority.key() == bridge_state.pauser || authority.key() == bridge_state.admin @ Errors::NotPrivileged,
     )]
     pub authority: Signer<'info>,
 
+    #[account(
+        mut,
+        seeds=["token_config".as_ref(), token_config.mint.as_ref()],
+        bump=token_config.bump,
+    )]
+    pub token_config: Option<Box<Account<'info, TokenConfig>>>,
+
     system_program: Program<'info, System>,
 }
 
 impl<'info> RelayerState<'info> {
+    // with a token config the flags of that token are changed, otherwise the global ones
+    fn emit_pause_changed(&self) {
+        let (mint, relayer_state, public_state) = match &self.token_config {
+            Some(token_config) => (
+                Some(token_config.mint),
+                token_config.relayer_state,
+                token_config.public_state,
+            ),
+            None => (
+                None,
+                self.bridge_state.relayer_state,
+                self.bridge_state.public_state,
+            ),
+        };
+
+        emit!(PauseChanged {
+            authority: self.authority.key(),
+            mint,
+            relayer_state,
+            public_state,
+        });
+    }
+
+    fn set_relayer_state(&mut self, state: State) {
+        match self.token_config.as_mut() {
+            Some(token_config) => token_config.relayer_state = state.to_code(),
+            None => self.bridge_state.relayer_state = state.to_code(),
+        }
+    }
+
     pub fn relayer_pause(&mut self) -> Result<()> {
-        self.bridge_state.relayer_state = State::Inactive.to_code();
+        require!(
+            self.authority.key() == self.bridge_state.pauser,
+            Errors::NotPrivileged
+        );
+
+        self.set_relayer_state(State::Inactive);
+        self.emit_pause_changed();
         Ok(())
     }
 
+    // the admin reactivates a token (e.g. after a circuit breaker trip), the pauser the global flag
     pub fn relayer_unpause(&mut self) -> Result<()> {
-        self.bridge_state.relayer_state = State::Active.to_code();
+        let unpauser = match &self.token_config {
+            Some(_) => self.bridge_state.admin,
+            None => self.bridge_state.pauser,
+        };
+        require!(self.authority.key() == unpauser, Errors::NotPrivileged);
+
+        self.set_relayer_state(State::Active);
+        self.emit_pause_changed();
         Ok(())
     }
 }
@@ -47,21 +98,86 @@ pub struct PublicState<'info> {
 
     #[account(
         mut,
-        constraint= authority.key() == ADMIN_PUBKEY || authority.key() == bridge_state.relayer_pubkey.key() @ Errors::NotPrivileged,
+        constraint= authority.key() == bridge_state.pauser || authority.key() == bridge_state.relayer_pubkey.key() || authority.key() == bridge_state.admin @ Errors::NotPrivileged,
     )]
     pub authority: Signer<'info>,
 
+    #[account(
+        mut,
+        seeds=["token_config".as_ref(), token_config.mint.as_ref()],
+        bump=token_config.bump,
+    )]
+    pub token_config: Option<Box<Account<'info, TokenConfig>>>,
+
     system_program: Program<'info, System>,
 }
 
 impl<'info> PublicState<'info> {
+    // with a token config the flags of that token are changed, otherwise the global ones
+    fn emit_pause_changed(&self) {
+        let (mint, relayer_state, public_state) = match &self.token_config {
+            Some(token_config) => (
+                Some(token_config.mint),
+                token_config.relayer_state,
+                token_config.public_state,
+            ),
+            None => (
+                None,
+                self.bridge_state.relayer_state,
+                self.bridge_state.public_state,
+            ),
+        };
+
+        emit!(PauseChanged {
+            authority: self.authority.key(),
+            mint,
+            relayer_state,
+            public_state,
+        });
+    }
+
+    fn set_public_state(&mut self, state: State) {
+        match self.token_config.as_mut() {
+            Some(token_config) => token_config.public_state = state.to_code(),
+            None => self.bridge_state.public_state = state.to_code(),
+        }
+    }
+
     pub fn public_pause(&mut self) -> Result<()> {
-        self.bridge_state.public_state = State::Inactive.to_code();
+        require!(
+            self.authority.key() == self.bridge_state.pauser
+                || self.authority.key() == self.bridge_state.relayer_pubkey,
+            Errors::NotPrivileged
+        );
+
+        self.set_public_state(State::Inactive);
+        self.emit_pause_changed();
         Ok(())
     }
 
+    // only the admin opens deposits of a token, and only once its deposit limits are set
     pub fn public_unpause(&mut self) -> Result<()> {
-        self.bridge_state.public_state = State::Active.to_code();
+        match &self.token_config {
+            Some(token_config) => {
+                require!(
+                    self.authority.key() == self.bridge_state.admin,
+                    Errors::NotPrivileged
+                );
+                require!(
+                    token_config.minimum_deposit > 0
+                        && token_config.minimum_deposit <= token_config.maximum_deposit,
+                    Errors::InvalidDepositLimits
+                );
+            }
+            None => require!(
+                self.authority.key() == self.bridge_state.pauser
+                    || self.authority.key() == self.bridge_state.relayer_pubkey,
+                Errors::NotPrivileged
+            ),
+        }
+
+        self.set_public_state(State::Active);
+        self.emit_pause_changed();
         Ok(())
     }
 }
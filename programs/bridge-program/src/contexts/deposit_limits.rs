@@ -1,11 +1,16 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::ADMIN_PUBKEY, states::BridgeState};
+use crate::{
+    constants::MAX_FEE_BPS,
+    events::ConfigChanged,
+    execute_queued_config_change,
+    states::{BridgeState, ConfigChange, QueuedConfigChange, TokenConfig},
+    Errors,
+};
 
 #[derive(Accounts)]
 pub struct UpdateLimitsOrFee<'info> {
     #[account(
-        mut,
         seeds=["bridge_state".as_ref()],
         bump=bridge_state.bump,
     )]
@@ -13,22 +18,174 @@ pub struct UpdateLimitsOrFee<'info> {
 
     #[account(
         mut,
-        address = ADMIN_PUBKEY,
+        seeds=["token_config".as_ref(), token_config.mint.as_ref()],
+        bump=token_config.bump,
+    )]
+    pub token_config: Box<Account<'info, TokenConfig>>,
+
+    #[account(
+        mut,
+        address=bridge_state.fee_manager @ Errors::NotPrivileged,
     )]
     pub authority: Signer<'info>,
 
+    #[account(
+        mut,
+        seeds=[
+            "config_change".as_ref(),
+            queued_change.change.kind().to_le_bytes().as_ref(),
+            queued_change.change.scope().as_ref(),
+        ],
+        bump=queued_change.bump,
+    )]
+    pub queued_change: Option<Box<Account<'info, QueuedConfigChange>>>,
+
     system_program: Program<'info, System>,
 }
 
 impl<'info> UpdateLimitsOrFee<'info> {
     pub fn set_deposit_limits(&mut self, minimum_deposit: u64, maximum_deposit: u64) -> Result<()> {
-        self.bridge_state.minimum_deposit = minimum_deposit;
-        self.bridge_state.maximum_deposit = maximum_deposit;
+        require!(
+            minimum_deposit > 0 && minimum_deposit <= maximum_deposit,
+            Errors::InvalidDepositLimits
+        );
+
+        let change = ConfigChange::DepositLimits {
+            mint: self.token_config.mint,
+            minimum_deposit,
+            maximum_deposit,
+        };
+
+        execute_queued_config_change(
+            &self.bridge_state,
+            &self.queued_change,
+            self.authority.to_account_info(),
+            &change,
+        )?;
+
+        self.token_config.minimum_deposit = minimum_deposit;
+        self.token_config.maximum_deposit = maximum_deposit;
+
+        emit!(ConfigChanged {
+            authority: self.authority.key(),
+            change,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_vault_cap(&mut self, vault_cap: u64) -> Result<()> {
+        let change = ConfigChange::VaultCap {
+            mint: self.token_config.mint,
+            vault_cap,
+        };
+
+        execute_queued_config_change(
+            &self.bridge_state,
+            &self.queued_change,
+            self.authority.to_account_info(),
+            &change,
+        )?;
+
+        self.token_config.vault_cap = vault_cap;
+
+        emit!(ConfigChanged {
+            authority: self.authority.key(),
+            change,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_user_deposit_limits(
+        &mut self,
+        user_deposit_window: i64,
+        user_deposit_cap: u64,
+        user_deposit_cooldown: i64,
+    ) -> Result<()> {
+        require!(
+            (user_deposit_cap == 0 || user_deposit_window > 0) && user_deposit_cooldown >= 0,
+            Errors::InvalidUserDepositLimits
+        );
+
+        let change = ConfigChange::UserDepositLimits {
+            mint: self.token_config.mint,
+            user_deposit_window,
+            user_deposit_cap,
+            user_deposit_cooldown,
+        };
+
+        execute_queued_config_change(
+            &self.bridge_state,
+            &self.queued_change,
+            self.authority.to_account_info(),
+            &change,
+        )?;
+
+        self.token_config.user_deposit_window = user_deposit_window;
+        self.token_config.user_deposit_cap = user_deposit_cap;
+        self.token_config.user_deposit_cooldown = user_deposit_cooldown;
+
+        emit!(ConfigChanged {
+            authority: self.authority.key(),
+            change,
+        });
+
         Ok(())
     }
 
     pub fn set_fee_amount(&mut self, fee_amount: u64) -> Result<()> {
-        self.bridge_state.fee_amount = fee_amount;
+        let change = ConfigChange::FeeAmount {
+            mint: self.token_config.mint,
+            fee_amount,
+        };
+
+        execute_queued_config_change(
+            &self.bridge_state,
+            &self.queued_change,
+            self.authority.to_account_info(),
+            &change,
+        )?;
+
+        self.token_config.fee_amount = fee_amount;
+
+        emit!(ConfigChanged {
+            authority: self.authority.key(),
+            change,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_bps_fee(&mut self, fee_bps: u16, min_bps_fee: u64, max_bps_fee: u64) -> Result<()> {
+        require!(
+            fee_bps <= MAX_FEE_BPS && (max_bps_fee == 0 || min_bps_fee <= max_bps_fee),
+            Errors::InvalidFeeConfig
+        );
+
+        let change = ConfigChange::BpsFee {
+            mint: self.token_config.mint,
+            fee_bps,
+            min_bps_fee,
+            max_bps_fee,
+        };
+
+        execute_queued_config_change(
+            &self.bridge_state,
+            &self.queued_change,
+            self.authority.to_account_info(),
+            &change,
+        )?;
+
+        self.token_config.fee_bps = fee_bps;
+        self.token_config.min_bps_fee = min_bps_fee;
+        self.token_config.max_bps_fee = max_bps_fee;
+
+        emit!(ConfigChanged {
+            authority: self.authority.key(),
+            change,
+        });
+
         Ok(())
     }
 }
@@ -0,0 +1,291 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::{
+    check_vault_amount,
+    events::DepositRefunded,
+    states::{BridgeState, DepositRecord, DepositStatus, RefundReason, TokenConfig},
+    transfer_checked_with_hook, Errors,
+};
+
+#[derive(Accounts)]
+#[instruction(deposit_id: u64)]
+pub struct ClaimRefund<'info> {
+    #[account(
+        seeds=["bridge_state".as_ref()],
+        bump=bridge_state.bump,
+    )]
+    pub bridge_state: Box<Account<'info, BridgeState>>,
+
+    #[account(
+        mut,
+        seeds=["token_config".as_ref(), mint_of_token_sent.key().as_ref()],
+        bump=token_config.bump,
+        has_one=vault,
+    )]
+    pub token_config: Box<Account<'info, TokenConfig>>,
+
+    #[account(
+        mut,
+        associated_token::mint=mint_of_token_sent,
+        associated_token::authority=bridge_state,
+        associated_token::token_program=token_program,
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds=["deposit_record".as_ref(), deposit_id.to_le_bytes().as_ref()],
+        bump=deposit_record.bump,
+        constraint=deposit_record.depositor==authority.key() @ Errors::NotPrivileged,
+        constraint=deposit_record.mint==mint_of_token_sent.key() @ Errors::MintMismatch,
+    )]
+    pub deposit_record: Box<Account<'info, DepositRecord>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub mint_of_token_sent: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint=authority_token_account.owner==authority.key() @ Errors::OwnerMismatch,
+        constraint=authority_token_account.mint==mint_of_token_sent.key() @ Errors::MintMismatch,
+    )]
+    pub authority_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds=["fee_vault".as_ref()],
+        bump,
+    )]
+    pub fee_vault: Option<SystemAccount<'info>>,
+
+    #[account(
+        mut,
+        associated_token::mint=mint_of_token_sent,
+        associated_token::authority=fee_vault,
+        associated_token::token_program=token_program,
+    )]
+    pub fee_vault_token_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        constraint=mint_of_fee_token_sent.key()==spl_token::native_mint::ID @ Errors::MintMismatch,
+        constraint=token_program.key()==spl_token::ID @ Errors::FeeTokenProgramMismatch,
+    )]
+    pub mint_of_fee_token_sent: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint=mint_of_fee_token_sent,
+        associated_token::authority=fee_vault,
+        associated_token::token_program=token_program,
+    )]
+    pub fee_vault_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut,
+        constraint=authority_fee_token_account.owner==authority.key() @ Errors::OwnerMismatch,
+        constraint=authority_fee_token_account.mint==spl_token::native_mint::ID @ Errors::MintMismatch,
+    )]
+    pub authority_fee_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    system_program: Program<'info, System>,
+    token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ClaimRefund<'info> {
+    pub fn claim_refund(
+        &mut self,
+        bumps: &ClaimRefundBumps,
+        deposit_id: u64,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        require!(self.deposit_record.is_pending(), Errors::DepositNotPending);
+        require!(
+            self.bridge_state.refund_timeout > 0,
+            Errors::RefundsDisabled
+        );
+
+        let refundable_at = self
+            .deposit_record
+            .timestamp
+            .checked_add(self.bridge_state.refund_timeout)
+            .ok_or(Errors::Overflow)?;
+
+        require!(
+            Clock::get()?.unix_timestamp >= refundable_at,
+            Errors::RefundTimeoutNotElapsed
+        );
+
+        self.deposit_record.status = DepositStatus::Refunded.to_code();
+        self.deposit_record.refund_reason = RefundReason::Timeout.to_code();
+
+        let amount = self.deposit_record.amount;
+
+        self.token_config.vault_amount = self
+            .token_config
+            .vault_amount
+            .checked_sub(amount)
+            .ok_or(Errors::Overflow)?;
+
+        self.token_config.pending_amount = self
+            .token_config
+            .pending_amount
+            .checked_sub(amount)
+            .ok_or(Errors::Overflow)?;
+
+        let signer_seeds: [&[&[u8]]; 1] = [&[b"bridge_state", &[self.bridge_state.bump]]];
+
+        transfer_checked_with_hook(
+            self.into_refund_context()
+                .with_signer(&signer_seeds)
+                .with_remaining_accounts(remaining_accounts.to_vec()),
+            amount,
+            self.mint_of_token_sent.decimals,
+        )?;
+
+        check_vault_amount(&mut self.vault, &self.token_config)?;
+
+        // the fees are paid back out of the fee vault as far as they have not been withdrawn,
+        // a fee that cannot be refunded is skipped so the deposited amount is always paid back
+        let (fee, token_fee) = if self.bridge_state.refund_fee {
+            (self.refundable_fee()?, self.refundable_token_fee())
+        } else {
+            (0, 0)
+        };
+
+        if fee > 0 || token_fee > 0 {
+            let fee_vault_seeds: [&[&[u8]]; 1] = [&[b"fee_vault", &[bumps.fee_vault.unwrap()]]];
+
+            if fee > 0 {
+                if self.deposit_record.fee_wsol {
+                    transfer_checked_with_hook(
+                        self.into_refund_wsol_fee_context()
+                            .with_signer(&fee_vault_seeds),
+                        fee,
+                        self.mint_of_fee_token_sent.as_ref().unwrap().decimals,
+                    )?;
+                } else {
+                    transfer(
+                        self.into_refund_fee_context().with_signer(&fee_vault_seeds),
+                        fee,
+                    )?;
+                }
+            }
+
+            if token_fee > 0 {
+                transfer_checked_with_hook(
+                    self.into_refund_token_fee_context()
+                        .with_signer(&fee_vault_seeds)
+                        .with_remaining_accounts(remaining_accounts.to_vec()),
+                    token_fee,
+                    self.mint_of_token_sent.decimals,
+                )?;
+            }
+        }
+
+        emit!(DepositRefunded {
+            deposit_id,
+            depositor: self.authority.key(),
+            mint: self.mint_of_token_sent.key(),
+            amount,
+            fee,
+            token_fee,
+            reason: RefundReason::Timeout.to_code(),
+        });
+
+        Ok(())
+    }
+
+    // the flat fee, if the accounts of the currency it was paid in are provided and the fee vault still holds it
+    fn refundable_fee(&self) -> Result<u64> {
+        let fee = self.deposit_record.fee;
+
+        let Some(fee_vault) = self.fee_vault.as_ref() else {
+            return Ok(0);
+        };
+
+        let refundable = if self.deposit_record.fee_wsol {
+            self.mint_of_fee_token_sent.is_some()
+                && self.authority_fee_token_account.is_some()
+                && self
+                    .fee_vault_ata
+                    .as_ref()
+                    .is_some_and(|fee_vault_ata| fee_vault_ata.amount >= fee)
+        } else {
+            fee_vault.lamports().saturating_sub(fee) >= Rent::get()?.minimum_balance(0)
+        };
+
+        Ok(if refundable { fee } else { 0 })
+    }
+
+    // the basis points fee, if the fee vault token account is provided and still holds it
+    fn refundable_token_fee(&self) -> u64 {
+        let token_fee = self.deposit_record.token_fee;
+
+        let refundable = self.fee_vault.is_some()
+            && self
+                .fee_vault_token_ata
+                .as_ref()
+                .is_some_and(|fee_vault_token_ata| fee_vault_token_ata.amount >= token_fee);
+
+        if refundable {
+            token_fee
+        } else {
+            0
+        }
+    }
+
+    fn into_refund_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.vault.to_account_info(),
+            mint: self.mint_of_token_sent.to_account_info(),
+            to: self.authority_token_account.to_account_info(),
+            authority: self.bridge_state.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+
+    fn into_refund_fee_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.fee_vault.as_ref().unwrap().to_account_info(),
+            to: self.authority.to_account_info(),
+        };
+        CpiContext::new(self.system_program.to_account_info(), cpi_accounts)
+    }
+
+    fn into_refund_wsol_fee_context(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.fee_vault_ata.as_ref().unwrap().to_account_info(),
+            mint: self
+                .mint_of_fee_token_sent
+                .as_ref()
+                .unwrap()
+                .to_account_info(),
+            to: self
+                .authority_fee_token_account
+                .as_ref()
+                .unwrap()
+                .to_account_info(),
+            authority: self.fee_vault.as_ref().unwrap().to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+
+    fn into_refund_token_fee_context(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.fee_vault_token_ata.as_ref().unwrap().to_account_info(),
+            mint: self.mint_of_token_sent.to_account_info(),
+            to: self.authority_token_account.to_account_info(),
+            authority: self.fee_vault.as_ref().unwrap().to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
@@ -0,0 +1,70 @@
+use anchor_lang::{prelude::*, solana_program::sysvar::instructions as sysvar_instructions};
+
+use crate::{
+    check_deposit_status_approval, deposit_status_message,
+    events::DepositInFlight,
+    states::{BridgeState, DepositRecord, DepositStatus, RelayerCommittee},
+    Errors,
+};
+
+#[derive(Accounts)]
+#[instruction(deposit_id: u64)]
+pub struct MarkDepositInFlight<'info> {
+    #[account(
+        seeds=["bridge_state".as_ref()],
+        bump=bridge_state.bump,
+    )]
+    pub bridge_state: Box<Account<'info, BridgeState>>,
+
+    #[account(
+        mut,
+        seeds=["deposit_record".as_ref(), deposit_id.to_le_bytes().as_ref()],
+        bump=deposit_record.bump,
+    )]
+    pub deposit_record: Box<Account<'info, DepositRecord>>,
+
+    #[account(
+        seeds=["relayer_committee".as_ref()],
+        bump=relayer_committee.bump,
+    )]
+    pub relayer_committee: Option<Box<Account<'info, RelayerCommittee>>>,
+
+    #[account(
+        constraint=bridge_state.is_relayer(&authority.key(), relayer_committee.as_deref().map(|c| &**c)) @ Errors::NotPrivileged,
+    )]
+    pub authority: Signer<'info>,
+
+    /// CHECK: address checked, only read through the instructions sysvar helpers
+    #[account(address=sysvar_instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> MarkDepositInFlight<'info> {
+    pub fn mark_deposit_in_flight(&mut self, deposit_id: u64) -> Result<()> {
+        // once in flight, the depositor can no longer claim a refund while the relayer delivers it
+        require!(self.deposit_record.is_pending(), Errors::DepositNotPending);
+
+        check_deposit_status_approval(
+            &self.bridge_state,
+            self.relayer_committee.as_deref().map(|c| &**c),
+            self.instructions_sysvar
+                .as_ref()
+                .map(|instructions_sysvar| instructions_sysvar.as_ref()),
+            &deposit_status_message(
+                deposit_id,
+                DepositStatus::InFlight.to_code(),
+                0,
+                self.bridge_state.chain_id,
+            ),
+        )?;
+
+        self.deposit_record.status = DepositStatus::InFlight.to_code();
+
+        emit!(DepositInFlight {
+            deposit_id,
+            relayer: self.authority.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+use anchor_lang::{prelude::*, solana_program::sysvar::instructions as sysvar_instructions};
+
+use crate::{
+    check_deposit_status_approval, deposit_status_message,
+    events::DepositProcessed,
+    states::{BridgeState, DepositRecord, DepositStatus, RelayerCommittee, TokenConfig},
+    Errors,
+};
+
+#[derive(Accounts)]
+#[instruction(deposit_id: u64)]
+pub struct MarkDepositProcessed<'info> {
+    #[account(
+        seeds=["bridge_state".as_ref()],
+        bump=bridge_state.bump,
+    )]
+    pub bridge_state: Box<Account<'info, BridgeState>>,
+
+    #[account(
+        mut,
+        seeds=["deposit_record".as_ref(), deposit_id.to_le_bytes().as_ref()],
+        bump=deposit_record.bump,
+    )]
+    pub deposit_record: Box<Account<'info, DepositRecord>>,
+
+    #[account(
+        mut,
+        seeds=["token_config".as_ref(), deposit_record.mint.as_ref()],
+        bump=token_config.bump,
+    )]
+    pub token_config: Box<Account<'info, TokenConfig>>,
+
+    #[account(
+        seeds=["relayer_committee".as_ref()],
+        bump=relayer_committee.bump,
+    )]
+    pub relayer_committee: Option<Box<Account<'info, RelayerCommittee>>>,
+
+    #[account(
+        constraint=bridge_state.is_relayer(&authority.key(), relayer_committee.as_deref().map(|c| &**c)) @ Errors::NotPrivileged,
+    )]
+    pub authority: Signer<'info>,
+
+    /// CHECK: address checked, only read through the instructions sysvar helpers
+    #[account(address=sysvar_instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> MarkDepositProcessed<'info> {
+    pub fn mark_deposit_processed(&mut self, deposit_id: u64) -> Result<()> {
+        require!(self.deposit_record.is_open(), Errors::DepositNotPending);
+
+        check_deposit_status_approval(
+            &self.bridge_state,
+            self.relayer_committee.as_deref().map(|c| &**c),
+            self.instructions_sysvar
+                .as_ref()
+                .map(|instructions_sysvar| instructions_sysvar.as_ref()),
+            &deposit_status_message(
+                deposit_id,
+                DepositStatus::Processed.to_code(),
+                0,
+                self.bridge_state.chain_id,
+            ),
+        )?;
+
+        self.deposit_record.status = DepositStatus::Processed.to_code();
+
+        // delivered on MultiversX, the deposit stays in the vault as liquidity but is no longer
+        // refundable
+        self.token_config.pending_amount = self
+            .token_config
+            .pending_amount
+            .checked_sub(self.deposit_record.amount)
+            .ok_or(Errors::Overflow)?;
+
+        emit!(DepositProcessed {
+            deposit_id,
+            relayer: self.authority.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,8 @@
+pub mod mark_deposit_processed;
+pub use mark_deposit_processed::*;
+pub mod mark_deposit_in_flight;
+pub use mark_deposit_in_flight::*;
+pub mod claim_refund;
+pub use claim_refund::*;
+pub mod refund_deposit;
+pub use refund_deposit::*;
@@ -0,0 +1,149 @@
+use anchor_lang::{prelude::*, solana_program::sysvar::instructions as sysvar_instructions};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::{
+    check_deposit_status_approval, check_vault_amount, deposit_status_message,
+    events::DepositRefunded,
+    states::{
+        BridgeState, DepositRecord, DepositStatus, RefundReason, RelayerCommittee, TokenConfig,
+    },
+    transfer_checked_with_hook, Errors,
+};
+
+#[derive(Accounts)]
+#[instruction(deposit_id: u64)]
+pub struct RefundDeposit<'info> {
+    #[account(
+        seeds=["bridge_state".as_ref()],
+        bump=bridge_state.bump,
+    )]
+    pub bridge_state: Box<Account<'info, BridgeState>>,
+
+    #[account(
+        mut,
+        seeds=["token_config".as_ref(), mint_of_token_sent.key().as_ref()],
+        bump=token_config.bump,
+        has_one=vault,
+    )]
+    pub token_config: Box<Account<'info, TokenConfig>>,
+
+    #[account(
+        mut,
+        associated_token::mint=mint_of_token_sent,
+        associated_token::authority=bridge_state,
+        associated_token::token_program=token_program,
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds=["deposit_record".as_ref(), deposit_id.to_le_bytes().as_ref()],
+        bump=deposit_record.bump,
+        constraint=deposit_record.mint==mint_of_token_sent.key() @ Errors::MintMismatch,
+    )]
+    pub deposit_record: Box<Account<'info, DepositRecord>>,
+
+    #[account(
+        seeds=["relayer_committee".as_ref()],
+        bump=relayer_committee.bump,
+    )]
+    pub relayer_committee: Option<Box<Account<'info, RelayerCommittee>>>,
+
+    #[account(
+        constraint=bridge_state.is_relayer(&authority.key(), relayer_committee.as_deref().map(|c| &**c)) @ Errors::NotPrivileged,
+    )]
+    pub authority: Signer<'info>,
+
+    pub mint_of_token_sent: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint=depositor_token_account.owner==deposit_record.depositor @ Errors::OwnerMismatch,
+        constraint=depositor_token_account.mint==mint_of_token_sent.key() @ Errors::MintMismatch,
+    )]
+    pub depositor_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: address checked, only read through the instructions sysvar helpers
+    #[account(address=sysvar_instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+    token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> RefundDeposit<'info> {
+    pub fn refund_deposit(
+        &mut self,
+        deposit_id: u64,
+        reason: RefundReason,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        require!(reason != RefundReason::Timeout, Errors::InvalidRefundReason);
+        // a deposit that was processed or already refunded can never be refunded again
+        require!(self.deposit_record.is_open(), Errors::DepositNotPending);
+
+        check_deposit_status_approval(
+            &self.bridge_state,
+            self.relayer_committee.as_deref().map(|c| &**c),
+            self.instructions_sysvar
+                .as_ref()
+                .map(|instructions_sysvar| instructions_sysvar.as_ref()),
+            &deposit_status_message(
+                deposit_id,
+                DepositStatus::Refunded.to_code(),
+                reason.to_code(),
+                self.bridge_state.chain_id,
+            ),
+        )?;
+
+        self.deposit_record.status = DepositStatus::Refunded.to_code();
+        self.deposit_record.refund_reason = reason.to_code();
+
+        let amount = self.deposit_record.amount;
+
+        self.token_config.vault_amount = self
+            .token_config
+            .vault_amount
+            .checked_sub(amount)
+            .ok_or(Errors::Overflow)?;
+
+        self.token_config.pending_amount = self
+            .token_config
+            .pending_amount
+            .checked_sub(amount)
+            .ok_or(Errors::Overflow)?;
+
+        let signer_seeds: [&[&[u8]]; 1] = [&[b"bridge_state", &[self.bridge_state.bump]]];
+
+        transfer_checked_with_hook(
+            self.into_refund_context()
+                .with_signer(&signer_seeds)
+                .with_remaining_accounts(remaining_accounts.to_vec()),
+            amount,
+            self.mint_of_token_sent.decimals,
+        )?;
+
+        check_vault_amount(&mut self.vault, &self.token_config)?;
+
+        emit!(DepositRefunded {
+            deposit_id,
+            depositor: self.deposit_record.depositor,
+            mint: self.mint_of_token_sent.key(),
+            amount,
+            fee: 0,
+            token_fee: 0,
+            reason: reason.to_code(),
+        });
+
+        Ok(())
+    }
+
+    fn into_refund_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.vault.to_account_info(),
+            mint: self.mint_of_token_sent.to_account_info(),
+            to: self.depositor_token_account.to_account_info(),
+            authority: self.bridge_state.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
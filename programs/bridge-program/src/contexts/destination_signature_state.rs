@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::DestinationSignatureStateChanged,
+    execute_queued_config_change,
+    states::{bridge::State, BridgeState, ConfigChange, QueuedConfigChange},
+    Errors,
+};
+
+#[derive(Accounts)]
+pub struct DestinationSignatureState<'info> {
+    #[account(
+        mut,
+        seeds=["bridge_state".as_ref()],
+        bump=bridge_state.bump,
+    )]
+    pub bridge_state: Box<Account<'info, BridgeState>>,
+
+    #[account(
+        mut,
+        address=bridge_state.admin @ Errors::NotPrivileged,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds=[
+            "config_change".as_ref(),
+            queued_change.change.kind().to_le_bytes().as_ref(),
+            queued_change.change.scope().as_ref(),
+        ],
+        bump=queued_change.bump,
+    )]
+    pub queued_change: Option<Box<Account<'info, QueuedConfigChange>>>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> DestinationSignatureState<'info> {
+    // turning the check off is a security downgrade, so both directions go through the timelock
+    fn set_destination_signature_state(&mut self, destination_signature_state: u8) -> Result<()> {
+        execute_queued_config_change(
+            &self.bridge_state,
+            &self.queued_change,
+            self.authority.to_account_info(),
+            &ConfigChange::DestinationSignature {
+                destination_signature_state,
+            },
+        )?;
+
+        self.bridge_state.destination_signature_state = destination_signature_state;
+
+        emit!(DestinationSignatureStateChanged {
+            authority: self.authority.key(),
+            destination_signature_state,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_destination_signature_active(&mut self) -> Result<()> {
+        self.set_destination_signature_state(State::Active.to_code())
+    }
+
+    pub fn set_destination_signature_inactive(&mut self) -> Result<()> {
+        self.set_destination_signature_state(State::Inactive.to_code())
+    }
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::FeeOverrideChanged,
+    states::{BridgeState, FeeOverride},
+    Errors,
+};
+
+#[derive(Accounts)]
+#[instruction(mint: Pubkey, address: Pubkey)]
+pub struct RemoveFeeOverride<'info> {
+    #[account(
+        mut,
+        close=authority,
+        seeds=["fee_override".as_ref(), mint.as_ref(), address.as_ref()],
+        bump=fee_override.bump
+    )]
+    pub fee_override: Account<'info, FeeOverride>,
+
+    #[account(
+        mut,
+        address=bridge_state.fee_manager @ Errors::NotPrivileged,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds=["bridge_state".as_ref()],
+        bump=bridge_state.bump,
+    )]
+    pub bridge_state: Account<'info, BridgeState>,
+    system_program: Program<'info, System>,
+}
+
+impl<'info> RemoveFeeOverride<'info> {
+    pub fn remove_fee_override(&mut self, mint: Pubkey, address: Pubkey) -> Result<()> {
+        emit!(FeeOverrideChanged {
+            authority: self.authority.key(),
+            mint,
+            address,
+            fee_amount: 0,
+            fee_bps: 0,
+            added: false,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::MAX_FEE_BPS,
+    events::FeeOverrideChanged,
+    states::{BridgeState, FeeOverride, TokenConfig},
+    Errors,
+};
+
+#[derive(Accounts)]
+#[instruction(mint: Pubkey, address: Pubkey)]
+pub struct SetFeeOverride<'info> {
+    #[account(
+        init_if_needed,
+        space=FeeOverride::INIT_SPACE,
+        payer=authority,
+        seeds=["fee_override".as_ref(), mint.as_ref(), address.as_ref()],
+        bump
+    )]
+    pub fee_override: Account<'info, FeeOverride>,
+
+    #[account(
+        mut,
+        address=bridge_state.fee_manager @ Errors::NotPrivileged,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds=["bridge_state".as_ref()],
+        bump=bridge_state.bump,
+    )]
+    pub bridge_state: Account<'info, BridgeState>,
+
+    #[account(
+        seeds=["token_config".as_ref(), mint.as_ref()],
+        bump=token_config.bump,
+    )]
+    pub token_config: Box<Account<'info, TokenConfig>>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> SetFeeOverride<'info> {
+    pub fn set_fee_override(
+        &mut self,
+        bumps: &SetFeeOverrideBumps,
+        mint: Pubkey,
+        address: Pubkey,
+        fee_amount: u64,
+        fee_bps: u16,
+    ) -> Result<()> {
+        require!(fee_bps <= MAX_FEE_BPS, Errors::InvalidFeeConfig);
+
+        self.fee_override.set_inner(FeeOverride {
+            bump: bumps.fee_override,
+            mint,
+            address,
+            fee_amount,
+            fee_bps,
+        });
+
+        emit!(FeeOverrideChanged {
+            authority: self.authority.key(),
+            mint,
+            address,
+            fee_amount,
+            fee_bps,
+            added: true,
+        });
+
+        Ok(())
+    }
+}
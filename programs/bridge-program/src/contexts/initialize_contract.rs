@@ -1,12 +1,16 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{Mint, Token, TokenAccount},
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
 use crate::{
-    constants::ADMIN_PUBKEY,
-    states::{bridge::State, BridgeState},
+    check_mint_extensions,
+    events::ContractInitialized,
+    fund_fee_vault,
+    program::BridgeProgram,
+    states::{bridge::State, BridgeState, TokenConfig},
+    Errors,
 };
 
 #[derive(Accounts)]
@@ -20,50 +24,138 @@ pub struct InitializeContract<'info> {
     )]
     pub bridge_state: Account<'info, BridgeState>,
 
+    #[account(
+        init,
+        payer=authority,
+        seeds=["token_config".as_ref(), mint_of_token_whitelisted.key().as_ref()],
+        bump,
+        space=TokenConfig::INIT_SPACE,
+    )]
+    pub token_config: Box<Account<'info, TokenConfig>>,
+
     #[account(
         init_if_needed,
         payer=authority,
         associated_token::mint=mint_of_token_whitelisted,
         associated_token::authority=bridge_state,
+        associated_token::token_program=token_program,
     )]
-    pub vault: Account<'info, TokenAccount>,
-
-    pub mint_of_token_whitelisted: Account<'info, Mint>,
+    pub vault: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         mut,
-        address=ADMIN_PUBKEY,
+        seeds=["fee_vault".as_ref()],
+        bump,
     )]
+    pub fee_vault: SystemAccount<'info>,
+
+    pub mint_of_token_whitelisted: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
     pub authority: Signer<'info>,
 
+    #[account(
+        constraint=program.programdata_address()? == Some(program_data.key()) @ Errors::NotPrivileged,
+    )]
+    pub program: Program<'info, BridgeProgram>,
+
+    #[account(
+        constraint=program_data.upgrade_authority_address == Some(authority.key()) @ Errors::NotPrivileged,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
     system_program: Program<'info, System>,
-    token_program: Program<'info, Token>,
+    token_program: Interface<'info, TokenInterface>,
     associated_token_program: Program<'info, AssociatedToken>,
 }
 
 impl<'info> InitializeContract<'info> {
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize_contract(
         &mut self,
         bumps: &InitializeContractBumps,
+        admin: Pubkey,
         relayer_pubkey: Pubkey,
         fee_collector: Pubkey,
         fee_amount: u64,
         minimum_deposit: u64,
         maximum_deposit: u64,
     ) -> Result<()> {
+        check_mint_extensions(&self.mint_of_token_whitelisted.to_account_info())?;
+
+        fund_fee_vault(
+            self.system_program.to_account_info(),
+            self.authority.to_account_info(),
+            self.fee_vault.to_account_info(),
+        )?;
+
         self.bridge_state.set_inner(BridgeState {
             bump: bumps.bridge_state,
-            mint_of_token_whitelisted: self.mint_of_token_whitelisted.key(),
             relayer_pubkey,
-            vault: self.vault.key(),
             fee_collector,
-            vault_amount: 0u64,
             relayer_state: State::Inactive.to_code(),
             public_state: State::Inactive.to_code(),
             whitelist_state: State::Inactive.to_code(),
+            deposit_nonce: 0u64,
+            admin,
+            pending_admin: Pubkey::default(),
+            pauser: admin,
+            fee_manager: admin,
+            liquidity_manager: admin,
+            whitelist_manager: admin,
+            multisig_state: State::Inactive.to_code(),
+            timelock_delay: 0,
+            fees_collected: 0,
+            relayer_committee_state: State::Inactive.to_code(),
+            chain_id: 0,
+            destination_signature_state: State::Inactive.to_code(),
+            refund_timeout: 0,
+            refund_fee: false,
+        });
+
+        // the first bridged token follows the global pause flags from the start
+        self.token_config.set_inner(TokenConfig {
+            bump: bumps.token_config,
+            mint: self.mint_of_token_whitelisted.key(),
+            vault: self.vault.key(),
+            vault_amount: 0u64,
+            relayer_state: State::Active.to_code(),
+            public_state: State::Active.to_code(),
             minimum_deposit,
             maximum_deposit,
             fee_amount,
+            fee_bps: 0,
+            min_bps_fee: 0,
+            max_bps_fee: 0,
+            fees_collected: 0,
+            user_deposit_window: 0,
+            user_deposit_cap: 0,
+            user_deposit_cooldown: 0,
+            vault_cap: 0,
+            previous_mint: Pubkey::default(),
+            previous_vault: Pubkey::default(),
+            outflow_window: 0,
+            outflow_cap: 0,
+            outflow_window_start: 0,
+            outflow_in_window: 0,
+            breaker_release_bps: 0,
+            breaker_window: 0,
+            breaker_threshold: 0,
+            breaker_window_start: 0,
+            breaker_released_in_window: 0,
+            pending_amount: 0,
+        });
+
+        emit!(ContractInitialized {
+            authority: self.authority.key(),
+            admin,
+            mint_of_token_whitelisted: self.mint_of_token_whitelisted.key(),
+            vault: self.vault.key(),
+            relayer_pubkey,
+            fee_collector,
+            fee_amount,
+            minimum_deposit,
+            maximum_deposit,
         });
 
         Ok(())
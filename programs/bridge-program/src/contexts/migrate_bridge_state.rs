@@ -0,0 +1,191 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+    Discriminator,
+};
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::{
+    events::BridgeStateMigrated,
+    fund_fee_vault,
+    program::BridgeProgram,
+    states::{
+        bridge::{LegacyBridgeState, State},
+        BridgeState, TokenConfig,
+    },
+    Errors,
+};
+
+#[derive(Accounts)]
+pub struct MigrateBridgeState<'info> {
+    /// CHECK: bridge state in the legacy layout, checked and decoded in the handler
+    #[account(
+        mut,
+        seeds=["bridge_state".as_ref()],
+        bump,
+        owner=crate::ID,
+    )]
+    pub bridge_state: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer=authority,
+        seeds=["token_config".as_ref(), mint_of_token_whitelisted.key().as_ref()],
+        bump,
+        space=TokenConfig::INIT_SPACE,
+    )]
+    pub token_config: Box<Account<'info, TokenConfig>>,
+
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub mint_of_token_whitelisted: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds=["fee_vault".as_ref()],
+        bump,
+    )]
+    pub fee_vault: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint=program.programdata_address()? == Some(program_data.key()) @ Errors::NotPrivileged,
+    )]
+    pub program: Program<'info, BridgeProgram>,
+
+    #[account(
+        constraint=program_data.upgrade_authority_address == Some(authority.key()) @ Errors::NotPrivileged,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateBridgeState<'info> {
+    pub fn migrate_bridge_state(
+        &mut self,
+        bumps: &MigrateBridgeStateBumps,
+        admin: Pubkey,
+    ) -> Result<()> {
+        let bridge_state = self.bridge_state.to_account_info();
+
+        let legacy = {
+            let data = bridge_state.try_borrow_data()?;
+
+            require!(
+                data.len() == LegacyBridgeState::INIT_SPACE,
+                Errors::AlreadyMigrated
+            );
+            require!(
+                data[..8] == BridgeState::DISCRIMINATOR,
+                ErrorCode::AccountDiscriminatorMismatch
+            );
+
+            LegacyBridgeState::deserialize(&mut &data[8..])?
+        };
+
+        // the whitelisted token moves to its own token config, created from the legacy fields
+        require!(
+            self.mint_of_token_whitelisted.key() == legacy.mint_of_token_whitelisted,
+            Errors::MintMismatch
+        );
+        require!(self.vault.key() == legacy.vault, Errors::VaultMismatch);
+
+        let rent = Rent::get()?
+            .minimum_balance(BridgeState::INIT_SPACE)
+            .saturating_sub(bridge_state.lamports());
+
+        if rent > 0 {
+            transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.authority.to_account_info(),
+                        to: bridge_state.clone(),
+                    },
+                ),
+                rent,
+            )?;
+        }
+
+        bridge_state.realloc(BridgeState::INIT_SPACE, true)?;
+
+        fund_fee_vault(
+            self.system_program.to_account_info(),
+            self.authority.to_account_info(),
+            self.fee_vault.to_account_info(),
+        )?;
+
+        let migrated = BridgeState {
+            bump: legacy.bump,
+            relayer_pubkey: legacy.relayer_pubkey,
+            fee_collector: legacy.fee_collector,
+            relayer_state: legacy.relayer_state,
+            public_state: legacy.public_state,
+            whitelist_state: legacy.whitelist_state,
+            deposit_nonce: 0u64,
+            admin,
+            pending_admin: Pubkey::default(),
+            pauser: admin,
+            fee_manager: admin,
+            liquidity_manager: admin,
+            whitelist_manager: admin,
+            multisig_state: State::Inactive.to_code(),
+            timelock_delay: 0,
+            fees_collected: 0,
+            relayer_committee_state: State::Inactive.to_code(),
+            chain_id: 0,
+            destination_signature_state: State::Inactive.to_code(),
+            refund_timeout: 0,
+            refund_fee: false,
+        };
+
+        let mut data = bridge_state.try_borrow_mut_data()?;
+        migrated.try_serialize(&mut &mut data[..])?;
+
+        // like the first token of a new deployment, it follows the global pause flags
+        self.token_config.set_inner(TokenConfig {
+            bump: bumps.token_config,
+            mint: legacy.mint_of_token_whitelisted,
+            vault: legacy.vault,
+            vault_amount: legacy.vault_amount,
+            relayer_state: State::Active.to_code(),
+            public_state: State::Active.to_code(),
+            minimum_deposit: legacy.minimum_deposit,
+            maximum_deposit: legacy.maximum_deposit,
+            fee_amount: legacy.fee_amount,
+            fee_bps: 0,
+            min_bps_fee: 0,
+            max_bps_fee: 0,
+            fees_collected: 0,
+            user_deposit_window: 0,
+            user_deposit_cap: 0,
+            user_deposit_cooldown: 0,
+            vault_cap: 0,
+            previous_mint: Pubkey::default(),
+            previous_vault: Pubkey::default(),
+            outflow_window: 0,
+            outflow_cap: 0,
+            outflow_window_start: 0,
+            outflow_in_window: 0,
+            breaker_release_bps: 0,
+            breaker_window: 0,
+            breaker_threshold: 0,
+            breaker_window_start: 0,
+            breaker_released_in_window: 0,
+            pending_amount: 0,
+        });
+
+        emit!(BridgeStateMigrated {
+            authority: self.authority.key(),
+            admin,
+            mint_of_token_whitelisted: legacy.mint_of_token_whitelisted,
+            vault: legacy.vault,
+            vault_amount: legacy.vault_amount,
+        });
+
+        Ok(())
+    }
+}
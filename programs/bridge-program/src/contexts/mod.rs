@@ -1,5 +1,7 @@
 pub mod initialize_contract;
 pub use initialize_contract::*;
+pub mod migrate_bridge_state;
+pub use migrate_bridge_state::*;
 pub mod add_liquidity;
 pub use add_liquidity::*;
 pub mod remove_liquidity;
@@ -16,7 +18,35 @@ pub mod deposit_limits;
 pub use deposit_limits::*;
 pub mod whitelist;
 pub use whitelist::*;
+pub mod fee_override;
+pub use fee_override::*;
 pub mod contract_states;
 pub use contract_states::*;
+pub mod update_outflow_limit;
+pub use update_outflow_limit::*;
+pub mod update_circuit_breaker;
+pub use update_circuit_breaker::*;
+pub mod update_chain_id;
+pub use update_chain_id::*;
+pub mod destination_signature_state;
+pub use destination_signature_state::*;
+pub mod update_refund_policy;
+pub use update_refund_policy::*;
+pub mod deposit_status;
+pub use deposit_status::*;
 pub mod update_fee_collector;
 pub use update_fee_collector::*;
+pub mod update_admin;
+pub use update_admin::*;
+pub mod update_role;
+pub use update_role::*;
+pub mod multisig;
+pub use multisig::*;
+pub mod withdraw_fees;
+pub use withdraw_fees::*;
+pub mod reconcile;
+pub use reconcile::*;
+pub mod timelock;
+pub use timelock::*;
+pub mod relayer_committee;
+pub use relayer_committee::*;
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::ProposalApproved,
+    states::{Multisig, Proposal},
+    Errors,
+};
+
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    #[account(
+        mut,
+        seeds=["proposal".as_ref(), proposal.proposal_id.to_le_bytes().as_ref()],
+        bump=proposal.bump,
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    #[account(
+        seeds=["multisig".as_ref()],
+        bump=multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+
+    #[account(
+        mut,
+        constraint=multisig.is_signer(&authority.key()) @ Errors::NotPrivileged,
+    )]
+    pub authority: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> ApproveProposal<'info> {
+    pub fn approve_proposal(&mut self) -> Result<()> {
+        require!(!self.proposal.executed, Errors::ProposalAlreadyExecuted);
+        require!(
+            !self.proposal.approvals.contains(&self.authority.key()),
+            Errors::ProposalAlreadyApproved
+        );
+
+        self.proposal.approvals.push(self.authority.key());
+
+        emit!(ProposalApproved {
+            proposal_id: self.proposal.proposal_id,
+            signer: self.authority.key(),
+            approvals: self.multisig.count_approvals(&self.proposal.approvals) as u8,
+            threshold: self.multisig.threshold,
+        });
+
+        Ok(())
+    }
+}
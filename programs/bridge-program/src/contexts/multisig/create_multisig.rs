@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::MultisigChanged,
+    states::{bridge::State, BridgeState, Multisig},
+    Errors,
+};
+
+#[derive(Accounts)]
+pub struct CreateMultisig<'info> {
+    #[account(
+        init,
+        payer=authority,
+        seeds=["multisig".as_ref()],
+        bump,
+        space=Multisig::INIT_SPACE,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        seeds=["bridge_state".as_ref()],
+        bump=bridge_state.bump,
+    )]
+    pub bridge_state: Box<Account<'info, BridgeState>>,
+
+    #[account(
+        mut,
+        address=bridge_state.admin @ Errors::NotPrivileged,
+    )]
+    pub authority: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> CreateMultisig<'info> {
+    pub fn create_multisig(
+        &mut self,
+        bumps: &CreateMultisigBumps,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        require!(
+            Multisig::is_valid_config(&signers, threshold),
+            Errors::InvalidMultisigConfig
+        );
+
+        self.multisig.set_inner(Multisig {
+            bump: bumps.multisig,
+            signers: signers.clone(),
+            threshold,
+            proposal_count: 0u64,
+        });
+
+        // once active, the multisig can only be reconfigured through its own proposals
+        self.bridge_state.multisig_state = State::Active.to_code();
+
+        emit!(MultisigChanged { signers, threshold });
+
+        Ok(())
+    }
+}
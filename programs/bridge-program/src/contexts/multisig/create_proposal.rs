@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::ProposalCreated,
+    states::{Multisig, Proposal, ProposalAction},
+    Errors,
+};
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(
+        init,
+        payer=authority,
+        seeds=["proposal".as_ref(), multisig.proposal_count.to_le_bytes().as_ref()],
+        bump,
+        space=Proposal::INIT_SPACE,
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    #[account(
+        mut,
+        seeds=["multisig".as_ref()],
+        bump=multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+
+    #[account(
+        mut,
+        constraint=multisig.is_signer(&authority.key()) @ Errors::NotPrivileged,
+    )]
+    pub authority: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> CreateProposal<'info> {
+    pub fn create_proposal(
+        &mut self,
+        bumps: &CreateProposalBumps,
+        action: ProposalAction,
+    ) -> Result<()> {
+        if let ProposalAction::UpdateMultisig {
+            ref signers,
+            threshold,
+        } = action
+        {
+            require!(
+                Multisig::is_valid_config(signers, threshold),
+                Errors::InvalidMultisigConfig
+            );
+        }
+
+        let proposal_id = self.multisig.proposal_count;
+
+        // the proposer approves its own proposal
+        self.proposal.set_inner(Proposal {
+            bump: bumps.proposal,
+            proposal_id,
+            proposer: self.authority.key(),
+            action: action.clone(),
+            approvals: vec![self.authority.key()],
+            executed: false,
+        });
+
+        self.multisig.proposal_count += 1;
+
+        emit!(ProposalCreated {
+            proposal_id,
+            proposer: self.authority.key(),
+            action,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,8 @@
+pub mod create_multisig;
+pub use create_multisig::*;
+pub mod create_proposal;
+pub use create_proposal::*;
+pub mod approve_proposal;
+pub use approve_proposal::*;
+pub mod update_multisig;
+pub use update_multisig::*;
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::{MultisigChanged, ProposalExecuted},
+    states::{Multisig, Proposal, ProposalAction},
+    Errors,
+};
+
+#[derive(Accounts)]
+pub struct UpdateMultisig<'info> {
+    #[account(
+        mut,
+        seeds=["multisig".as_ref()],
+        bump=multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+
+    #[account(
+        mut,
+        seeds=["proposal".as_ref(), proposal.proposal_id.to_le_bytes().as_ref()],
+        bump=proposal.bump,
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    #[account(
+        mut,
+        constraint=multisig.is_signer(&authority.key()) @ Errors::NotPrivileged,
+    )]
+    pub authority: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateMultisig<'info> {
+    pub fn update_multisig(&mut self, signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        self.proposal.execute(
+            &self.multisig,
+            &ProposalAction::UpdateMultisig {
+                signers: signers.clone(),
+                threshold,
+            },
+        )?;
+
+        self.multisig.signers = signers.clone();
+        self.multisig.threshold = threshold;
+
+        emit!(ProposalExecuted {
+            proposal_id: self.proposal.proposal_id,
+        });
+        emit!(MultisigChanged { signers, threshold });
+
+        Ok(())
+    }
+}
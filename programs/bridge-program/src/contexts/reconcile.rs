@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{
+    events::VaultReconciled,
+    states::{BridgeState, TokenConfig},
+    Errors,
+};
+
+// Restricted to the liquidity manager: a donation to the vault would otherwise let anyone push
+// vault_amount over the vault cap and block deposits.
+#[derive(Accounts)]
+pub struct Reconcile<'info> {
+    #[account(
+        seeds=["bridge_state".as_ref()],
+        bump=bridge_state.bump,
+    )]
+    pub bridge_state: Box<Account<'info, BridgeState>>,
+
+    #[account(
+        mut,
+        seeds=["token_config".as_ref(), token_config.mint.as_ref()],
+        bump=token_config.bump,
+        has_one=vault,
+    )]
+    pub token_config: Box<Account<'info, TokenConfig>>,
+
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        address=bridge_state.liquidity_manager @ Errors::NotPrivileged,
+    )]
+    pub authority: Signer<'info>,
+}
+
+impl<'info> Reconcile<'info> {
+    pub fn reconcile(&mut self) -> Result<()> {
+        let previous_vault_amount = self.token_config.vault_amount;
+
+        self.token_config.vault_amount = self.vault.amount;
+
+        emit!(VaultReconciled {
+            mint: self.token_config.mint,
+            vault: self.vault.key(),
+            previous_vault_amount,
+            vault_amount: self.token_config.vault_amount,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::ReleaseApproved,
+    states::{RelayerCommittee, ReleaseApproval},
+    Errors,
+};
+
+#[derive(Accounts)]
+#[instruction(amount: u64, receiver: Pubkey, tx_hash: [u8; 32], mint: Pubkey)]
+pub struct ApproveRelease<'info> {
+    #[account(
+        seeds=["relayer_committee".as_ref()],
+        bump=relayer_committee.bump,
+    )]
+    pub relayer_committee: Box<Account<'info, RelayerCommittee>>,
+
+    #[account(
+        init_if_needed,
+        payer=authority,
+        seeds=[
+            "release_approval".as_ref(),
+            ReleaseApproval::transfer_hash(&tx_hash, &receiver, &mint, amount).as_ref(),
+        ],
+        bump,
+        space=ReleaseApproval::INIT_SPACE,
+    )]
+    pub release_approval: Box<Account<'info, ReleaseApproval>>,
+
+    #[account(
+        mut,
+        constraint=relayer_committee.is_relayer(&authority.key()) @ Errors::NotPrivileged,
+    )]
+    pub authority: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> ApproveRelease<'info> {
+    pub fn approve_release(
+        &mut self,
+        bumps: &ApproveReleaseBumps,
+        amount: u64,
+        receiver: Pubkey,
+        tx_hash: [u8; 32],
+        mint: Pubkey,
+    ) -> Result<()> {
+        // the first approval fixes the transfer, later ones must agree with it
+        if self.release_approval.approvals.is_empty() {
+            self.release_approval.set_inner(ReleaseApproval {
+                bump: bumps.release_approval,
+                tx_hash,
+                receiver,
+                mint,
+                amount,
+                approvals: Vec::new(),
+                payer: self.authority.key(),
+            });
+        } else {
+            require!(
+                self.release_approval.matches(&receiver, &mint, amount),
+                Errors::ReleaseMismatch
+            );
+        }
+
+        require!(
+            !self
+                .release_approval
+                .approvals
+                .contains(&self.authority.key()),
+            Errors::ReleaseAlreadyApproved
+        );
+
+        self.release_approval.approvals.push(self.authority.key());
+
+        emit!(ReleaseApproved {
+            tx_hash,
+            relayer: self.authority.key(),
+            approvals: self
+                .relayer_committee
+                .count_approvals(&self.release_approval.approvals) as u8,
+            threshold: self.relayer_committee.threshold,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,4 @@
+pub mod set_relayer_committee;
+pub use set_relayer_committee::*;
+pub mod approve_release;
+pub use approve_release::*;
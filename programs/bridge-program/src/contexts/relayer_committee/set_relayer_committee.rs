@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::{ConfigChanged, RelayerCommitteeChanged},
+    execute_multisig_proposal, execute_queued_config_change,
+    states::{
+        bridge::State, BridgeState, ConfigChange, Multisig, Proposal, ProposalAction,
+        QueuedConfigChange, RelayerCommittee,
+    },
+    Errors,
+};
+
+#[derive(Accounts)]
+pub struct SetRelayerCommittee<'info> {
+    #[account(
+        mut,
+        seeds=["bridge_state".as_ref()],
+        bump=bridge_state.bump,
+    )]
+    pub bridge_state: Box<Account<'info, BridgeState>>,
+
+    #[account(
+        init_if_needed,
+        payer=authority,
+        seeds=["relayer_committee".as_ref()],
+        bump,
+        space=RelayerCommittee::INIT_SPACE,
+    )]
+    pub relayer_committee: Box<Account<'info, RelayerCommittee>>,
+
+    #[account(
+        mut,
+        address=bridge_state.admin @ Errors::NotPrivileged,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds=["multisig".as_ref()],
+        bump=multisig.bump,
+    )]
+    pub multisig: Option<Box<Account<'info, Multisig>>>,
+
+    #[account(
+        mut,
+        seeds=["proposal".as_ref(), proposal.proposal_id.to_le_bytes().as_ref()],
+        bump=proposal.bump,
+    )]
+    pub proposal: Option<Box<Account<'info, Proposal>>>,
+
+    #[account(
+        mut,
+        seeds=[
+            "config_change".as_ref(),
+            queued_change.change.kind().to_le_bytes().as_ref(),
+            queued_change.change.scope().as_ref(),
+        ],
+        bump=queued_change.bump,
+    )]
+    pub queued_change: Option<Box<Account<'info, QueuedConfigChange>>>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> SetRelayerCommittee<'info> {
+    pub fn set_relayer_committee(
+        &mut self,
+        bumps: &SetRelayerCommitteeBumps,
+        relayers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        require!(
+            RelayerCommittee::is_valid_config(&relayers, threshold),
+            Errors::InvalidRelayerCommittee
+        );
+
+        execute_multisig_proposal(
+            &self.bridge_state,
+            &self.multisig,
+            &mut self.proposal,
+            ProposalAction::UpdateRelayerCommittee {
+                relayers: relayers.clone(),
+                threshold,
+            },
+        )?;
+
+        let change = ConfigChange::RelayerCommittee {
+            relayers_hash: RelayerCommittee::relayers_hash(&relayers),
+            threshold,
+        };
+
+        execute_queued_config_change(
+            &self.bridge_state,
+            &self.queued_change,
+            self.authority.to_account_info(),
+            &change,
+        )?;
+
+        self.relayer_committee.set_inner(RelayerCommittee {
+            bump: bumps.relayer_committee,
+            relayers: relayers.clone(),
+            threshold,
+        });
+
+        // while the committee is active the single relayer key can no longer release on its own
+        self.bridge_state.relayer_committee_state =
+            if RelayerCommittee::is_disabled_config(&relayers, threshold) {
+                State::Inactive.to_code()
+            } else {
+                State::Active.to_code()
+            };
+
+        emit!(ConfigChanged {
+            authority: self.authority.key(),
+            change,
+        });
+        emit!(RelayerCommitteeChanged {
+            relayers,
+            threshold,
+        });
+
+        Ok(())
+    }
+}
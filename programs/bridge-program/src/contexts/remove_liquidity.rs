@@ -1,64 +1,117 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{transfer_checked, Mint, Token, TokenAccount, TransferChecked},
+    token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked},
 };
 
-use crate::{constants::ADMIN_PUBKEY, states::BridgeState, Errors};
+use crate::{
+    check_vault_amount,
+    events::LiquidityChanged,
+    execute_multisig_proposal,
+    states::{BridgeState, Multisig, Proposal, ProposalAction, TokenConfig},
+    transfer_checked_with_hook, Errors,
+};
 
 #[derive(Accounts)]
 #[instruction(amount: u64)]
 pub struct RemoveLiquidity<'info> {
     #[account(
-        mut,
         seeds=["bridge_state".as_ref()],
         bump=bridge_state.bump,
-        has_one=vault,
     )]
     pub bridge_state: Box<Account<'info, BridgeState>>,
 
     #[account(
         mut,
-        constraint=vault.amount >= amount @ Errors::NotEnoughBalance,
-        associated_token::mint=mint_of_token_sent,
-        associated_token::authority=bridge_state
+        seeds=["token_config".as_ref(), mint_of_token_sent.key().as_ref()],
+        bump=token_config.bump,
+        has_one=vault,
     )]
-    pub vault: Box<Account<'info, TokenAccount>>,
+    pub token_config: Box<Account<'info, TokenConfig>>,
 
     #[account(
         mut,
-        address=ADMIN_PUBKEY,
+        constraint=vault.amount >= amount @ Errors::NotEnoughBalance,
+        associated_token::mint=mint_of_token_sent,
+        associated_token::authority=bridge_state,
+        associated_token::token_program=token_program,
     )]
-    pub authority: Signer<'info>,
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         mut,
-        constraint=mint_of_token_sent.key()==bridge_state.mint_of_token_whitelisted @ Errors::MintMismatch,
+        address=bridge_state.liquidity_manager @ Errors::NotPrivileged,
     )]
-    pub mint_of_token_sent: Account<'info, Mint>,
+    pub authority: Signer<'info>,
+
+    pub mint_of_token_sent: InterfaceAccount<'info, Mint>,
 
     #[account(mut,
         constraint=authority_token_account.owner==authority.key() @ Errors::OwnerMismatch,
         constraint=authority_token_account.mint==mint_of_token_sent.key() @ Errors::MintMismatch,
     )]
-    pub authority_token_account: Account<'info, TokenAccount>,
+    pub authority_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds=["multisig".as_ref()],
+        bump=multisig.bump,
+    )]
+    pub multisig: Option<Box<Account<'info, Multisig>>>,
+
+    #[account(
+        mut,
+        seeds=["proposal".as_ref(), proposal.proposal_id.to_le_bytes().as_ref()],
+        bump=proposal.bump,
+    )]
+    pub proposal: Option<Box<Account<'info, Proposal>>>,
 
     system_program: Program<'info, System>,
-    token_program: Program<'info, Token>,
+    token_program: Interface<'info, TokenInterface>,
     associated_token_program: Program<'info, AssociatedToken>,
 }
 
 impl<'info> RemoveLiquidity<'info> {
-    pub fn remove_liquidity(&mut self, amount: u64) -> Result<()> {
+    pub fn remove_liquidity(
+        &mut self,
+        amount: u64,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        execute_multisig_proposal(
+            &self.bridge_state,
+            &self.multisig,
+            &mut self.proposal,
+            ProposalAction::RemoveLiquidity {
+                mint: self.mint_of_token_sent.key(),
+                amount,
+            },
+        )?;
+
         let signer_seeds: [&[&[u8]]; 1] = [&[b"bridge_state", &[self.bridge_state.bump]]];
 
-        self.bridge_state.vault_amount -= amount;
-        transfer_checked(
+        self.token_config.vault_amount = self
+            .token_config
+            .vault_amount
+            .checked_sub(amount)
+            .ok_or(Errors::Overflow)?;
+        transfer_checked_with_hook(
             self.into_remove_liquidity_context()
-                .with_signer(&signer_seeds),
+                .with_signer(&signer_seeds)
+                .with_remaining_accounts(remaining_accounts.to_vec()),
             amount,
             self.mint_of_token_sent.decimals,
-        )
+        )?;
+
+        check_vault_amount(&mut self.vault, &self.token_config)?;
+
+        emit!(LiquidityChanged {
+            authority: self.authority.key(),
+            mint: self.mint_of_token_sent.key(),
+            amount,
+            added: false,
+            vault_amount: self.token_config.vault_amount,
+        });
+
+        Ok(())
     }
 
     fn into_remove_liquidity_context(
@@ -1,66 +1,247 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, solana_program::sysvar::instructions as sysvar_instructions};
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{transfer_checked, Mint, Token, TokenAccount, TransferChecked},
+    token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked},
 };
 
-use crate::{states::BridgeState, Errors};
+use crate::{
+    check_vault_amount, ed25519_message_signers,
+    events::{CircuitBreakerTripped, ReleaseEvent},
+    release_attestation_message,
+    states::{
+        bridge::State, BridgeState, RelayerCommittee, ReleaseApproval, TokenConfig, TransferReceipt,
+    },
+    transfer_checked_with_hook, Errors,
+};
 
 #[derive(Accounts)]
-#[instruction(amount: u64, receiver: Pubkey)]
+#[instruction(amount: u64, receiver: Pubkey, tx_hash: [u8; 32])]
 pub struct SendFromLiquidity<'info> {
     #[account(
         mut,
         seeds=["bridge_state".as_ref()],
         bump=bridge_state.bump,
-        has_one=vault,
     )]
     pub bridge_state: Box<Account<'info, BridgeState>>,
 
     #[account(
         mut,
-        constraint=vault.amount >= amount @ Errors::NotEnoughBalance,
-        associated_token::mint=mint_of_token_sent,
-        associated_token::authority=bridge_state 
+        seeds=["token_config".as_ref(), mint_of_token_sent.key().as_ref()],
+        bump=token_config.bump,
+        has_one=vault,
     )]
-    pub vault: Box<Account<'info, TokenAccount>>,
+    pub token_config: Box<Account<'info, TokenConfig>>,
 
     #[account(
         mut,
-        address=bridge_state.relayer_pubkey.key() @ Errors::NotPrivileged,
+        constraint=vault.amount >= amount @ Errors::NotEnoughBalance,
+        associated_token::mint=mint_of_token_sent,
+        associated_token::authority=bridge_state,
+        associated_token::token_program=token_program,
     )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // the single relayer, a committee member while the relayer committee is active, or anyone
+    // when the release carries an attestation
+    #[account(mut)]
     pub authority: Signer<'info>,
 
-    #[account(
-        constraint=mint_of_token_sent.key()==bridge_state.mint_of_token_whitelisted,
-    )]
-    pub mint_of_token_sent: Box<Account<'info, Mint>>,
+    pub mint_of_token_sent: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
         mut,
 
         constraint= receiver_token_account.owner==receiver @ Errors::OwnerMismatch,
-        constraint=receiver_token_account.mint==bridge_state.mint_of_token_whitelisted.key() @ Errors::MintMismatch,
+        constraint=receiver_token_account.mint==mint_of_token_sent.key() @ Errors::MintMismatch,
     )
     ]
-    pub receiver_token_account: Account<'info, TokenAccount>,
+    pub receiver_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer=authority,
+        seeds=["transfer_receipt".as_ref(), tx_hash.as_ref()],
+        bump,
+        space=TransferReceipt::INIT_SPACE,
+    )]
+    pub transfer_receipt: Box<Account<'info, TransferReceipt>>,
+
+    #[account(
+        seeds=["relayer_committee".as_ref()],
+        bump=relayer_committee.bump,
+    )]
+    pub relayer_committee: Option<Box<Account<'info, RelayerCommittee>>>,
+
+    #[account(
+        mut,
+        seeds=[
+            "release_approval".as_ref(),
+            ReleaseApproval::transfer_hash(&tx_hash, &receiver, &mint_of_token_sent.key(), amount)
+                .as_ref(),
+        ],
+        bump=release_approval.bump,
+        has_one=payer,
+        close=payer,
+    )]
+    pub release_approval: Option<Box<Account<'info, ReleaseApproval>>>,
+
+    /// CHECK: the relayer that paid for the release approval, checked against it
+    #[account(mut)]
+    pub payer: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: address checked, only read through the instructions sysvar helpers
+    #[account(address=sysvar_instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
 
     system_program: Program<'info, System>,
-    token_program: Program<'info, Token>,
+    token_program: Interface<'info, TokenInterface>,
     associated_token_program: Program<'info, AssociatedToken>,
 }
 
 impl<'info> SendFromLiquidity<'info> {
-    pub fn send_from_liquidity(&mut self, amount: u64) -> Result<()> {
+    pub fn send_from_liquidity(
+        &mut self,
+        bumps: &SendFromLiquidityBumps,
+        amount: u64,
+        receiver: Pubkey,
+        tx_hash: [u8; 32],
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        require!(
+            !self.transfer_receipt.is_processed(),
+            Errors::TransferAlreadyProcessed
+        );
+
+        self.check_release_approval(amount, receiver, tx_hash)?;
+
+        let now = Clock::get()?.unix_timestamp;
+
+        // pause the relayer side of the token instead of failing, so the pause sticks and the
+        // receipt stays unprocessed for a retry once the release has been checked
+        let vault_amount = self.token_config.vault_amount;
+
+        if self
+            .token_config
+            .trips_circuit_breaker(amount, vault_amount, now)?
+        {
+            self.token_config.relayer_state = State::Inactive.to_code();
+
+            emit!(CircuitBreakerTripped {
+                tx_hash,
+                receiver,
+                mint: self.mint_of_token_sent.key(),
+                amount,
+                vault_amount: self.token_config.vault_amount,
+                breaker_released_in_window: self.token_config.breaker_released_in_window,
+            });
+
+            return Ok(());
+        }
+
+        self.transfer_receipt.set_inner(TransferReceipt {
+            bump: bumps.transfer_receipt,
+            tx_hash,
+            receiver,
+            mint: self.mint_of_token_sent.key(),
+            amount,
+            slot: Clock::get()?.slot,
+        });
+
+        self.token_config.record_outflow(amount, now)?;
+
         let signer_seeds: [&[&[u8]]; 1] = [&[b"bridge_state", &[self.bridge_state.bump]]];
 
-        self.bridge_state.vault_amount -= amount;
-        transfer_checked(
+        self.token_config.vault_amount = self
+            .token_config
+            .vault_amount
+            .checked_sub(amount)
+            .ok_or(Errors::Overflow)?;
+        transfer_checked_with_hook(
             self.into_send_from_liquidity_context()
-                .with_signer(&signer_seeds),
+                .with_signer(&signer_seeds)
+                .with_remaining_accounts(remaining_accounts.to_vec()),
             amount,
             self.mint_of_token_sent.decimals,
-        )
+        )?;
+
+        check_vault_amount(&mut self.vault, &self.token_config)?;
+
+        emit!(ReleaseEvent {
+            tx_hash,
+            receiver,
+            mint: self.mint_of_token_sent.key(),
+            amount,
+            vault_amount: self.token_config.vault_amount,
+        });
+
+        Ok(())
+    }
+
+    // While the relayer committee is active, a release needs enough distinct committee approvals
+    // for this exact transfer; otherwise only the single relayer key can release. The approvals
+    // can also come as Ed25519 signatures over the release attestation, submitted by anyone.
+    fn check_release_approval(
+        &self,
+        amount: u64,
+        receiver: Pubkey,
+        tx_hash: [u8; 32],
+    ) -> Result<()> {
+        if let Some(instructions_sysvar) = &self.instructions_sysvar {
+            let message = release_attestation_message(
+                &tx_hash,
+                &receiver,
+                amount,
+                &self.mint_of_token_sent.key(),
+                self.bridge_state.chain_id,
+            );
+            let signers =
+                ed25519_message_signers(&instructions_sysvar.to_account_info(), &message)?;
+
+            let approved = if self.bridge_state.relayer_committee_state == State::Active.to_code() {
+                let relayer_committee = self
+                    .relayer_committee
+                    .as_ref()
+                    .ok_or(Errors::NoReleaseApprovalProvided)?;
+
+                relayer_committee.count_approvals(&signers) >= relayer_committee.threshold as usize
+            } else {
+                signers.contains(&self.bridge_state.relayer_pubkey)
+            };
+
+            require!(approved, Errors::ReleaseNotApproved);
+
+            return Ok(());
+        }
+
+        if self.bridge_state.relayer_committee_state != State::Active.to_code() {
+            require!(
+                self.authority.key() == self.bridge_state.relayer_pubkey,
+                Errors::NotPrivileged
+            );
+
+            return Ok(());
+        }
+
+        match (&self.relayer_committee, &self.release_approval) {
+            (Some(relayer_committee), Some(release_approval)) => {
+                require!(
+                    relayer_committee.is_relayer(&self.authority.key()),
+                    Errors::NotPrivileged
+                );
+                require!(
+                    release_approval.matches(&receiver, &self.mint_of_token_sent.key(), amount),
+                    Errors::ReleaseMismatch
+                );
+                require!(
+                    relayer_committee.count_approvals(&release_approval.approvals)
+                        >= relayer_committee.threshold as usize,
+                    Errors::ReleaseNotApproved
+                );
+
+                Ok(())
+            }
+            _ => err!(Errors::NoReleaseApprovalProvided),
+        }
     }
 
     fn into_send_from_liquidity_context(
@@ -1,12 +1,21 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{
+    prelude::*,
+    solana_program::sysvar::instructions as sysvar_instructions,
+    system_program::{transfer, Transfer},
+};
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{transfer_checked, Mint, Token, TokenAccount, TransferChecked},
+    token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked},
 };
 
 use crate::{
-    states::{BridgeState, WhitelistEntry},
-    Errors,
+    check_vault_amount, compute_bps_fee, destination_signature_message, ed25519_message_signers,
+    events::DepositEvent,
+    states::{
+        BridgeState, DepositRecord, DepositStatus, FeeOverride, TokenConfig, UserDeposit,
+        WhitelistEntry,
+    },
+    transfer_checked_with_hook, transfer_fee, Errors,
 };
 
 #[derive(Accounts)]
@@ -16,16 +25,24 @@ pub struct SendToLiquidity<'info> {
         mut,
         seeds=["bridge_state".as_ref()],
         bump=bridge_state.bump,
-        has_one=vault,
     )]
     pub bridge_state: Box<Account<'info, BridgeState>>,
 
+    #[account(
+        mut,
+        seeds=["token_config".as_ref(), mint_of_token_sent.key().as_ref()],
+        bump=token_config.bump,
+        has_one=vault,
+    )]
+    pub token_config: Box<Account<'info, TokenConfig>>,
+
     #[account(
         mut,
         associated_token::mint=mint_of_token_sent,
-        associated_token::authority=bridge_state
+        associated_token::authority=bridge_state,
+        associated_token::token_program=token_program,
     )]
-    pub vault: Box<Account<'info, TokenAccount>>,
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         seeds=[authority.key().as_ref(), bridge_state.key().as_ref()],
@@ -34,71 +51,275 @@ pub struct SendToLiquidity<'info> {
     )]
     pub whitelist: Option<Account<'info, WhitelistEntry>>,
 
+    #[account(
+        seeds=["fee_override".as_ref(), mint_of_token_sent.key().as_ref(), authority.key().as_ref()],
+        bump=fee_override.bump,
+    )]
+    pub fee_override: Option<Account<'info, FeeOverride>>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
 
     #[account(
-        constraint=mint_of_token_sent.key()==bridge_state.mint_of_token_whitelisted,
+        init,
+        payer=authority,
+        seeds=["deposit_record".as_ref(), bridge_state.deposit_nonce.to_le_bytes().as_ref()],
+        bump,
+        space=DepositRecord::INIT_SPACE,
     )]
-    pub mint_of_token_sent: Box<Account<'info, Mint>>,
+    pub deposit_record: Box<Account<'info, DepositRecord>>,
+
+    #[account(
+        init_if_needed,
+        payer=authority,
+        seeds=["user_deposit".as_ref(), mint_of_token_sent.key().as_ref(), authority.key().as_ref()],
+        bump,
+        space=UserDeposit::INIT_SPACE,
+    )]
+    pub user_deposit: Option<Box<Account<'info, UserDeposit>>>,
+
+    pub mint_of_token_sent: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
         mut,
         constraint=authority_token_account.amount >= amount @ Errors::NotEnoughBalance,
         constraint=authority_token_account.owner==authority.key() @ Errors::OwnerMismatch,
-        constraint=authority_token_account.mint==bridge_state.mint_of_token_whitelisted @ Errors::MintMismatch,
+        constraint=authority_token_account.mint==mint_of_token_sent.key() @ Errors::MintMismatch,
     )
     ]
-    pub authority_token_account: Box<Account<'info, TokenAccount>>,
+    pub authority_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         constraint=mint_of_fee_token_sent.key()==spl_token::native_mint::ID @ Errors::MintMismatch,
+        constraint=token_program.key()==spl_token::ID @ Errors::FeeTokenProgramMismatch,
     )]
-    pub mint_of_fee_token_sent: Option<Account<'info, Mint>>,
+    pub mint_of_fee_token_sent: Option<InterfaceAccount<'info, Mint>>,
 
     #[account(
         init_if_needed,
         payer=authority,
         associated_token::mint=mint_of_fee_token_sent,
-        associated_token::authority=fee_collector,
+        associated_token::authority=fee_vault,
+        associated_token::token_program=token_program,
     )]
-    pub fee_collector_ata: Option<Account<'info, TokenAccount>>,
+    pub fee_vault_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer=authority,
+        associated_token::mint=mint_of_token_sent,
+        associated_token::authority=fee_vault,
+        associated_token::token_program=token_program,
+    )]
+    pub fee_vault_token_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     #[account(
         mut,
-        constraint=fee_collector.key()==bridge_state.fee_collector @ Errors::FeeCollectorMismatch,
+        seeds=["fee_vault".as_ref()],
+        bump,
     )]
-    pub fee_collector: Option<SystemAccount<'info>>,
+    pub fee_vault: Option<SystemAccount<'info>>,
 
     #[account(mut,
-        constraint=authority_fee_token_account.amount >= bridge_state.fee_amount @ Errors::NotEnoughBalance,
+        constraint=authority_fee_token_account.amount >= fee_override.as_ref().map_or(token_config.fee_amount, |o| o.fee_amount) @ Errors::NotEnoughBalance,
         constraint=authority_fee_token_account.owner==authority.key() @ Errors::OwnerMismatch,
         constraint=authority_fee_token_account.mint==spl_token::native_mint::ID @ Errors::MintMismatch,
     )]
-    pub authority_fee_token_account: Option<Account<'info, TokenAccount>>,
+    pub authority_fee_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: address checked, only read through the instructions sysvar helpers
+    #[account(address=sysvar_instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
 
     system_program: Program<'info, System>,
-    token_program: Program<'info, Token>,
+    token_program: Interface<'info, TokenInterface>,
     associated_token_program: Program<'info, AssociatedToken>,
 }
 
 impl<'info> SendToLiquidity<'info> {
-    pub fn send_to_liquidity(&mut self, amount: u64) -> Result<()> {
-        if self.bridge_state.fee_amount > 0 {
-            transfer_checked(
-                self.into_send_fee_context(),
-                self.bridge_state.fee_amount,
-                self.mint_of_fee_token_sent.as_ref().unwrap().decimals,
+    pub fn send_to_liquidity(
+        &mut self,
+        bumps: &SendToLiquidityBumps,
+        amount: u64,
+        destination_address: String,
+        destination_key: [u8; 32],
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        if let Some(user_deposit) = &mut self.user_deposit {
+            user_deposit.bump = bumps.user_deposit.unwrap();
+            user_deposit.depositor = self.authority.key();
+            user_deposit.mint = self.mint_of_token_sent.key();
+            user_deposit.deposit_nonce += 1;
+
+            user_deposit.record_deposit(
+                amount,
+                &self.token_config,
+                Clock::get()?.unix_timestamp,
             )?;
         }
 
-        self.bridge_state.vault_amount += amount;
+        let fee = self.fee_amount();
+        let token_fee_sent = self.token_fee(amount)?;
+        let amount_sent = amount - token_fee_sent;
+
+        // a Token-2022 transfer fee is withheld on the way in, the vaults are only credited the rest
+        let mint = self.mint_of_token_sent.to_account_info();
+        let token_fee = token_fee_sent - transfer_fee(&mint, token_fee_sent)?;
+        let amount = amount_sent - transfer_fee(&mint, amount_sent)?;
+        let deposit_id = self.bridge_state.deposit_nonce;
 
-        transfer_checked(
-            self.into_send_to_liquidity_context(),
+        self.deposit_record.set_inner(DepositRecord {
+            bump: bumps.deposit_record,
+            deposit_id,
+            depositor: self.authority.key(),
+            mint: self.mint_of_token_sent.key(),
             amount,
+            fee,
+            token_fee,
+            destination_address: destination_key,
+            slot: Clock::get()?.slot,
+            status: DepositStatus::Pending.to_code(),
+            timestamp: Clock::get()?.unix_timestamp,
+            refund_reason: 0,
+            fee_wsol: self.authority_fee_token_account.is_some(),
+        });
+
+        self.bridge_state.deposit_nonce += 1;
+
+        self.bridge_state.fees_collected = self
+            .bridge_state
+            .fees_collected
+            .checked_add(fee)
+            .ok_or(Errors::Overflow)?;
+
+        self.token_config.fees_collected = self
+            .token_config
+            .fees_collected
+            .checked_add(token_fee)
+            .ok_or(Errors::Overflow)?;
+
+        if fee > 0 {
+            if self.authority_fee_token_account.is_some() {
+                transfer_checked_with_hook(
+                    self.into_send_fee_context(),
+                    fee,
+                    self.mint_of_fee_token_sent.as_ref().unwrap().decimals,
+                )?;
+            } else {
+                transfer(self.into_send_native_fee_context(), fee)?;
+            }
+        }
+
+        if token_fee_sent > 0 {
+            transfer_checked_with_hook(
+                self.into_send_token_fee_context()
+                    .with_remaining_accounts(remaining_accounts.to_vec()),
+                token_fee_sent,
+                self.mint_of_token_sent.decimals,
+            )?;
+        }
+
+        self.token_config.vault_amount = self
+            .token_config
+            .vault_amount
+            .checked_add(amount)
+            .ok_or(Errors::Overflow)?;
+
+        self.token_config.pending_amount = self
+            .token_config
+            .pending_amount
+            .checked_add(amount)
+            .ok_or(Errors::Overflow)?;
+
+        // the counterpart liquidity on MultiversX is finite, a cap of 0 disables the check
+        require!(
+            self.token_config.vault_cap == 0
+                || self.token_config.vault_amount <= self.token_config.vault_cap,
+            Errors::VaultCapExceeded
+        );
+
+        transfer_checked_with_hook(
+            self.into_send_to_liquidity_context()
+                .with_remaining_accounts(remaining_accounts.to_vec()),
+            amount_sent,
             self.mint_of_token_sent.decimals,
-        )
+        )?;
+
+        check_vault_amount(&mut self.vault, &self.token_config)?;
+
+        emit!(DepositEvent {
+            deposit_id,
+            depositor: self.authority.key(),
+            mint: self.mint_of_token_sent.key(),
+            amount,
+            fee,
+            token_fee,
+            destination_address,
+            vault_amount: self.token_config.vault_amount,
+        });
+
+        Ok(())
+    }
+
+    // The MultiversX key behind the destination must have signed this deposit in an Ed25519
+    // program instruction, proving the depositor controls the address funds are bridged to.
+    pub fn check_destination_signature(
+        &self,
+        amount: u64,
+        destination_key: &[u8; 32],
+    ) -> Result<()> {
+        let instructions_sysvar = self
+            .instructions_sysvar
+            .as_ref()
+            .ok_or(Errors::InvalidDestinationSignature)?;
+
+        let user_deposit = self
+            .user_deposit
+            .as_ref()
+            .ok_or(Errors::NoUserDepositAccountProvided)?;
+
+        let message = destination_signature_message(
+            &self.authority.key(),
+            &self.mint_of_token_sent.key(),
+            amount,
+            user_deposit.deposit_nonce,
+        );
+        let signers = ed25519_message_signers(&instructions_sysvar.to_account_info(), &message)?;
+
+        require!(
+            signers.contains(&Pubkey::new_from_array(*destination_key)),
+            Errors::InvalidDestinationSignature
+        );
+
+        Ok(())
+    }
+
+    // A fee override replaces this token's flat fee and basis points for one depositor. The token's
+    // minimum basis points fee is not applied, so an override can lower the fee below it or waive it.
+    pub fn fee_amount(&self) -> u64 {
+        match &self.fee_override {
+            Some(fee_override) => fee_override.fee_amount,
+            None => self.token_config.fee_amount,
+        }
+    }
+
+    pub fn token_fee(&self, amount: u64) -> Result<u64> {
+        match &self.fee_override {
+            Some(fee_override) => compute_bps_fee(
+                amount,
+                fee_override.fee_bps,
+                0,
+                self.token_config.max_bps_fee,
+                self.mint_of_token_sent.decimals,
+            ),
+            None => compute_bps_fee(
+                amount,
+                self.token_config.fee_bps,
+                self.token_config.min_bps_fee,
+                self.token_config.max_bps_fee,
+                self.mint_of_token_sent.decimals,
+            ),
+        }
     }
 
     fn into_send_to_liquidity_context(
@@ -113,6 +334,24 @@ impl<'info> SendToLiquidity<'info> {
         CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
     }
 
+    fn into_send_token_fee_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.authority_token_account.to_account_info(),
+            to: self.fee_vault_token_ata.as_ref().unwrap().to_account_info(),
+            mint: self.mint_of_token_sent.to_account_info(),
+            authority: self.authority.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+
+    fn into_send_native_fee_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.authority.to_account_info(),
+            to: self.fee_vault.as_ref().unwrap().to_account_info(),
+        };
+        CpiContext::new(self.system_program.to_account_info(), cpi_accounts)
+    }
+
     fn into_send_fee_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
         let cpi_accounts = TransferChecked {
             from: self
@@ -120,7 +359,7 @@ impl<'info> SendToLiquidity<'info> {
                 .as_ref()
                 .unwrap()
                 .to_account_info(),
-            to: self.fee_collector_ata.as_ref().unwrap().to_account_info(),
+            to: self.fee_vault_ata.as_ref().unwrap().to_account_info(),
             mint: self
                 .mint_of_fee_token_sent
                 .as_ref()
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::ConfigChangeCancelled,
+    states::{BridgeState, QueuedConfigChange},
+    Errors,
+};
+
+#[derive(Accounts)]
+pub struct CancelConfigChange<'info> {
+    #[account(
+        mut,
+        close=authority,
+        seeds=[
+            "config_change".as_ref(),
+            queued_change.change.kind().to_le_bytes().as_ref(),
+            queued_change.change.scope().as_ref(),
+        ],
+        bump=queued_change.bump,
+    )]
+    pub queued_change: Box<Account<'info, QueuedConfigChange>>,
+
+    #[account(
+        seeds=["bridge_state".as_ref()],
+        bump=bridge_state.bump,
+    )]
+    pub bridge_state: Box<Account<'info, BridgeState>>,
+
+    #[account(
+        mut,
+        constraint=authority.key() == bridge_state.config_authority(&queued_change.change)
+            || authority.key() == bridge_state.admin @ Errors::NotPrivileged,
+    )]
+    pub authority: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> CancelConfigChange<'info> {
+    pub fn cancel_config_change(&mut self) -> Result<()> {
+        emit!(ConfigChangeCancelled {
+            authority: self.authority.key(),
+            change: self.queued_change.change.clone(),
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,6 @@
+pub mod queue_config_change;
+pub use queue_config_change::*;
+pub mod cancel_config_change;
+pub use cancel_config_change::*;
+pub mod update_timelock_delay;
+pub use update_timelock_delay::*;
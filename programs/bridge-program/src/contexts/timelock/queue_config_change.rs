@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::ConfigChangeQueued,
+    states::{BridgeState, ConfigChange, QueuedConfigChange},
+    Errors,
+};
+
+#[derive(Accounts)]
+#[instruction(change: ConfigChange)]
+pub struct QueueConfigChange<'info> {
+    #[account(
+        init,
+        payer=authority,
+        seeds=[
+            "config_change".as_ref(),
+            change.kind().to_le_bytes().as_ref(),
+            change.scope().as_ref(),
+        ],
+        bump,
+        space=QueuedConfigChange::INIT_SPACE,
+    )]
+    pub queued_change: Box<Account<'info, QueuedConfigChange>>,
+
+    #[account(
+        seeds=["bridge_state".as_ref()],
+        bump=bridge_state.bump,
+    )]
+    pub bridge_state: Box<Account<'info, BridgeState>>,
+
+    #[account(
+        mut,
+        constraint=authority.key() == bridge_state.config_authority(&change) @ Errors::NotPrivileged,
+    )]
+    pub authority: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> QueueConfigChange<'info> {
+    pub fn queue_config_change(
+        &mut self,
+        bumps: &QueueConfigChangeBumps,
+        change: ConfigChange,
+    ) -> Result<()> {
+        let eta = Clock::get()?
+            .unix_timestamp
+            .checked_add(self.bridge_state.timelock_delay)
+            .ok_or(Errors::Overflow)?;
+
+        self.queued_change.set_inner(QueuedConfigChange {
+            bump: bumps.queued_change,
+            change: change.clone(),
+            queued_by: self.authority.key(),
+            eta,
+        });
+
+        emit!(ConfigChangeQueued {
+            authority: self.authority.key(),
+            change,
+            eta,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::ConfigChanged,
+    execute_queued_config_change,
+    states::{BridgeState, ConfigChange, QueuedConfigChange},
+    Errors,
+};
+
+#[derive(Accounts)]
+pub struct UpdateTimelockDelay<'info> {
+    #[account(
+        mut,
+        seeds=["bridge_state".as_ref()],
+        bump=bridge_state.bump,
+    )]
+    pub bridge_state: Box<Account<'info, BridgeState>>,
+
+    #[account(
+        mut,
+        address=bridge_state.admin @ Errors::NotPrivileged,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds=[
+            "config_change".as_ref(),
+            queued_change.change.kind().to_le_bytes().as_ref(),
+            queued_change.change.scope().as_ref(),
+        ],
+        bump=queued_change.bump,
+    )]
+    pub queued_change: Option<Box<Account<'info, QueuedConfigChange>>>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateTimelockDelay<'info> {
+    pub fn set_timelock_delay(&mut self, timelock_delay: i64) -> Result<()> {
+        require!(timelock_delay >= 0, Errors::InvalidTimelockDelay);
+
+        let change = ConfigChange::TimelockDelay { timelock_delay };
+
+        // lowering the delay has to wait out the current one
+        execute_queued_config_change(
+            &self.bridge_state,
+            &self.queued_change,
+            self.authority.to_account_info(),
+            &change,
+        )?;
+
+        self.bridge_state.timelock_delay = timelock_delay;
+
+        emit!(ConfigChanged {
+            authority: self.authority.key(),
+            change,
+        });
+
+        Ok(())
+    }
+}
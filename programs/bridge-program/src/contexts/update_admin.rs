@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::{AdminChanged, AdminProposed},
+    states::BridgeState,
+    Errors,
+};
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    #[account(
+        mut,
+        seeds=["bridge_state".as_ref()],
+        bump=bridge_state.bump,
+    )]
+    pub bridge_state: Box<Account<'info, BridgeState>>,
+
+    #[account(
+        mut,
+        address=bridge_state.admin @ Errors::NotPrivileged,
+    )]
+    pub authority: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> ProposeAdmin<'info> {
+    pub fn propose_admin(&mut self, pending_admin: Pubkey) -> Result<()> {
+        self.bridge_state.pending_admin = pending_admin;
+
+        emit!(AdminProposed {
+            admin: self.bridge_state.admin,
+            pending_admin,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(
+        mut,
+        seeds=["bridge_state".as_ref()],
+        bump=bridge_state.bump,
+    )]
+    pub bridge_state: Box<Account<'info, BridgeState>>,
+
+    #[account(
+        mut,
+        address=bridge_state.pending_admin @ Errors::NotPrivileged,
+    )]
+    pub authority: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> AcceptAdmin<'info> {
+    pub fn accept_admin(&mut self) -> Result<()> {
+        let previous_admin = self.bridge_state.admin;
+
+        self.bridge_state.admin = self.authority.key();
+        self.bridge_state.pending_admin = Pubkey::default();
+
+        emit!(AdminChanged {
+            previous_admin,
+            admin: self.bridge_state.admin,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::ConfigChanged,
+    execute_queued_config_change,
+    states::{BridgeState, ConfigChange, QueuedConfigChange},
+    Errors,
+};
+
+#[derive(Accounts)]
+pub struct UpdateChainId<'info> {
+    #[account(
+        mut,
+        seeds=["bridge_state".as_ref()],
+        bump=bridge_state.bump,
+    )]
+    pub bridge_state: Box<Account<'info, BridgeState>>,
+
+    #[account(
+        mut,
+        address=bridge_state.admin @ Errors::NotPrivileged,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds=[
+            "config_change".as_ref(),
+            queued_change.change.kind().to_le_bytes().as_ref(),
+            queued_change.change.scope().as_ref(),
+        ],
+        bump=queued_change.bump,
+    )]
+    pub queued_change: Option<Box<Account<'info, QueuedConfigChange>>>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateChainId<'info> {
+    pub fn set_chain_id(&mut self, chain_id: u64) -> Result<()> {
+        let change = ConfigChange::ChainId { chain_id };
+
+        execute_queued_config_change(
+            &self.bridge_state,
+            &self.queued_change,
+            self.authority.to_account_info(),
+            &change,
+        )?;
+
+        self.bridge_state.chain_id = chain_id;
+
+        emit!(ConfigChanged {
+            authority: self.authority.key(),
+            change,
+        });
+
+        Ok(())
+    }
+}
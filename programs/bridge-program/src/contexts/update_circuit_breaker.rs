@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::MAX_FEE_BPS,
+    events::ConfigChanged,
+    execute_queued_config_change,
+    states::{BridgeState, ConfigChange, QueuedConfigChange, TokenConfig},
+    Errors,
+};
+
+#[derive(Accounts)]
+pub struct UpdateCircuitBreaker<'info> {
+    #[account(
+        seeds=["bridge_state".as_ref()],
+        bump=bridge_state.bump,
+    )]
+    pub bridge_state: Box<Account<'info, BridgeState>>,
+
+    #[account(
+        mut,
+        seeds=["token_config".as_ref(), token_config.mint.as_ref()],
+        bump=token_config.bump,
+    )]
+    pub token_config: Box<Account<'info, TokenConfig>>,
+
+    #[account(
+        mut,
+        address=bridge_state.admin @ Errors::NotPrivileged,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds=[
+            "config_change".as_ref(),
+            queued_change.change.kind().to_le_bytes().as_ref(),
+            queued_change.change.scope().as_ref(),
+        ],
+        bump=queued_change.bump,
+    )]
+    pub queued_change: Option<Box<Account<'info, QueuedConfigChange>>>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateCircuitBreaker<'info> {
+    pub fn set_circuit_breaker(
+        &mut self,
+        breaker_release_bps: u16,
+        breaker_window: i64,
+        breaker_threshold: u64,
+    ) -> Result<()> {
+        require!(
+            breaker_release_bps <= MAX_FEE_BPS && (breaker_threshold == 0 || breaker_window > 0),
+            Errors::InvalidCircuitBreaker
+        );
+
+        let change = ConfigChange::CircuitBreaker {
+            mint: self.token_config.mint,
+            breaker_release_bps,
+            breaker_window,
+            breaker_threshold,
+        };
+
+        execute_queued_config_change(
+            &self.bridge_state,
+            &self.queued_change,
+            self.authority.to_account_info(),
+            &change,
+        )?;
+
+        self.token_config.breaker_release_bps = breaker_release_bps;
+        self.token_config.breaker_window = breaker_window;
+        self.token_config.breaker_threshold = breaker_threshold;
+
+        emit!(ConfigChanged {
+            authority: self.authority.key(),
+            change,
+        });
+
+        Ok(())
+    }
+}
@@ -1,6 +1,11 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::ADMIN_PUBKEY, states::BridgeState};
+use crate::{
+    events::ConfigChanged,
+    execute_queued_config_change,
+    states::{BridgeState, ConfigChange, QueuedConfigChange},
+    Errors,
+};
 
 #[derive(Accounts)]
 pub struct UpdateFeeCollector<'info> {
@@ -13,16 +18,42 @@ pub struct UpdateFeeCollector<'info> {
 
     #[account(
     mut,
-    address=ADMIN_PUBKEY,
+    address=bridge_state.fee_manager @ Errors::NotPrivileged,
   )]
     pub authority: Signer<'info>,
 
+    #[account(
+        mut,
+        seeds=[
+            "config_change".as_ref(),
+            queued_change.change.kind().to_le_bytes().as_ref(),
+            queued_change.change.scope().as_ref(),
+        ],
+        bump=queued_change.bump,
+    )]
+    pub queued_change: Option<Box<Account<'info, QueuedConfigChange>>>,
+
     system_program: Program<'info, System>,
 }
 
 impl<'info> UpdateFeeCollector<'info> {
     pub fn update_fee_collector(&mut self, fee_collector: Pubkey) -> Result<()> {
+        let change = ConfigChange::FeeCollector { fee_collector };
+
+        execute_queued_config_change(
+            &self.bridge_state,
+            &self.queued_change,
+            self.authority.to_account_info(),
+            &change,
+        )?;
+
         self.bridge_state.fee_collector = fee_collector;
+
+        emit!(ConfigChanged {
+            authority: self.authority.key(),
+            change,
+        });
+
         Ok(())
     }
 }
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::ConfigChanged,
+    execute_queued_config_change,
+    states::{BridgeState, ConfigChange, QueuedConfigChange, TokenConfig},
+    Errors,
+};
+
+#[derive(Accounts)]
+pub struct UpdateOutflowLimit<'info> {
+    #[account(
+        seeds=["bridge_state".as_ref()],
+        bump=bridge_state.bump,
+    )]
+    pub bridge_state: Box<Account<'info, BridgeState>>,
+
+    #[account(
+        mut,
+        seeds=["token_config".as_ref(), token_config.mint.as_ref()],
+        bump=token_config.bump,
+    )]
+    pub token_config: Box<Account<'info, TokenConfig>>,
+
+    #[account(
+        mut,
+        address=bridge_state.admin @ Errors::NotPrivileged,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds=[
+            "config_change".as_ref(),
+            queued_change.change.kind().to_le_bytes().as_ref(),
+            queued_change.change.scope().as_ref(),
+        ],
+        bump=queued_change.bump,
+    )]
+    pub queued_change: Option<Box<Account<'info, QueuedConfigChange>>>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateOutflowLimit<'info> {
+    pub fn set_outflow_limit(&mut self, outflow_window: i64, outflow_cap: u64) -> Result<()> {
+        require!(
+            outflow_cap == 0 || outflow_window > 0,
+            Errors::InvalidOutflowLimit
+        );
+
+        let change = ConfigChange::OutflowLimit {
+            mint: self.token_config.mint,
+            outflow_window,
+            outflow_cap,
+        };
+
+        execute_queued_config_change(
+            &self.bridge_state,
+            &self.queued_change,
+            self.authority.to_account_info(),
+            &change,
+        )?;
+
+        self.token_config.outflow_window = outflow_window;
+        self.token_config.outflow_cap = outflow_cap;
+
+        emit!(ConfigChanged {
+            authority: self.authority.key(),
+            change,
+        });
+
+        Ok(())
+    }
+}
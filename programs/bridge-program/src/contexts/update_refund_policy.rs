@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::ConfigChanged,
+    execute_queued_config_change,
+    states::{BridgeState, ConfigChange, QueuedConfigChange},
+    Errors,
+};
+
+#[derive(Accounts)]
+pub struct UpdateRefundPolicy<'info> {
+    #[account(
+        mut,
+        seeds=["bridge_state".as_ref()],
+        bump=bridge_state.bump,
+    )]
+    pub bridge_state: Box<Account<'info, BridgeState>>,
+
+    #[account(
+        mut,
+        address=bridge_state.admin @ Errors::NotPrivileged,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds=[
+            "config_change".as_ref(),
+            queued_change.change.kind().to_le_bytes().as_ref(),
+            queued_change.change.scope().as_ref(),
+        ],
+        bump=queued_change.bump,
+    )]
+    pub queued_change: Option<Box<Account<'info, QueuedConfigChange>>>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateRefundPolicy<'info> {
+    pub fn set_refund_policy(&mut self, refund_timeout: i64, refund_fee: bool) -> Result<()> {
+        require!(refund_timeout >= 0, Errors::InvalidRefundPolicy);
+
+        let change = ConfigChange::RefundPolicy {
+            refund_timeout,
+            refund_fee,
+        };
+
+        execute_queued_config_change(
+            &self.bridge_state,
+            &self.queued_change,
+            self.authority.to_account_info(),
+            &change,
+        )?;
+
+        self.bridge_state.refund_timeout = refund_timeout;
+        self.bridge_state.refund_fee = refund_fee;
+
+        emit!(ConfigChanged {
+            authority: self.authority.key(),
+            change,
+        });
+
+        Ok(())
+    }
+}
@@ -1,6 +1,11 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::ADMIN_PUBKEY, states::BridgeState};
+use crate::{
+    events::ConfigChanged,
+    execute_multisig_proposal, execute_queued_config_change,
+    states::{BridgeState, ConfigChange, Multisig, Proposal, ProposalAction, QueuedConfigChange},
+    Errors,
+};
 
 #[derive(Accounts)]
 pub struct UpdateRelayer<'info> {
@@ -13,16 +18,62 @@ pub struct UpdateRelayer<'info> {
 
     #[account(
     mut,
-    address=ADMIN_PUBKEY,
+    address=bridge_state.admin @ Errors::NotPrivileged,
   )]
     pub authority: Signer<'info>,
 
+    #[account(
+        seeds=["multisig".as_ref()],
+        bump=multisig.bump,
+    )]
+    pub multisig: Option<Box<Account<'info, Multisig>>>,
+
+    #[account(
+        mut,
+        seeds=["proposal".as_ref(), proposal.proposal_id.to_le_bytes().as_ref()],
+        bump=proposal.bump,
+    )]
+    pub proposal: Option<Box<Account<'info, Proposal>>>,
+
+    #[account(
+        mut,
+        seeds=[
+            "config_change".as_ref(),
+            queued_change.change.kind().to_le_bytes().as_ref(),
+            queued_change.change.scope().as_ref(),
+        ],
+        bump=queued_change.bump,
+    )]
+    pub queued_change: Option<Box<Account<'info, QueuedConfigChange>>>,
+
     system_program: Program<'info, System>,
 }
 
 impl<'info> UpdateRelayer<'info> {
     pub fn update_relayer(&mut self, relayer_pubkey: Pubkey) -> Result<()> {
+        execute_multisig_proposal(
+            &self.bridge_state,
+            &self.multisig,
+            &mut self.proposal,
+            ProposalAction::UpdateRelayer { relayer_pubkey },
+        )?;
+
+        let change = ConfigChange::Relayer { relayer_pubkey };
+
+        execute_queued_config_change(
+            &self.bridge_state,
+            &self.queued_change,
+            self.authority.to_account_info(),
+            &change,
+        )?;
+
         self.bridge_state.relayer_pubkey = relayer_pubkey;
+
+        emit!(ConfigChanged {
+            authority: self.authority.key(),
+            change,
+        });
+
         Ok(())
     }
 }
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::RoleChanged,
+    states::{bridge::Role, BridgeState},
+    Errors,
+};
+
+#[derive(Accounts)]
+pub struct UpdateRole<'info> {
+    #[account(
+        mut,
+        seeds=["bridge_state".as_ref()],
+        bump=bridge_state.bump,
+    )]
+    pub bridge_state: Box<Account<'info, BridgeState>>,
+
+    #[account(
+        mut,
+        address=bridge_state.admin @ Errors::NotPrivileged,
+    )]
+    pub authority: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateRole<'info> {
+    pub fn grant_role(&mut self, role: Role, account: Pubkey) -> Result<()> {
+        self.bridge_state.set_role(role, account);
+
+        emit!(RoleChanged {
+            authority: self.authority.key(),
+            role,
+            account,
+        });
+
+        Ok(())
+    }
+
+    // an unassigned role is held by the default pubkey, which can never sign
+    pub fn revoke_role(&mut self, role: Role) -> Result<()> {
+        self.grant_role(role, Pubkey::default())
+    }
+}
@@ -1,44 +1,291 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{Mint, Token, TokenAccount},
+    token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked},
 };
 
-use crate::{constants::ADMIN_PUBKEY, states::BridgeState};
+use crate::{
+    check_mint_extensions,
+    events::{ConfigChanged, MintMigrated},
+    execute_multisig_proposal, execute_queued_config_change,
+    states::{
+        bridge::State, BridgeState, ConfigChange, Multisig, Proposal, ProposalAction,
+        QueuedConfigChange, TokenConfig,
+    },
+    transfer_checked_with_hook, Errors,
+};
 
 #[derive(Accounts)]
 pub struct UpdateWhitelistedMint<'info> {
     #[account(
-        mut,
         seeds=["bridge_state".as_ref()],
         bump=bridge_state.bump,
     )]
     pub bridge_state: Box<Account<'info, BridgeState>>,
 
+    #[account(
+        init,
+        payer=authority,
+        seeds=["token_config".as_ref(), mint_of_token_whitelisted.key().as_ref()],
+        bump,
+        space=TokenConfig::INIT_SPACE,
+    )]
+    pub token_config: Box<Account<'info, TokenConfig>>,
+
     #[account(
         init_if_needed,
         payer=authority,
         associated_token::mint=mint_of_token_whitelisted,
         associated_token::authority=bridge_state,
+        associated_token::token_program=token_program,
     )]
-    pub vault: Account<'info, TokenAccount>,
+    pub vault: InterfaceAccount<'info, TokenAccount>,
 
-    pub mint_of_token_whitelisted: Box<Account<'info, Mint>>,
+    pub mint_of_token_whitelisted: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
         mut,
-        address=ADMIN_PUBKEY,
+        address=bridge_state.admin @ Errors::NotPrivileged,
     )]
     pub authority: Signer<'info>,
 
+    #[account(
+        seeds=["multisig".as_ref()],
+        bump=multisig.bump,
+    )]
+    pub multisig: Option<Box<Account<'info, Multisig>>>,
+
+    #[account(
+        mut,
+        seeds=["proposal".as_ref(), proposal.proposal_id.to_le_bytes().as_ref()],
+        bump=proposal.bump,
+    )]
+    pub proposal: Option<Box<Account<'info, Proposal>>>,
+
+    #[account(
+        mut,
+        seeds=[
+            "config_change".as_ref(),
+            queued_change.change.kind().to_le_bytes().as_ref(),
+            queued_change.change.scope().as_ref(),
+        ],
+        bump=queued_change.bump,
+    )]
+    pub queued_change: Option<Box<Account<'info, QueuedConfigChange>>>,
+
+    // the accounts below are only provided when migrating away from a previously whitelisted mint
+    #[account(
+        mut,
+        seeds=["token_config".as_ref(), previous_token_config.mint.as_ref()],
+        bump=previous_token_config.bump,
+    )]
+    pub previous_token_config: Option<Box<Account<'info, TokenConfig>>>,
+
+    #[account(mut)]
+    pub previous_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub previous_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        constraint=sweep_token_account.owner==bridge_state.liquidity_manager @ Errors::OwnerMismatch,
+    )]
+    pub sweep_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    // token program of the previous mint, when it differs from the one of the new mint
+    pub previous_token_program: Option<Interface<'info, TokenInterface>>,
+
     system_program: Program<'info, System>,
-    token_program: Program<'info, Token>,
+    token_program: Interface<'info, TokenInterface>,
     associated_token_program: Program<'info, AssociatedToken>,
 }
 impl<'info> UpdateWhitelistedMint<'info> {
-    pub fn update_whitelisted_mint(&mut self) -> Result<()> {
-        self.bridge_state.mint_of_token_whitelisted = self.mint_of_token_whitelisted.key();
-        self.bridge_state.vault_amount = self.vault.amount; // update state with vault balance
+    pub fn update_whitelisted_mint(
+        &mut self,
+        bumps: &UpdateWhitelistedMintBumps,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        check_mint_extensions(&self.mint_of_token_whitelisted.to_account_info())?;
+
+        // the migration and where the previous vault is swept to are part of what gets approved
+        let previous_mint = self
+            .previous_token_config
+            .as_ref()
+            .map(|previous_token_config| previous_token_config.mint)
+            .unwrap_or_default();
+        let sweep_token_account = self
+            .sweep_token_account
+            .as_ref()
+            .map(|sweep_token_account| sweep_token_account.key())
+            .unwrap_or_default();
+
+        execute_multisig_proposal(
+            &self.bridge_state,
+            &self.multisig,
+            &mut self.proposal,
+            ProposalAction::UpdateWhitelistedMint {
+                mint_of_token_whitelisted: self.mint_of_token_whitelisted.key(),
+                previous_mint,
+                sweep_token_account,
+            },
+        )?;
+
+        let change = ConfigChange::WhitelistedMint {
+            mint_of_token_whitelisted: self.mint_of_token_whitelisted.key(),
+            vault: self.vault.key(),
+            previous_mint,
+            sweep_token_account,
+        };
+
+        execute_queued_config_change(
+            &self.bridge_state,
+            &self.queued_change,
+            self.authority.to_account_info(),
+            &change,
+        )?;
+
+        // a newly whitelisted token starts paused until its limits and fee are set
+        self.token_config.set_inner(TokenConfig {
+            bump: bumps.token_config,
+            mint: self.mint_of_token_whitelisted.key(),
+            vault: self.vault.key(),
+            vault_amount: self.vault.amount, // update state with vault balance
+            relayer_state: State::Inactive.to_code(),
+            public_state: State::Inactive.to_code(),
+            minimum_deposit: 0,
+            maximum_deposit: 0,
+            fee_amount: 0,
+            fee_bps: 0,
+            min_bps_fee: 0,
+            max_bps_fee: 0,
+            fees_collected: 0,
+            user_deposit_window: 0,
+            user_deposit_cap: 0,
+            user_deposit_cooldown: 0,
+            vault_cap: 0,
+            previous_mint: Pubkey::default(),
+            previous_vault: Pubkey::default(),
+            outflow_window: 0,
+            outflow_cap: 0,
+            outflow_window_start: 0,
+            outflow_in_window: 0,
+            breaker_release_bps: 0,
+            breaker_window: 0,
+            breaker_threshold: 0,
+            breaker_window_start: 0,
+            breaker_released_in_window: 0,
+            pending_amount: 0,
+        });
+
+        if self.previous_token_config.is_some() {
+            self.migrate_from_previous_mint(remaining_accounts)?;
+        }
+
+        emit!(ConfigChanged {
+            authority: self.authority.key(),
+            change,
+        });
+
+        Ok(())
+    }
+
+    // Retires the previous token config in favour of the new one. Refuses to strand tokens:
+    // a non-empty previous vault is either swept to the liquidity manager or the call fails, and
+    // nothing is swept while deposits of the previous mint can still be refunded from the vault.
+    fn migrate_from_previous_mint(
+        &mut self,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        require!(
+            self.previous_vault.is_some() && self.previous_mint.is_some(),
+            Errors::NoMigrationAccountsProvided
+        );
+
+        let previous_token_config = self.previous_token_config.as_ref().unwrap();
+        let previous_vault = self.previous_vault.as_ref().unwrap();
+        let previous_mint = self.previous_mint.as_ref().unwrap();
+
+        require!(
+            previous_mint.key() == previous_token_config.mint,
+            Errors::MintMismatch
+        );
+        require!(
+            previous_vault.key() == previous_token_config.vault,
+            Errors::VaultMismatch
+        );
+
+        require!(
+            previous_token_config.pending_amount == 0,
+            Errors::PendingDepositsOutstanding
+        );
+
+        let swept_amount = previous_vault.amount;
+
+        if swept_amount > 0 {
+            require!(self.sweep_token_account.is_some(), Errors::VaultNotEmpty);
+
+            let signer_seeds: [&[&[u8]]; 1] = [&[b"bridge_state", &[self.bridge_state.bump]]];
+
+            transfer_checked_with_hook(
+                self.into_sweep_context()
+                    .with_signer(&signer_seeds)
+                    .with_remaining_accounts(remaining_accounts.to_vec()),
+                swept_amount,
+                previous_mint.decimals,
+            )?;
+        }
+
+        let previous_token_config = self.previous_token_config.as_mut().unwrap();
+
+        previous_token_config.vault_amount = 0;
+        previous_token_config.relayer_state = State::Inactive.to_code();
+        previous_token_config.public_state = State::Inactive.to_code();
+
+        self.token_config.minimum_deposit = previous_token_config.minimum_deposit;
+        self.token_config.maximum_deposit = previous_token_config.maximum_deposit;
+        self.token_config.user_deposit_window = previous_token_config.user_deposit_window;
+        self.token_config.user_deposit_cap = previous_token_config.user_deposit_cap;
+        self.token_config.user_deposit_cooldown = previous_token_config.user_deposit_cooldown;
+        self.token_config.vault_cap = previous_token_config.vault_cap;
+        self.token_config.outflow_window = previous_token_config.outflow_window;
+        self.token_config.outflow_cap = previous_token_config.outflow_cap;
+        self.token_config.breaker_release_bps = previous_token_config.breaker_release_bps;
+        self.token_config.breaker_window = previous_token_config.breaker_window;
+        self.token_config.breaker_threshold = previous_token_config.breaker_threshold;
+        self.token_config.fee_amount = previous_token_config.fee_amount;
+        self.token_config.fee_bps = previous_token_config.fee_bps;
+        self.token_config.min_bps_fee = previous_token_config.min_bps_fee;
+        self.token_config.max_bps_fee = previous_token_config.max_bps_fee;
+        self.token_config.previous_mint = previous_token_config.mint;
+        self.token_config.previous_vault = previous_token_config.vault;
+
+        emit!(MintMigrated {
+            authority: self.authority.key(),
+            previous_mint: self.token_config.previous_mint,
+            previous_vault: self.token_config.previous_vault,
+            mint: self.token_config.mint,
+            vault: self.token_config.vault,
+            swept_amount,
+            sweep_token_account: self
+                .sweep_token_account
+                .as_ref()
+                .map(|account| account.key()),
+        });
+
         Ok(())
     }
+
+    fn into_sweep_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.previous_vault.as_ref().unwrap().to_account_info(),
+            mint: self.previous_mint.as_ref().unwrap().to_account_info(),
+            to: self.sweep_token_account.as_ref().unwrap().to_account_info(),
+            authority: self.bridge_state.to_account_info(),
+        };
+        let token_program = match &self.previous_token_program {
+            Some(previous_token_program) => previous_token_program.to_account_info(),
+            None => self.token_program.to_account_info(),
+        };
+        CpiContext::new(token_program, cpi_accounts)
+    }
 }
@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::ADMIN_PUBKEY,
+    events::WhitelistChanged,
     states::{BridgeState, WhitelistEntry},
+    Errors,
 };
 
 #[derive(Accounts)]
@@ -22,7 +23,7 @@ pub struct AddToWhitelist<'info> {
 
     #[account(
         mut,
-        address = ADMIN_PUBKEY,
+        address=bridge_state.whitelist_manager @ Errors::NotPrivileged,
     )]
     pub authority: Signer<'info>,
 
@@ -42,6 +43,12 @@ impl<'info> AddToWhitelist<'info> {
             bridge_state_address: self.bridge_state.key(),
         });
 
+        emit!(WhitelistChanged {
+            authority: self.authority.key(),
+            address,
+            added: true,
+        });
+
         Ok(())
     }
 }
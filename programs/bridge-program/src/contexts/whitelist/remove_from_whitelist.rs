@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::ADMIN_PUBKEY,
+    events::WhitelistChanged,
     states::{BridgeState, WhitelistEntry},
+    Errors,
 };
 
 #[derive(Accounts)]
@@ -21,7 +22,7 @@ pub struct RemoveFromWhitelist<'info> {
 
     #[account(
         mut,
-        address = ADMIN_PUBKEY,
+        address=bridge_state.whitelist_manager @ Errors::NotPrivileged,
     )]
     pub authority: Signer<'info>,
 
@@ -35,7 +36,13 @@ pub struct RemoveFromWhitelist<'info> {
 }
 
 impl<'info> RemoveFromWhitelist<'info> {
-    pub fn remove_from_whitelist(&mut self, _address: Pubkey) -> Result<()> {
+    pub fn remove_from_whitelist(&mut self, address: Pubkey) -> Result<()> {
+        emit!(WhitelistChanged {
+            authority: self.authority.key(),
+            address,
+            added: false,
+        });
+
         Ok(())
     }
 }
@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::ADMIN_PUBKEY,
+    events::WhitelistStateChanged,
     states::{bridge::State, BridgeState},
+    Errors,
 };
 
 #[derive(Accounts)]
@@ -16,7 +17,7 @@ pub struct WhitelistState<'info> {
 
     #[account(
         mut,
-        address = ADMIN_PUBKEY,
+        address=bridge_state.whitelist_manager @ Errors::NotPrivileged,
     )]
     pub authority: Signer<'info>,
 
@@ -24,13 +25,22 @@ pub struct WhitelistState<'info> {
 }
 
 impl<'info> WhitelistState<'info> {
+    fn emit_whitelist_state_changed(&self) {
+        emit!(WhitelistStateChanged {
+            authority: self.authority.key(),
+            whitelist_state: self.bridge_state.whitelist_state,
+        });
+    }
+
     pub fn set_whitelist_active(&mut self) -> Result<()> {
         self.bridge_state.whitelist_state = State::Active.to_code();
+        self.emit_whitelist_state_changed();
         Ok(())
     }
 
     pub fn set_whitelist_inactive(&mut self) -> Result<()> {
         self.bridge_state.whitelist_state = State::Inactive.to_code();
+        self.emit_whitelist_state_changed();
         Ok(())
     }
 }
@@ -0,0 +1,136 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::{events::FeesWithdrawn, states::BridgeState, transfer_checked_with_hook, Errors};
+
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    #[account(
+        seeds=["bridge_state".as_ref()],
+        bump=bridge_state.bump,
+    )]
+    pub bridge_state: Box<Account<'info, BridgeState>>,
+
+    #[account(
+        mut,
+        address=bridge_state.fee_manager @ Errors::NotPrivileged,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds=["fee_vault".as_ref()],
+        bump,
+    )]
+    pub fee_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        address=bridge_state.fee_collector @ Errors::FeeCollectorMismatch,
+    )]
+    pub fee_collector: SystemAccount<'info>,
+
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        associated_token::mint=mint,
+        associated_token::authority=fee_vault,
+        associated_token::token_program=token_program,
+    )]
+    pub fee_vault_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        init_if_needed,
+        payer=authority,
+        associated_token::mint=mint,
+        associated_token::authority=fee_collector,
+        associated_token::token_program=token_program,
+    )]
+    pub fee_collector_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    system_program: Program<'info, System>,
+    token_program: Interface<'info, TokenInterface>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> WithdrawFees<'info> {
+    pub fn withdraw_fees(
+        &mut self,
+        bumps: &WithdrawFeesBumps,
+        amount: u64,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        let signer_seeds: [&[&[u8]]; 1] = [&[b"fee_vault", &[bumps.fee_vault]]];
+
+        // with a mint the fees are paid out of the fee vault's token account, otherwise in lamports
+        let mint = match &self.mint {
+            Some(mint) => {
+                require!(
+                    self.fee_vault_ata.is_some() && self.fee_collector_ata.is_some(),
+                    Errors::NoFeeAccountsProvided
+                );
+
+                transfer_checked_with_hook(
+                    self.into_withdraw_token_fees_context()
+                        .with_signer(&signer_seeds)
+                        .with_remaining_accounts(remaining_accounts.to_vec()),
+                    amount,
+                    mint.decimals,
+                )?;
+
+                Some(mint.key())
+            }
+            None => {
+                // the fee vault keeps its rent-exempt minimum
+                require!(
+                    self.fee_vault.lamports().saturating_sub(amount)
+                        >= Rent::get()?.minimum_balance(0),
+                    Errors::NotEnoughBalance
+                );
+
+                transfer(
+                    self.into_withdraw_fees_context().with_signer(&signer_seeds),
+                    amount,
+                )?;
+
+                None
+            }
+        };
+
+        emit!(FeesWithdrawn {
+            authority: self.authority.key(),
+            fee_collector: self.fee_collector.key(),
+            mint,
+            amount,
+        });
+
+        Ok(())
+    }
+
+    fn into_withdraw_fees_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.fee_vault.to_account_info(),
+            to: self.fee_collector.to_account_info(),
+        };
+        CpiContext::new(self.system_program.to_account_info(), cpi_accounts)
+    }
+
+    fn into_withdraw_token_fees_context(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.fee_vault_ata.as_ref().unwrap().to_account_info(),
+            mint: self.mint.as_ref().unwrap().to_account_info(),
+            to: self.fee_collector_ata.as_ref().unwrap().to_account_info(),
+            authority: self.fee_vault.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
@@ -22,4 +22,100 @@ pub enum Errors {
     NoFeeAccountsProvided,
     #[msg("Fee collector mismatch")]
     FeeCollectorMismatch,
+    #[msg("Transfer already processed")]
+    TransferAlreadyProcessed,
+    #[msg("Destination address too long")]
+    DestinationAddressTooLong,
+    #[msg("Invalid multisig configuration")]
+    InvalidMultisigConfig,
+    #[msg("Multisig proposal required")]
+    MultisigProposalRequired,
+    #[msg("Proposal already approved")]
+    ProposalAlreadyApproved,
+    #[msg("Proposal already executed")]
+    ProposalAlreadyExecuted,
+    #[msg("Proposal not approved")]
+    ProposalNotApproved,
+    #[msg("Proposal mismatch")]
+    ProposalMismatch,
+    #[msg("Config change not queued")]
+    ConfigChangeNotQueued,
+    #[msg("Config change mismatch")]
+    ConfigChangeMismatch,
+    #[msg("Timelock not expired")]
+    TimelockNotExpired,
+    #[msg("Invalid timelock delay")]
+    InvalidTimelockDelay,
+    #[msg("Not all migration accounts were provided")]
+    NoMigrationAccountsProvided,
+    #[msg("Vault mismatch")]
+    VaultMismatch,
+    #[msg("Vault not empty")]
+    VaultNotEmpty,
+    #[msg("Arithmetic overflow")]
+    Overflow,
+    #[msg("Vault balance is below the vault amount")]
+    VaultAmountMismatch,
+    #[msg("Invalid fee configuration")]
+    InvalidFeeConfig,
+    #[msg("Fee exceeds the amount sent")]
+    FeeExceedsAmount,
+    #[msg("Invalid outflow limit")]
+    InvalidOutflowLimit,
+    #[msg("Outflow cap exceeded for the current window")]
+    OutflowCapExceeded,
+    #[msg("Invalid circuit breaker configuration")]
+    InvalidCircuitBreaker,
+    #[msg("Invalid user deposit limits")]
+    InvalidUserDepositLimits,
+    #[msg("User deposit account not provided")]
+    NoUserDepositAccountProvided,
+    #[msg("User deposit cap exceeded for the current window")]
+    UserDepositCapExceeded,
+    #[msg("Deposit cooldown has not elapsed")]
+    DepositCooldownActive,
+    #[msg("Deposit would exceed the vault cap")]
+    VaultCapExceeded,
+    #[msg("Invalid relayer committee configuration")]
+    InvalidRelayerCommittee,
+    #[msg("Release approval not provided")]
+    NoReleaseApprovalProvided,
+    #[msg("Release not approved by enough relayers")]
+    ReleaseNotApproved,
+    #[msg("Release does not match the approved transfer")]
+    ReleaseMismatch,
+    #[msg("Release already approved by this relayer")]
+    ReleaseAlreadyApproved,
+    #[msg("Invalid release attestation")]
+    InvalidAttestation,
+    #[msg("Invalid MultiversX destination address")]
+    InvalidDestinationAddress,
+    #[msg("Destination address signature not verified")]
+    InvalidDestinationSignature,
+    #[msg("Invalid refund policy")]
+    InvalidRefundPolicy,
+    #[msg("Deposit is not pending")]
+    DepositNotPending,
+    #[msg("Refunds are disabled")]
+    RefundsDisabled,
+    #[msg("Refund timeout has not elapsed")]
+    RefundTimeoutNotElapsed,
+    #[msg("Invalid refund reason")]
+    InvalidRefundReason,
+    #[msg("Mint has an unsupported Token-2022 extension")]
+    UnsupportedMintExtension,
+    #[msg("Mint is non-transferable")]
+    NonTransferableMint,
+    #[msg("Fees in wSOL require the legacy token program")]
+    FeeTokenProgramMismatch,
+    #[msg("Bridge state already migrated")]
+    AlreadyMigrated,
+    #[msg("Config change expired")]
+    ConfigChangeExpired,
+    #[msg("Deposit status change not approved by enough relayers")]
+    DepositStatusNotApproved,
+    #[msg("Deposits of the previous mint are still pending")]
+    PendingDepositsOutstanding,
+    #[msg("Invalid deposit limits")]
+    InvalidDepositLimits,
 }
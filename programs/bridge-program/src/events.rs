@@ -0,0 +1,230 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{bridge::Role, ConfigChange, ProposalAction};
+
+#[event]
+pub struct ContractInitialized {
+    pub authority: Pubkey,
+    pub admin: Pubkey,
+    pub mint_of_token_whitelisted: Pubkey,
+    pub vault: Pubkey,
+    pub relayer_pubkey: Pubkey,
+    pub fee_collector: Pubkey,
+    pub fee_amount: u64,
+    pub minimum_deposit: u64,
+    pub maximum_deposit: u64,
+}
+
+#[event]
+pub struct BridgeStateMigrated {
+    pub authority: Pubkey,
+    pub admin: Pubkey,
+    pub mint_of_token_whitelisted: Pubkey,
+    pub vault: Pubkey,
+    pub vault_amount: u64,
+}
+
+#[event]
+pub struct AdminProposed {
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+}
+
+#[event]
+pub struct AdminChanged {
+    pub previous_admin: Pubkey,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct RoleChanged {
+    pub authority: Pubkey,
+    pub role: Role,
+    pub account: Pubkey,
+}
+
+#[event]
+pub struct MultisigChanged {
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct RelayerCommitteeChanged {
+    pub relayers: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct ReleaseApproved {
+    pub tx_hash: [u8; 32],
+    pub relayer: Pubkey,
+    pub approvals: u8,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct ProposalCreated {
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub action: ProposalAction,
+}
+
+#[event]
+pub struct ProposalApproved {
+    pub proposal_id: u64,
+    pub signer: Pubkey,
+    pub approvals: u8,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct ProposalExecuted {
+    pub proposal_id: u64,
+}
+
+#[event]
+pub struct DepositEvent {
+    pub deposit_id: u64,
+    pub depositor: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub fee: u64,
+    pub token_fee: u64,
+    pub destination_address: String,
+    pub vault_amount: u64,
+}
+
+#[event]
+pub struct DepositInFlight {
+    pub deposit_id: u64,
+    pub relayer: Pubkey,
+}
+
+#[event]
+pub struct DepositProcessed {
+    pub deposit_id: u64,
+    pub relayer: Pubkey,
+}
+
+#[event]
+pub struct DepositRefunded {
+    pub deposit_id: u64,
+    pub depositor: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub fee: u64,
+    pub token_fee: u64,
+    pub reason: u8,
+}
+
+#[event]
+pub struct ReleaseEvent {
+    pub tx_hash: [u8; 32],
+    pub receiver: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub vault_amount: u64,
+}
+
+#[event]
+pub struct LiquidityChanged {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub added: bool,
+    pub vault_amount: u64,
+}
+
+#[event]
+pub struct CircuitBreakerTripped {
+    pub tx_hash: [u8; 32],
+    pub receiver: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub vault_amount: u64,
+    pub breaker_released_in_window: u64,
+}
+
+#[event]
+pub struct FeesWithdrawn {
+    pub authority: Pubkey,
+    pub fee_collector: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+}
+
+#[event]
+pub struct VaultReconciled {
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub previous_vault_amount: u64,
+    pub vault_amount: u64,
+}
+
+#[event]
+pub struct ConfigChanged {
+    pub authority: Pubkey,
+    pub change: ConfigChange,
+}
+
+#[event]
+pub struct ConfigChangeQueued {
+    pub authority: Pubkey,
+    pub change: ConfigChange,
+    pub eta: i64,
+}
+
+#[event]
+pub struct ConfigChangeCancelled {
+    pub authority: Pubkey,
+    pub change: ConfigChange,
+}
+
+#[event]
+pub struct MintMigrated {
+    pub authority: Pubkey,
+    pub previous_mint: Pubkey,
+    pub previous_vault: Pubkey,
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub swept_amount: u64,
+    pub sweep_token_account: Option<Pubkey>,
+}
+
+#[event]
+pub struct PauseChanged {
+    pub authority: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub relayer_state: u8,
+    pub public_state: u8,
+}
+
+#[event]
+pub struct WhitelistStateChanged {
+    pub authority: Pubkey,
+    pub whitelist_state: u8,
+}
+
+#[event]
+pub struct DestinationSignatureStateChanged {
+    pub authority: Pubkey,
+    pub destination_signature_state: u8,
+}
+
+#[event]
+pub struct WhitelistChanged {
+    pub authority: Pubkey,
+    pub address: Pubkey,
+    pub added: bool,
+}
+
+#[event]
+pub struct FeeOverrideChanged {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub address: Pubkey,
+    pub fee_amount: u64,
+    pub fee_bps: u16,
+    pub added: bool,
+}
@@ -4,8 +4,10 @@ mod contexts;
 use contexts::*;
 mod constants;
 mod errors;
-mod states;
+mod events;
+pub mod states;
 use errors::*;
+use states::{bridge::Role, ConfigChange, ProposalAction, RefundReason};
 mod utils;
 use utils::*;
 
@@ -25,12 +27,13 @@ declare_id!("4wDs9FnvdksFXy69UKVgi7WWqtYJmbM6TiMCEWY9wJz9");
 #[program]
 pub mod bridge_program {
 
-    use crate::states::bridge::State;
+    use crate::{constants::MAX_DESTINATION_ADDRESS_LEN, states::bridge::State};
 
     use super::*;
 
     pub fn initialize_contract(
         ctx: Context<InitializeContract>,
+        admin: Pubkey,
         relayer_pubkey: Pubkey,
         fee_collector: Pubkey,
         fee_amount: u64,
@@ -39,6 +42,7 @@ pub mod bridge_program {
     ) -> Result<()> {
         ctx.accounts.initialize_contract(
             &ctx.bumps,
+            admin,
             relayer_pubkey,
             fee_collector,
             fee_amount,
@@ -47,6 +51,86 @@ pub mod bridge_program {
         )
     }
 
+    pub fn migrate_bridge_state(ctx: Context<MigrateBridgeState>, admin: Pubkey) -> Result<()> {
+        ctx.accounts.migrate_bridge_state(&ctx.bumps, admin)
+    }
+
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, pending_admin: Pubkey) -> Result<()> {
+        ctx.accounts.propose_admin(pending_admin)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        ctx.accounts.accept_admin()
+    }
+
+    pub fn grant_role(ctx: Context<UpdateRole>, role: Role, account: Pubkey) -> Result<()> {
+        ctx.accounts.grant_role(role, account)
+    }
+
+    pub fn revoke_role(ctx: Context<UpdateRole>, role: Role) -> Result<()> {
+        ctx.accounts.revoke_role(role)
+    }
+
+    pub fn create_multisig(
+        ctx: Context<CreateMultisig>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        ctx.accounts.create_multisig(&ctx.bumps, signers, threshold)
+    }
+
+    pub fn create_proposal(ctx: Context<CreateProposal>, action: ProposalAction) -> Result<()> {
+        ctx.accounts.create_proposal(&ctx.bumps, action)
+    }
+
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        ctx.accounts.approve_proposal()
+    }
+
+    pub fn update_multisig(
+        ctx: Context<UpdateMultisig>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        ctx.accounts.update_multisig(signers, threshold)
+    }
+
+    pub fn queue_config_change(
+        ctx: Context<QueueConfigChange>,
+        change: ConfigChange,
+    ) -> Result<()> {
+        ctx.accounts.queue_config_change(&ctx.bumps, change)
+    }
+
+    pub fn cancel_config_change(ctx: Context<CancelConfigChange>) -> Result<()> {
+        ctx.accounts.cancel_config_change()
+    }
+
+    pub fn set_outflow_limit(
+        ctx: Context<UpdateOutflowLimit>,
+        outflow_window: i64,
+        outflow_cap: u64,
+    ) -> Result<()> {
+        ctx.accounts.set_outflow_limit(outflow_window, outflow_cap)
+    }
+
+    pub fn set_circuit_breaker(
+        ctx: Context<UpdateCircuitBreaker>,
+        breaker_release_bps: u16,
+        breaker_window: i64,
+        breaker_threshold: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .set_circuit_breaker(breaker_release_bps, breaker_window, breaker_threshold)
+    }
+
+    pub fn set_timelock_delay(
+        ctx: Context<UpdateTimelockDelay>,
+        timelock_delay: i64,
+    ) -> Result<()> {
+        ctx.accounts.set_timelock_delay(timelock_delay)
+    }
+
     pub fn update_fee_collector(
         ctx: Context<UpdateFeeCollector>,
         fee_collector: Pubkey,
@@ -58,16 +142,38 @@ pub mod bridge_program {
         ctx.accounts.update_relayer(relayer_pubkey)
     }
 
-    pub fn update_whitelisted_mint(ctx: Context<UpdateWhitelistedMint>) -> Result<()> {
-        ctx.accounts.update_whitelisted_mint()
+    pub fn update_whitelisted_mint<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateWhitelistedMint<'info>>,
+    ) -> Result<()> {
+        ctx.accounts
+            .update_whitelisted_mint(&ctx.bumps, ctx.remaining_accounts)
+    }
+
+    pub fn add_liquidity<'info>(
+        ctx: Context<'_, '_, '_, 'info, AddLiquidity<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        ctx.accounts.add_liquidity(amount, ctx.remaining_accounts)
     }
 
-    pub fn add_liquidity(ctx: Context<AddLiquidity>, amount: u64) -> Result<()> {
-        ctx.accounts.add_liquidity(amount)
+    pub fn remove_liquidity<'info>(
+        ctx: Context<'_, '_, '_, 'info, RemoveLiquidity<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .remove_liquidity(amount, ctx.remaining_accounts)
     }
 
-    pub fn remove_liquidity(ctx: Context<RemoveLiquidity>, amount: u64) -> Result<()> {
-        ctx.accounts.remove_liquidity(amount)
+    pub fn withdraw_fees<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawFees<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .withdraw_fees(&ctx.bumps, amount, ctx.remaining_accounts)
+    }
+
+    pub fn reconcile(ctx: Context<Reconcile>) -> Result<()> {
+        ctx.accounts.reconcile()
     }
 
     pub fn set_deposit_limits(
@@ -79,10 +185,36 @@ pub mod bridge_program {
             .set_deposit_limits(minimum_deposit, maximum_deposit)
     }
 
+    pub fn set_vault_cap(ctx: Context<UpdateLimitsOrFee>, vault_cap: u64) -> Result<()> {
+        ctx.accounts.set_vault_cap(vault_cap)
+    }
+
+    pub fn set_user_deposit_limits(
+        ctx: Context<UpdateLimitsOrFee>,
+        user_deposit_window: i64,
+        user_deposit_cap: u64,
+        user_deposit_cooldown: i64,
+    ) -> Result<()> {
+        ctx.accounts.set_user_deposit_limits(
+            user_deposit_window,
+            user_deposit_cap,
+            user_deposit_cooldown,
+        )
+    }
+
     pub fn set_fee_amount(ctx: Context<UpdateLimitsOrFee>, fee_amount: u64) -> Result<()> {
         ctx.accounts.set_fee_amount(fee_amount)
     }
 
+    pub fn set_bps_fee(
+        ctx: Context<UpdateLimitsOrFee>,
+        fee_bps: u16,
+        min_bps_fee: u64,
+        max_bps_fee: u64,
+    ) -> Result<()> {
+        ctx.accounts.set_bps_fee(fee_bps, min_bps_fee, max_bps_fee)
+    }
+
     pub fn relayer_pause(ctx: Context<RelayerState>) -> Result<()> {
         ctx.accounts.relayer_pause()
     }
@@ -107,6 +239,16 @@ pub mod bridge_program {
         ctx.accounts.set_whitelist_inactive()
     }
 
+    pub fn set_destination_signature_active(ctx: Context<DestinationSignatureState>) -> Result<()> {
+        ctx.accounts.set_destination_signature_active()
+    }
+
+    pub fn set_destination_signature_inactive(
+        ctx: Context<DestinationSignatureState>,
+    ) -> Result<()> {
+        ctx.accounts.set_destination_signature_inactive()
+    }
+
     pub fn add_to_whitelist(ctx: Context<AddToWhitelist>, address: Pubkey) -> Result<()> {
         ctx.accounts.add_to_whitelist(address)
     }
@@ -115,27 +257,123 @@ pub mod bridge_program {
         ctx.accounts.remove_from_whitelist(address)
     }
 
-    pub fn send_from_liquidity(
-        ctx: Context<SendFromLiquidity>,
+    pub fn set_fee_override(
+        ctx: Context<SetFeeOverride>,
+        mint: Pubkey,
+        address: Pubkey,
+        fee_amount: u64,
+        fee_bps: u16,
+    ) -> Result<()> {
+        ctx.accounts
+            .set_fee_override(&ctx.bumps, mint, address, fee_amount, fee_bps)
+    }
+
+    pub fn remove_fee_override(
+        ctx: Context<RemoveFeeOverride>,
+        mint: Pubkey,
+        address: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.remove_fee_override(mint, address)
+    }
+
+    pub fn send_from_liquidity<'info>(
+        ctx: Context<'_, '_, '_, 'info, SendFromLiquidity<'info>>,
         amount: u64,
-        _receiver: Pubkey,
+        receiver: Pubkey,
+        tx_hash: [u8; 32],
     ) -> Result<()> {
         require!(
-            ctx.accounts.bridge_state.relayer_state == State::Active.to_code(),
+            ctx.accounts.bridge_state.relayer_state == State::Active.to_code()
+                && ctx.accounts.token_config.relayer_state == State::Active.to_code(),
             Errors::ProgramIsPaused
         );
 
-        ctx.accounts.send_from_liquidity(amount)
+        ctx.accounts.send_from_liquidity(
+            &ctx.bumps,
+            amount,
+            receiver,
+            tx_hash,
+            ctx.remaining_accounts,
+        )
+    }
+
+    pub fn set_chain_id(ctx: Context<UpdateChainId>, chain_id: u64) -> Result<()> {
+        ctx.accounts.set_chain_id(chain_id)
+    }
+
+    pub fn set_refund_policy(
+        ctx: Context<UpdateRefundPolicy>,
+        refund_timeout: i64,
+        refund_fee: bool,
+    ) -> Result<()> {
+        ctx.accounts.set_refund_policy(refund_timeout, refund_fee)
+    }
+
+    pub fn mark_deposit_in_flight(
+        ctx: Context<MarkDepositInFlight>,
+        deposit_id: u64,
+    ) -> Result<()> {
+        ctx.accounts.mark_deposit_in_flight(deposit_id)
+    }
+
+    pub fn mark_deposit_processed(
+        ctx: Context<MarkDepositProcessed>,
+        deposit_id: u64,
+    ) -> Result<()> {
+        ctx.accounts.mark_deposit_processed(deposit_id)
+    }
+
+    pub fn claim_refund<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimRefund<'info>>,
+        deposit_id: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .claim_refund(&ctx.bumps, deposit_id, ctx.remaining_accounts)
     }
 
-    pub fn send_to_liquidity(
-        ctx: Context<SendToLiquidity>,
+    pub fn refund_deposit<'info>(
+        ctx: Context<'_, '_, '_, 'info, RefundDeposit<'info>>,
+        deposit_id: u64,
+        reason: RefundReason,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.bridge_state.relayer_state == State::Active.to_code()
+                && ctx.accounts.token_config.relayer_state == State::Active.to_code(),
+            Errors::ProgramIsPaused
+        );
+
+        ctx.accounts
+            .refund_deposit(deposit_id, reason, ctx.remaining_accounts)
+    }
+
+    pub fn set_relayer_committee(
+        ctx: Context<SetRelayerCommittee>,
+        relayers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        ctx.accounts
+            .set_relayer_committee(&ctx.bumps, relayers, threshold)
+    }
+
+    pub fn approve_release(
+        ctx: Context<ApproveRelease>,
+        amount: u64,
+        receiver: Pubkey,
+        tx_hash: [u8; 32],
+        mint: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts
+            .approve_release(&ctx.bumps, amount, receiver, tx_hash, mint)
+    }
+
+    pub fn send_to_liquidity<'info>(
+        ctx: Context<'_, '_, '_, 'info, SendToLiquidity<'info>>,
         amount: u64,
         destination_address: String,
-        destination_address_signature: String,
     ) -> Result<()> {
         require!(
-            ctx.accounts.bridge_state.public_state == State::Active.to_code(),
+            ctx.accounts.bridge_state.public_state == State::Active.to_code()
+                && ctx.accounts.token_config.public_state == State::Active.to_code(),
             Errors::ProgramIsPaused
         );
 
@@ -148,29 +386,67 @@ pub mod bridge_program {
             require!(ctx.accounts.whitelist.is_some(), Errors::NotWhitelisted);
         }
 
-        if ctx.accounts.bridge_state.fee_amount > 0 {
+        if ctx.accounts.fee_amount() > 0 {
+            // the flat fee is paid in wSOL when all wSOL accounts are provided, in native SOL when none are
+            let wsol_fee_accounts = [
+                ctx.accounts.authority_fee_token_account.is_some(),
+                ctx.accounts.fee_vault_ata.is_some(),
+                ctx.accounts.mint_of_fee_token_sent.is_some(),
+            ];
+
             require!(
-                ctx.accounts.authority_fee_token_account.is_some()
-                    && ctx.accounts.fee_collector_ata.is_some()
-                    && ctx.accounts.fee_collector.is_some()
-                    && ctx.accounts.mint_of_fee_token_sent.is_some(),
+                ctx.accounts.fee_vault.is_some()
+                    && (wsol_fee_accounts.iter().all(|provided| *provided)
+                        || wsol_fee_accounts.iter().all(|provided| !provided)),
+                Errors::NoFeeAccountsProvided
+            );
+        }
+
+        // the user deposit account also holds the nonce a destination signature signs
+        if ctx.accounts.token_config.user_deposit_cap > 0
+            || ctx.accounts.token_config.user_deposit_cooldown > 0
+            || ctx.accounts.bridge_state.destination_signature_state == State::Active.to_code()
+        {
+            require!(
+                ctx.accounts.user_deposit.is_some(),
+                Errors::NoUserDepositAccountProvided
+            );
+        }
+
+        if ctx.accounts.token_fee(amount)? > 0 {
+            require!(
+                ctx.accounts.fee_vault_token_ata.is_some() && ctx.accounts.fee_vault.is_some(),
                 Errors::NoFeeAccountsProvided
             );
         }
 
         require!(
-            ctx.accounts.bridge_state.minimum_deposit <= amount
-                && amount <= ctx.accounts.bridge_state.maximum_deposit,
+            ctx.accounts.token_config.minimum_deposit <= amount
+                && amount <= ctx.accounts.token_config.maximum_deposit,
             Errors::PaymentAmountNotInAcceptedRange
         );
 
+        require!(
+            destination_address.len() <= MAX_DESTINATION_ADDRESS_LEN,
+            Errors::DestinationAddressTooLong
+        );
+
+        let destination_key = decode_destination_address(&destination_address)?;
+
+        if ctx.accounts.bridge_state.destination_signature_state == State::Active.to_code() {
+            ctx.accounts
+                .check_destination_signature(amount, &destination_key)?;
+        }
+
         msg!("amount_sent: {}", amount);
         msg!("destination_address: {}", destination_address);
-        msg!(
-            "destination_address_signature: {}",
-            destination_address_signature
-        );
 
-        ctx.accounts.send_to_liquidity(amount)
+        ctx.accounts.send_to_liquidity(
+            &ctx.bumps,
+            amount,
+            destination_address,
+            destination_key,
+            ctx.remaining_accounts,
+        )
     }
 }
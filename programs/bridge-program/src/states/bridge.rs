@@ -1,7 +1,63 @@
 use anchor_lang::prelude::*;
 
+use crate::states::{ConfigChange, RelayerCommittee};
+
 #[account]
 pub struct BridgeState {
+    pub bump: u8,
+    pub relayer_pubkey: Pubkey,
+    pub fee_collector: Pubkey,
+    pub relayer_state: u8,
+    pub public_state: u8,
+    pub whitelist_state: u8,
+    pub deposit_nonce: u64,
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+    pub pauser: Pubkey,
+    pub fee_manager: Pubkey,
+    pub liquidity_manager: Pubkey,
+    pub whitelist_manager: Pubkey,
+    pub multisig_state: u8,
+    pub timelock_delay: i64,
+    // lifetime total of flat fees paid in, not reduced by fee refunds or withdrawals
+    pub fees_collected: u64,
+    pub relayer_committee_state: u8,
+    pub chain_id: u64,
+    pub destination_signature_state: u8,
+    pub refund_timeout: i64,
+    pub refund_fee: bool,
+}
+
+impl Space for BridgeState {
+    const INIT_SPACE: usize = 8
+        + 1 // bump
+        + 32 // relayer_pubkey
+        + 32 // fee_collector
+        + 1 // relayer_state
+        + 1 // public_state
+        + 1 // whitelist_state
+        + 8 // deposit_nonce
+        + 32 // admin
+        + 32 // pending_admin
+        + 32 // pauser
+        + 32 // fee_manager
+        + 32 // liquidity_manager
+        + 32 // whitelist_manager
+        + 1 // multisig_state
+        + 8 // timelock_delay
+        + 8 // fees_collected
+        + 1 // relayer_committee_state
+        + 8 // chain_id
+        + 1 // destination_signature_state
+        + 8 // refund_timeout
+        + 1 // refund_fee
+        + 7; // 7 bytes of padding
+}
+
+// Layout of the bridge state before roles and token configs were introduced, read once by
+// `migrate_bridge_state` to carry a deployed bridge over to the current layout.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyBridgeState {
     pub bump: u8,
     pub mint_of_token_whitelisted: Pubkey,
     pub relayer_pubkey: Pubkey,
@@ -16,7 +72,7 @@ pub struct BridgeState {
     pub fee_amount: u64,
 }
 
-impl Space for BridgeState {
+impl Space for LegacyBridgeState {
     const INIT_SPACE: usize = 8 + 1 + 32 + 32 + 32 + 32 + 8 + 1 + 1 + 1 + 8 + 8 + 8 + 100; // 100 bytes of padding
 }
 
@@ -33,3 +89,52 @@ impl State {
         }
     }
 }
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum Role {
+    Pauser,
+    FeeManager,
+    LiquidityManager,
+    WhitelistManager,
+}
+
+impl BridgeState {
+    pub fn set_role(&mut self, role: Role, account: Pubkey) {
+        match role {
+            Role::Pauser => self.pauser = account,
+            Role::FeeManager => self.fee_manager = account,
+            Role::LiquidityManager => self.liquidity_manager = account,
+            Role::WhitelistManager => self.whitelist_manager = account,
+        }
+    }
+
+    // the single relayer, or a committee member while the relayer committee is active
+    pub fn is_relayer(&self, key: &Pubkey, relayer_committee: Option<&RelayerCommittee>) -> bool {
+        if self.relayer_committee_state == State::Active.to_code() {
+            relayer_committee.is_some_and(|committee| committee.is_relayer(key))
+        } else {
+            *key == self.relayer_pubkey
+        }
+    }
+
+    // account allowed to queue (and apply) a given configuration change
+    pub fn config_authority(&self, change: &ConfigChange) -> Pubkey {
+        match change {
+            ConfigChange::FeeAmount { .. }
+            | ConfigChange::BpsFee { .. }
+            | ConfigChange::DepositLimits { .. }
+            | ConfigChange::UserDepositLimits { .. }
+            | ConfigChange::VaultCap { .. }
+            | ConfigChange::FeeCollector { .. } => self.fee_manager,
+            ConfigChange::Relayer { .. }
+            | ConfigChange::WhitelistedMint { .. }
+            | ConfigChange::TimelockDelay { .. }
+            | ConfigChange::OutflowLimit { .. }
+            | ConfigChange::CircuitBreaker { .. }
+            | ConfigChange::RelayerCommittee { .. }
+            | ConfigChange::ChainId { .. }
+            | ConfigChange::RefundPolicy { .. }
+            | ConfigChange::DestinationSignature { .. } => self.admin,
+        }
+    }
+}
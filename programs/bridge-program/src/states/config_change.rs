@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct QueuedConfigChange {
+    pub bump: u8,
+    pub change: ConfigChange,
+    pub queued_by: Pubkey,
+    pub eta: i64,
+}
+
+impl Space for QueuedConfigChange {
+    const INIT_SPACE: usize = 8 + 1 + ConfigChange::INIT_SPACE + 32 + 8;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub enum ConfigChange {
+    FeeAmount {
+        mint: Pubkey,
+        fee_amount: u64,
+    },
+    DepositLimits {
+        mint: Pubkey,
+        minimum_deposit: u64,
+        maximum_deposit: u64,
+    },
+    Relayer {
+        relayer_pubkey: Pubkey,
+    },
+    FeeCollector {
+        fee_collector: Pubkey,
+    },
+    WhitelistedMint {
+        mint_of_token_whitelisted: Pubkey,
+        vault: Pubkey,
+        // default key when not migrating away from a previous mint or not sweeping its vault
+        previous_mint: Pubkey,
+        sweep_token_account: Pubkey,
+    },
+    TimelockDelay {
+        timelock_delay: i64,
+    },
+    BpsFee {
+        mint: Pubkey,
+        fee_bps: u16,
+        min_bps_fee: u64,
+        max_bps_fee: u64,
+    },
+    OutflowLimit {
+        mint: Pubkey,
+        outflow_window: i64,
+        outflow_cap: u64,
+    },
+    CircuitBreaker {
+        mint: Pubkey,
+        breaker_release_bps: u16,
+        breaker_window: i64,
+        breaker_threshold: u64,
+    },
+    UserDepositLimits {
+        mint: Pubkey,
+        user_deposit_window: i64,
+        user_deposit_cap: u64,
+        user_deposit_cooldown: i64,
+    },
+    VaultCap {
+        mint: Pubkey,
+        vault_cap: u64,
+    },
+    RelayerCommittee {
+        relayers_hash: [u8; 32],
+        threshold: u8,
+    },
+    ChainId {
+        chain_id: u64,
+    },
+    RefundPolicy {
+        refund_timeout: i64,
+        refund_fee: bool,
+    },
+    DestinationSignature {
+        destination_signature_state: u8,
+    },
+}
+
+impl ConfigChange {
+    // sized for the largest variant, WhitelistedMint
+    pub const INIT_SPACE: usize = 1 + 32 + 32 + 32 + 32;
+
+    // one pending change per kind (and scope), used as the queued change PDA seed
+    pub fn kind(&self) -> u8 {
+        match self {
+            ConfigChange::FeeAmount { .. } => 0,
+            ConfigChange::DepositLimits { .. } => 1,
+            ConfigChange::Relayer { .. } => 2,
+            ConfigChange::FeeCollector { .. } => 3,
+            ConfigChange::WhitelistedMint { .. } => 4,
+            ConfigChange::TimelockDelay { .. } => 5,
+            ConfigChange::BpsFee { .. } => 6,
+            ConfigChange::OutflowLimit { .. } => 7,
+            ConfigChange::CircuitBreaker { .. } => 8,
+            ConfigChange::UserDepositLimits { .. } => 9,
+            ConfigChange::VaultCap { .. } => 10,
+            ConfigChange::RelayerCommittee { .. } => 11,
+            ConfigChange::ChainId { .. } => 12,
+            ConfigChange::RefundPolicy { .. } => 13,
+            ConfigChange::DestinationSignature { .. } => 14,
+        }
+    }
+
+    // mint a per-token change applies to, so that changes of different tokens can be queued side
+    // by side; bridge-wide changes use the default key
+    pub fn scope(&self) -> Pubkey {
+        match self {
+            ConfigChange::FeeAmount { mint, .. }
+            | ConfigChange::DepositLimits { mint, .. }
+            | ConfigChange::BpsFee { mint, .. }
+            | ConfigChange::UserDepositLimits { mint, .. }
+            | ConfigChange::VaultCap { mint, .. }
+            | ConfigChange::OutflowLimit { mint, .. }
+            | ConfigChange::CircuitBreaker { mint, .. } => *mint,
+            ConfigChange::WhitelistedMint {
+                mint_of_token_whitelisted,
+                ..
+            } => *mint_of_token_whitelisted,
+            ConfigChange::Relayer { .. }
+            | ConfigChange::FeeCollector { .. }
+            | ConfigChange::TimelockDelay { .. }
+            | ConfigChange::RelayerCommittee { .. }
+            | ConfigChange::ChainId { .. }
+            | ConfigChange::RefundPolicy { .. }
+            | ConfigChange::DestinationSignature { .. } => Pubkey::default(),
+        }
+    }
+}
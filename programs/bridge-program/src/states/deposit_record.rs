@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct DepositRecord {
+    pub bump: u8,
+    pub deposit_id: u64,
+    pub depositor: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub fee: u64,
+    pub token_fee: u64,
+    pub destination_address: [u8; 32],
+    pub slot: u64,
+    pub status: u8,
+    pub timestamp: i64,
+    pub refund_reason: u8,
+    pub fee_wsol: bool,
+}
+
+impl Space for DepositRecord {
+    const INIT_SPACE: usize = 8 + 1 + 8 + 32 + 32 + 8 + 8 + 8 + 32 + 8 + 1 + 8 + 1 + 1;
+}
+
+impl DepositRecord {
+    pub fn is_pending(&self) -> bool {
+        self.status == DepositStatus::Pending.to_code()
+    }
+
+    // not yet processed or refunded; an in flight deposit can only be settled by the relayer
+    pub fn is_open(&self) -> bool {
+        self.is_pending() || self.status == DepositStatus::InFlight.to_code()
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum DepositStatus {
+    Pending = 0,
+    Processed = 1,
+    Refunded = 2,
+    InFlight = 3,
+}
+impl DepositStatus {
+    pub fn to_code(&self) -> u8 {
+        match self {
+            DepositStatus::Pending => 0,
+            DepositStatus::Processed => 1,
+            DepositStatus::Refunded => 2,
+            DepositStatus::InFlight => 3,
+        }
+    }
+}
+
+// why a deposit was refunded; relayers pick any reason but the timeout
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum RefundReason {
+    Timeout = 0,
+    BlacklistedDestination = 1,
+    InsufficientLiquidity = 2,
+    Other = 3,
+}
+impl RefundReason {
+    pub fn to_code(&self) -> u8 {
+        match self {
+            RefundReason::Timeout => 0,
+            RefundReason::BlacklistedDestination => 1,
+            RefundReason::InsufficientLiquidity => 2,
+            RefundReason::Other => 3,
+        }
+    }
+}
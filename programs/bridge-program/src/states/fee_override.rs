@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct FeeOverride {
+    pub bump: u8,
+    pub mint: Pubkey,
+    pub address: Pubkey,
+    pub fee_amount: u64,
+    pub fee_bps: u16,
+}
+
+impl Space for FeeOverride {
+    const INIT_SPACE: usize = 8 + 1 + 32 + 32 + 8 + 2;
+}
@@ -2,3 +2,23 @@ pub mod bridge;
 pub use bridge::BridgeState;
 pub mod whitelist;
 pub use whitelist::WhitelistEntry;
+pub mod transfer_receipt;
+pub use transfer_receipt::TransferReceipt;
+pub mod deposit_record;
+pub use deposit_record::{DepositRecord, DepositStatus, RefundReason};
+pub mod multisig;
+pub use multisig::Multisig;
+pub mod proposal;
+pub use proposal::{Proposal, ProposalAction};
+pub mod config_change;
+pub use config_change::{ConfigChange, QueuedConfigChange};
+pub mod token_config;
+pub use token_config::TokenConfig;
+pub mod fee_override;
+pub use fee_override::FeeOverride;
+pub mod user_deposit;
+pub use user_deposit::UserDeposit;
+pub mod relayer_committee;
+pub use relayer_committee::RelayerCommittee;
+pub mod release_approval;
+pub use release_approval::ReleaseApproval;
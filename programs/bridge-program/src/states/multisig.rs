@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_MULTISIG_SIGNERS;
+
+#[account]
+pub struct Multisig {
+    pub bump: u8,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub proposal_count: u64,
+}
+
+impl Space for Multisig {
+    const INIT_SPACE: usize = 8 + 1 + (4 + 32 * MAX_MULTISIG_SIGNERS) + 1 + 8;
+}
+
+impl Multisig {
+    pub fn is_signer(&self, key: &Pubkey) -> bool {
+        self.signers.contains(key)
+    }
+
+    // approvals from keys that were removed from the signer set no longer count
+    pub fn count_approvals(&self, approvals: &[Pubkey]) -> usize {
+        approvals.iter().filter(|key| self.is_signer(key)).count()
+    }
+
+    pub fn is_valid_config(signers: &[Pubkey], threshold: u8) -> bool {
+        let mut unique = signers.to_vec();
+        unique.sort();
+        unique.dedup();
+
+        !signers.is_empty()
+            && signers.len() <= MAX_MULTISIG_SIGNERS
+            && unique.len() == signers.len()
+            && threshold > 0
+            && threshold as usize <= signers.len()
+    }
+}
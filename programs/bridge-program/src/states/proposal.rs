@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::MAX_MULTISIG_SIGNERS, states::Multisig, Errors};
+
+#[account]
+pub struct Proposal {
+    pub bump: u8,
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub action: ProposalAction,
+    pub approvals: Vec<Pubkey>,
+    pub executed: bool,
+}
+
+impl Space for Proposal {
+    const INIT_SPACE: usize =
+        8 + 1 + 8 + 32 + ProposalAction::INIT_SPACE + (4 + 32 * MAX_MULTISIG_SIGNERS) + 1;
+}
+
+impl Proposal {
+    pub fn execute(&mut self, multisig: &Multisig, action: &ProposalAction) -> Result<()> {
+        require!(!self.executed, Errors::ProposalAlreadyExecuted);
+        require!(self.action == *action, Errors::ProposalMismatch);
+        require!(
+            multisig.count_approvals(&self.approvals) >= multisig.threshold as usize,
+            Errors::ProposalNotApproved
+        );
+
+        self.executed = true;
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub enum ProposalAction {
+    RemoveLiquidity {
+        mint: Pubkey,
+        amount: u64,
+    },
+    UpdateRelayer {
+        relayer_pubkey: Pubkey,
+    },
+    UpdateWhitelistedMint {
+        mint_of_token_whitelisted: Pubkey,
+        // default key when not migrating away from a previous mint or not sweeping its vault
+        previous_mint: Pubkey,
+        sweep_token_account: Pubkey,
+    },
+    UpdateMultisig {
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    },
+    UpdateRelayerCommittee {
+        relayers: Vec<Pubkey>,
+        threshold: u8,
+    },
+}
+
+impl ProposalAction {
+    // sized for the largest variants, UpdateMultisig and UpdateRelayerCommittee
+    pub const INIT_SPACE: usize = 1 + (4 + 32 * MAX_MULTISIG_SIGNERS) + 1;
+}
@@ -0,0 +1,42 @@
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+
+use crate::{constants::MAX_MULTISIG_SIGNERS, states::Multisig};
+
+#[account]
+pub struct RelayerCommittee {
+    pub bump: u8,
+    pub relayers: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+impl Space for RelayerCommittee {
+    const INIT_SPACE: usize = 8 + 1 + (4 + 32 * MAX_MULTISIG_SIGNERS) + 1;
+}
+
+impl RelayerCommittee {
+    pub fn is_relayer(&self, key: &Pubkey) -> bool {
+        self.relayers.contains(key)
+    }
+
+    // approvals from relayers that were removed from the committee no longer count
+    pub fn count_approvals(&self, approvals: &[Pubkey]) -> usize {
+        approvals.iter().filter(|key| self.is_relayer(key)).count()
+    }
+
+    // an empty committee with a zero threshold switches back to the single relayer
+    pub fn is_disabled_config(relayers: &[Pubkey], threshold: u8) -> bool {
+        relayers.is_empty() && threshold == 0
+    }
+
+    pub fn is_valid_config(relayers: &[Pubkey], threshold: u8) -> bool {
+        Self::is_disabled_config(relayers, threshold)
+            || Multisig::is_valid_config(relayers, threshold)
+    }
+
+    // the relayer set does not fit in a queued config change, so the timelock commits to its hash
+    pub fn relayers_hash(relayers: &[Pubkey]) -> [u8; 32] {
+        let keys: Vec<&[u8]> = relayers.iter().map(|key| key.as_ref()).collect();
+
+        hashv(&keys).to_bytes()
+    }
+}
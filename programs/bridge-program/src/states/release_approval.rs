@@ -0,0 +1,45 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::hash::{hashv, Hash},
+};
+
+use crate::constants::MAX_MULTISIG_SIGNERS;
+
+#[account]
+pub struct ReleaseApproval {
+    pub bump: u8,
+    pub tx_hash: [u8; 32],
+    pub receiver: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub approvals: Vec<Pubkey>,
+    // relayer that created the account, refunded its rent when the release closes it
+    pub payer: Pubkey,
+}
+
+impl Space for ReleaseApproval {
+    const INIT_SPACE: usize = 8 + 1 + 32 + 32 + 32 + 8 + (4 + 32 * MAX_MULTISIG_SIGNERS) + 32;
+}
+
+impl ReleaseApproval {
+    // Seed of the approval account, committing to the whole transfer and not only the MultiversX
+    // transaction hash, so that a relayer approving first with a wrong receiver, mint or amount
+    // cannot block the approvals of the real transfer.
+    pub fn transfer_hash(
+        tx_hash: &[u8; 32],
+        receiver: &Pubkey,
+        mint: &Pubkey,
+        amount: u64,
+    ) -> Hash {
+        hashv(&[
+            tx_hash,
+            receiver.as_ref(),
+            mint.as_ref(),
+            &amount.to_le_bytes(),
+        ])
+    }
+
+    pub fn matches(&self, receiver: &Pubkey, mint: &Pubkey, amount: u64) -> bool {
+        self.receiver == *receiver && self.mint == *mint && self.amount == amount
+    }
+}
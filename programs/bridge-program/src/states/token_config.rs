@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::MAX_FEE_BPS, Errors};
+
+#[account]
+pub struct TokenConfig {
+    pub bump: u8,
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub vault_amount: u64,
+    pub relayer_state: u8,
+    pub public_state: u8,
+    pub minimum_deposit: u64,
+    pub maximum_deposit: u64,
+    pub fee_amount: u64,
+    pub fee_bps: u16,
+    pub min_bps_fee: u64,
+    pub max_bps_fee: u64,
+    // lifetime total of basis points fees paid in, not reduced by fee refunds or withdrawals
+    pub fees_collected: u64,
+    pub user_deposit_window: i64,
+    pub user_deposit_cap: u64,
+    pub user_deposit_cooldown: i64,
+    pub vault_cap: u64,
+    pub previous_mint: Pubkey,
+    pub previous_vault: Pubkey,
+    pub outflow_window: i64,
+    pub outflow_cap: u64,
+    pub outflow_window_start: i64,
+    pub outflow_in_window: u64,
+    pub breaker_release_bps: u16,
+    pub breaker_window: i64,
+    pub breaker_threshold: u64,
+    pub breaker_window_start: i64,
+    pub breaker_released_in_window: u64,
+    // amount of deposits that can still be refunded, pending or in flight
+    pub pending_amount: u64,
+}
+
+impl Space for TokenConfig {
+    const INIT_SPACE: usize = 8
+        + 1 // bump
+        + 32 // mint
+        + 32 // vault
+        + 8 // vault_amount
+        + 1 // relayer_state
+        + 1 // public_state
+        + 8 // minimum_deposit
+        + 8 // maximum_deposit
+        + 8 // fee_amount
+        + 2 // fee_bps
+        + 8 // min_bps_fee
+        + 8 // max_bps_fee
+        + 8 // fees_collected
+        + 8 // user_deposit_window
+        + 8 // user_deposit_cap
+        + 8 // user_deposit_cooldown
+        + 8 // vault_cap
+        + 32 // previous_mint
+        + 32 // previous_vault
+        + 8 // outflow_window
+        + 8 // outflow_cap
+        + 8 // outflow_window_start
+        + 8 // outflow_in_window
+        + 2 // breaker_release_bps
+        + 8 // breaker_window
+        + 8 // breaker_threshold
+        + 8 // breaker_window_start
+        + 8 // breaker_released_in_window
+        + 8 // pending_amount
+        + 6; // 6 bytes of padding
+}
+
+impl TokenConfig {
+    // Adds a release to the current outflow window, starting a new window once the previous one
+    // has elapsed. A zero cap disables the limit.
+    pub fn record_outflow(&mut self, amount: u64, now: i64) -> Result<()> {
+        if self.outflow_cap == 0 {
+            return Ok(());
+        }
+
+        if now
+            >= self
+                .outflow_window_start
+                .saturating_add(self.outflow_window)
+        {
+            self.outflow_window_start = now;
+            self.outflow_in_window = 0;
+        }
+
+        let outflow_in_window = self
+            .outflow_in_window
+            .checked_add(amount)
+            .ok_or(Errors::Overflow)?;
+
+        require!(
+            outflow_in_window <= self.outflow_cap,
+            Errors::OutflowCapExceeded
+        );
+
+        self.outflow_in_window = outflow_in_window;
+
+        Ok(())
+    }
+
+    // Whether a release trips the circuit breaker, either by being larger than the configured
+    // fraction of the vault or by pushing the releases of the current window past the threshold.
+    // A release that trips it is not counted. Zero values disable either check.
+    pub fn trips_circuit_breaker(
+        &mut self,
+        amount: u64,
+        vault_amount: u64,
+        now: i64,
+    ) -> Result<bool> {
+        if self.breaker_release_bps > 0
+            && amount as u128 * MAX_FEE_BPS as u128
+                > vault_amount as u128 * self.breaker_release_bps as u128
+        {
+            return Ok(true);
+        }
+
+        if self.breaker_threshold > 0 {
+            if now
+                >= self
+                    .breaker_window_start
+                    .saturating_add(self.breaker_window)
+            {
+                self.breaker_window_start = now;
+                self.breaker_released_in_window = 0;
+            }
+
+            let breaker_released_in_window = self
+                .breaker_released_in_window
+                .checked_add(amount)
+                .ok_or(Errors::Overflow)?;
+
+            if breaker_released_in_window > self.breaker_threshold {
+                return Ok(true);
+            }
+
+            self.breaker_released_in_window = breaker_released_in_window;
+        }
+
+        Ok(false)
+    }
+}
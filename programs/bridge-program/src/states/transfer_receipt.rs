@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct TransferReceipt {
+    pub bump: u8,
+    pub tx_hash: [u8; 32],
+    pub receiver: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub slot: u64,
+}
+
+impl Space for TransferReceipt {
+    const INIT_SPACE: usize = 8 + 1 + 32 + 32 + 32 + 8 + 8;
+}
+
+impl TransferReceipt {
+    // a freshly created receipt is zeroed, a paid out one always has the release slot set
+    pub fn is_processed(&self) -> bool {
+        self.slot != 0
+    }
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::{states::TokenConfig, Errors};
+
+#[account]
+pub struct UserDeposit {
+    pub bump: u8,
+    pub depositor: Pubkey,
+    pub mint: Pubkey,
+    pub window_start: i64,
+    pub deposited_in_window: u64,
+    pub last_deposit: i64,
+    // deposits of this user in this token, part of the message a destination signature signs
+    pub deposit_nonce: u64,
+}
+
+impl Space for UserDeposit {
+    const INIT_SPACE: usize = 8 + 1 + 32 + 32 + 8 + 8 + 8 + 8;
+}
+
+impl UserDeposit {
+    // Enforces the token's per-user cooldown and window cap, then records the deposit.
+    pub fn record_deposit(
+        &mut self,
+        amount: u64,
+        token_config: &TokenConfig,
+        now: i64,
+    ) -> Result<()> {
+        if token_config.user_deposit_cooldown > 0 && self.last_deposit > 0 {
+            require!(
+                now >= self
+                    .last_deposit
+                    .saturating_add(token_config.user_deposit_cooldown),
+                Errors::DepositCooldownActive
+            );
+        }
+
+        if token_config.user_deposit_cap > 0 {
+            if now
+                >= self
+                    .window_start
+                    .saturating_add(token_config.user_deposit_window)
+            {
+                self.window_start = now;
+                self.deposited_in_window = 0;
+            }
+
+            let deposited_in_window = self
+                .deposited_in_window
+                .checked_add(amount)
+                .ok_or(Errors::Overflow)?;
+
+            require!(
+                deposited_in_window <= token_config.user_deposit_cap,
+                Errors::UserDepositCapExceeded
+            );
+
+            self.deposited_in_window = deposited_in_window;
+        }
+
+        self.last_deposit = now;
+
+        Ok(())
+    }
+}
@@ -1,9 +1,463 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        ed25519_program, keccak,
+        sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+    },
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::token_interface::{
+    spl_token_2022::{
+        self,
+        extension::{
+            transfer_fee::TransferFeeConfig, BaseStateWithExtensions, ExtensionType,
+            StateWithExtensions,
+        },
+        onchain::invoke_transfer_checked,
+    },
+    TokenAccount, TransferChecked,
+};
+
+use crate::{
+    constants::{
+        CONFIG_CHANGE_GRACE_PERIOD, DESTINATION_ADDRESS_HRP, MAX_FEE_BPS, MULTIVERSX_MESSAGE_PREFIX,
+    },
+    events::ProposalExecuted,
+    states::{
+        bridge::State, BridgeState, ConfigChange, Multisig, Proposal, ProposalAction,
+        QueuedConfigChange, RelayerCommittee, TokenConfig,
+    },
+    Errors,
+};
+
 pub fn check_amount(amount: u64, decimals: u8) -> bool {
     let token_decimals = 10u64.pow(decimals as u32);
 
     amount % token_decimals == 0
 }
 
+// Percentage fee taken in the bridged token, clamped to the min/max caps (a zero max means no cap).
+// The fee is rounded up to whole tokens (and a maximum down), so that a whole deposit minus the fee
+// is still a whole amount to bridge.
+pub fn compute_bps_fee(
+    amount: u64,
+    fee_bps: u16,
+    min_bps_fee: u64,
+    max_bps_fee: u64,
+    decimals: u8,
+) -> Result<u64> {
+    if fee_bps == 0 {
+        return Ok(0);
+    }
+
+    let token_decimals = 10u128.pow(decimals as u32);
+
+    // fee_bps never exceeds MAX_FEE_BPS, so the fee never exceeds the amount before the min cap
+    let mut fee = (amount as u128 * fee_bps as u128 / MAX_FEE_BPS as u128).max(min_bps_fee as u128);
+
+    fee = fee.div_ceil(token_decimals) * token_decimals;
+
+    if max_bps_fee > 0 {
+        fee = fee.min(max_bps_fee as u128 / token_decimals * token_decimals);
+    }
+
+    require!(fee <= amount as u128, Errors::FeeExceedsAmount);
+
+    Ok(fee as u64)
+}
+
+// While the multisig is active, privileged actions need an approved proposal matching the call.
+pub fn execute_multisig_proposal<'info>(
+    bridge_state: &BridgeState,
+    multisig: &Option<Box<Account<'info, Multisig>>>,
+    proposal: &mut Option<Box<Account<'info, Proposal>>>,
+    action: ProposalAction,
+) -> Result<()> {
+    if bridge_state.multisig_state != State::Active.to_code() {
+        return Ok(());
+    }
+
+    match (multisig, proposal) {
+        (Some(multisig), Some(proposal)) => {
+            proposal.execute(multisig, &action)?;
+
+            emit!(ProposalExecuted {
+                proposal_id: proposal.proposal_id,
+            });
+
+            Ok(())
+        }
+        _ => err!(Errors::MultisigProposalRequired),
+    }
+}
+
+// The fee vault is a system account holding the native SOL fees. It is kept rent-exempt from the
+// start, so that a flat fee below the rent minimum can still be paid into it.
+pub fn fund_fee_vault<'info>(
+    system_program: AccountInfo<'info>,
+    payer: AccountInfo<'info>,
+    fee_vault: AccountInfo<'info>,
+) -> Result<()> {
+    let lamports = Rent::get()?
+        .minimum_balance(0)
+        .saturating_sub(fee_vault.lamports());
+
+    if lamports > 0 {
+        transfer(
+            CpiContext::new(
+                system_program,
+                Transfer {
+                    from: payer,
+                    to: fee_vault,
+                },
+            ),
+            lamports,
+        )?;
+    }
+
+    Ok(())
+}
+
+// The vault must always hold at least the tracked vault_amount after a transfer. Tokens sent
+// straight to the vault only add a surplus, which reconcile folds into vault_amount.
+pub fn check_vault_amount(
+    vault: &mut InterfaceAccount<'_, TokenAccount>,
+    token_config: &TokenConfig,
+) -> Result<()> {
+    vault.reload()?;
+
+    require!(
+        vault.amount >= token_config.vault_amount,
+        Errors::VaultAmountMismatch
+    );
+
+    Ok(())
+}
+
+// Token-2022 mints are only accepted with extensions the bridge handles: transfer fees are netted
+// out of what the vault is credited and transfer hooks get their extra accounts forwarded.
+pub fn check_mint_extensions(mint: &AccountInfo) -> Result<()> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(());
+    }
+
+    let data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+
+    for extension in mint.get_extension_types()? {
+        match extension {
+            ExtensionType::TransferFeeConfig
+            | ExtensionType::TransferHook
+            | ExtensionType::MetadataPointer
+            | ExtensionType::TokenMetadata => {}
+            // tokens that can never leave the depositor's account cannot be bridged
+            ExtensionType::NonTransferable => return err!(Errors::NonTransferableMint),
+            _ => return err!(Errors::UnsupportedMintExtension),
+        }
+    }
+
+    Ok(())
+}
+
+// Part of a transfer of `amount` withheld by the Token-2022 transfer fee of the mint, if any.
+pub fn transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(0);
+    }
+
+    let data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+
+    match mint.get_extension::<TransferFeeConfig>() {
+        Ok(config) => Ok(config
+            .calculate_epoch_fee(Clock::get()?.epoch, amount)
+            .ok_or(Errors::Overflow)?),
+        Err(_) => Ok(0),
+    }
+}
+
+// transfer_checked that also resolves the extra accounts of a Token-2022 transfer hook from the
+// remaining accounts of the context; a plain transfer for mints without a hook.
+pub fn transfer_checked_with_hook<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, TransferChecked<'info>>,
+    amount: u64,
+    decimals: u8,
+) -> Result<()> {
+    invoke_transfer_checked(
+        ctx.program.key,
+        ctx.accounts.from,
+        ctx.accounts.mint,
+        ctx.accounts.to,
+        ctx.accounts.authority,
+        &ctx.remaining_accounts,
+        amount,
+        decimals,
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
+}
+
+// While a timelock delay is set, configuration changes must be queued first and can only be
+// applied once the delay has elapsed. The queued change is closed to the authority on apply.
+pub fn execute_queued_config_change<'info>(
+    bridge_state: &BridgeState,
+    queued_change: &Option<Box<Account<'info, QueuedConfigChange>>>,
+    authority: AccountInfo<'info>,
+    change: &ConfigChange,
+) -> Result<()> {
+    if bridge_state.timelock_delay == 0 {
+        return Ok(());
+    }
+
+    match queued_change {
+        Some(queued_change) => {
+            require!(
+                queued_change.change == *change,
+                Errors::ConfigChangeMismatch
+            );
+
+            let now = Clock::get()?.unix_timestamp;
+
+            require!(now >= queued_change.eta, Errors::TimelockNotExpired);
+            require!(
+                now <= queued_change.eta.saturating_add(CONFIG_CHANGE_GRACE_PERIOD),
+                Errors::ConfigChangeExpired
+            );
+
+            queued_change.close(authority)
+        }
+        None => err!(Errors::ConfigChangeNotQueued),
+    }
+}
+
+const BECH32_CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+fn bech32_polymod(values: impl Iterator<Item = u8>) -> u32 {
+    const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+    let mut checksum = 1u32;
+
+    for value in values {
+        let top = checksum >> 25;
+        checksum = ((checksum & 0x1ffffff) << 5) ^ value as u32;
+
+        for (i, generator) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                checksum ^= generator;
+            }
+        }
+    }
+
+    checksum
+}
+
+// Decodes a lowercase erd1 bech32 address into its 32 byte public key, checking the HRP, the
+// checksum and the payload length. A typo would otherwise lock the funds on the MultiversX side.
+pub fn decode_destination_address(address: &str) -> Result<[u8; 32]> {
+    let (hrp, data) = address
+        .rsplit_once('1')
+        .ok_or(Errors::InvalidDestinationAddress)?;
+
+    // 52 five bit words for the 32 byte key followed by the 6 word checksum
+    require!(
+        hrp == DESTINATION_ADDRESS_HRP && data.len() == 58,
+        Errors::InvalidDestinationAddress
+    );
+
+    let mut words = Vec::with_capacity(data.len());
+
+    for c in data.bytes() {
+        let word = BECH32_CHARSET
+            .iter()
+            .position(|charset_c| *charset_c == c)
+            .ok_or(Errors::InvalidDestinationAddress)?;
+
+        words.push(word as u8);
+    }
+
+    let expanded_hrp = hrp
+        .bytes()
+        .map(|c| c >> 5)
+        .chain(std::iter::once(0))
+        .chain(hrp.bytes().map(|c| c & 31));
+
+    require!(
+        bech32_polymod(expanded_hrp.chain(words.iter().copied())) == 1,
+        Errors::InvalidDestinationAddress
+    );
+
+    let mut key = [0u8; 32];
+    let mut accumulator = 0u32;
+    let mut bits = 0u32;
+    let mut index = 0;
+
+    for word in &words[..words.len() - 6] {
+        accumulator = (accumulator << 5) | *word as u32;
+        bits += 5;
+
+        if bits >= 8 {
+            bits -= 8;
+            key[index] = (accumulator >> bits) as u8;
+            index += 1;
+            accumulator &= (1 << bits) - 1;
+        }
+    }
+
+    // the 4 leftover padding bits must be zero
+    require!(accumulator == 0, Errors::InvalidDestinationAddress);
+
+    Ok(key)
+}
+
+// Canonical message a release attestation signs: tx_hash, receiver, amount, mint and chain id.
+pub fn release_attestation_message(
+    tx_hash: &[u8; 32],
+    receiver: &Pubkey,
+    amount: u64,
+    mint: &Pubkey,
+    chain_id: u64,
+) -> Vec<u8> {
+    let mut message = Vec::with_capacity(32 + 32 + 8 + 32 + 8);
+
+    message.extend_from_slice(tx_hash);
+    message.extend_from_slice(receiver.as_ref());
+    message.extend_from_slice(&amount.to_le_bytes());
+    message.extend_from_slice(mint.as_ref());
+    message.extend_from_slice(&chain_id.to_le_bytes());
+
+    message
+}
+
+// Canonical message the relayer committee signs to move a deposit out of pending: deposit id,
+// new status, refund reason (0 when processed) and chain id.
+pub fn deposit_status_message(
+    deposit_id: u64,
+    status: u8,
+    refund_reason: u8,
+    chain_id: u64,
+) -> Vec<u8> {
+    let mut message = Vec::with_capacity(8 + 1 + 1 + 8);
+
+    message.extend_from_slice(&deposit_id.to_le_bytes());
+    message.push(status);
+    message.push(refund_reason);
+    message.extend_from_slice(&chain_id.to_le_bytes());
+
+    message
+}
+
+// Message the MultiversX destination key signs to prove the depositor controls it, hashed the way
+// MultiversX wallets sign messages: keccak256("\x17Elrond Signed Message:\n" + length + message),
+// where the message is "<depositor>:<mint>:<amount>:<user deposit nonce>". The nonce is the
+// depositor's own, so deposits of other users cannot invalidate a signature.
+pub fn destination_signature_message(
+    depositor: &Pubkey,
+    mint: &Pubkey,
+    amount: u64,
+    deposit_nonce: u64,
+) -> [u8; 32] {
+    let message = format!("{}:{}:{}:{}", depositor, mint, amount, deposit_nonce);
+
+    keccak::hashv(&[
+        MULTIVERSX_MESSAGE_PREFIX,
+        message.len().to_string().as_bytes(),
+        message.as_bytes(),
+    ])
+    .to_bytes()
+}
+
+// Keys that signed `message` in the data of an Ed25519 program instruction: a signature count,
+// a padding byte, then a 14 byte offsets entry per signature. Only signatures whose key, signature
+// and message are all stored in the instruction itself (index u16::MAX) are accepted.
+pub fn parse_ed25519_signers(data: &[u8], message: &[u8]) -> Result<Vec<Pubkey>> {
+    let count = *data.first().ok_or(Errors::InvalidAttestation)? as usize;
+
+    let mut signers = Vec::with_capacity(count);
+
+    for i in 0..count {
+        let start = 2 + i * 14;
+        let offsets = data
+            .get(start..start + 14)
+            .ok_or(Errors::InvalidAttestation)?;
+        let read = |at: usize| u16::from_le_bytes([offsets[at], offsets[at + 1]]);
+
+        require!(
+            read(2) == u16::MAX && read(6) == u16::MAX && read(12) == u16::MAX,
+            Errors::InvalidAttestation
+        );
+
+        let pubkey_offset = read(4) as usize;
+        let message_offset = read(8) as usize;
+
+        let pubkey = data
+            .get(pubkey_offset..pubkey_offset + 32)
+            .ok_or(Errors::InvalidAttestation)?;
+        let signed_message = data
+            .get(message_offset..message_offset + read(10) as usize)
+            .ok_or(Errors::InvalidAttestation)?;
+
+        if signed_message == message {
+            let mut key = [0u8; 32];
+            key.copy_from_slice(pubkey);
+            signers.push(Pubkey::new_from_array(key));
+        }
+    }
+
+    Ok(signers)
+}
+
+// The Ed25519 program fails the whole transaction on a bad signature, so any Ed25519 instruction
+// placed before this one proves its keys signed the message.
+pub fn ed25519_message_signers(
+    instructions_sysvar: &AccountInfo,
+    message: &[u8],
+) -> Result<Vec<Pubkey>> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+
+    let mut signers = Vec::new();
+
+    for index in 0..current_index {
+        let instruction = load_instruction_at_checked(index as usize, instructions_sysvar)?;
+
+        if instruction.program_id == ed25519_program::ID {
+            signers.extend(parse_ed25519_signers(&instruction.data, message)?);
+        }
+    }
+
+    signers.sort();
+    signers.dedup();
+
+    Ok(signers)
+}
+
+// While the relayer committee is active, marking a deposit processed or refunding it needs Ed25519
+// signatures over the deposit status message from at least the committee threshold, like a release.
+pub fn check_deposit_status_approval(
+    bridge_state: &BridgeState,
+    relayer_committee: Option<&RelayerCommittee>,
+    instructions_sysvar: Option<&AccountInfo>,
+    message: &[u8],
+) -> Result<()> {
+    if bridge_state.relayer_committee_state != State::Active.to_code() {
+        return Ok(());
+    }
+
+    let (Some(relayer_committee), Some(instructions_sysvar)) =
+        (relayer_committee, instructions_sysvar)
+    else {
+        return err!(Errors::DepositStatusNotApproved);
+    };
+
+    let signers = ed25519_message_signers(instructions_sysvar, message)?;
+
+    require!(
+        relayer_committee.count_approvals(&signers) >= relayer_committee.threshold as usize,
+        Errors::DepositStatusNotApproved
+    );
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -22,4 +476,118 @@ mod tests {
 
         assert_eq!(check, false);
     }
+
+    #[test]
+    fn compute_bps_fee_test() {
+        assert_eq!(compute_bps_fee(1_000_000, 0, 5_000, 0, 0).unwrap(), 0);
+
+        assert_eq!(compute_bps_fee(1_000_000, 25, 0, 0, 0).unwrap(), 2_500);
+        assert_eq!(
+            compute_bps_fee(u64::MAX, 25, 0, 0, 0).unwrap(),
+            u64::MAX / 400
+        );
+
+        assert_eq!(
+            compute_bps_fee(1_000_000, 25, 5_000, 10_000, 0).unwrap(),
+            5_000
+        );
+        assert_eq!(
+            compute_bps_fee(100_000_000, 25, 5_000, 10_000, 0).unwrap(),
+            10_000
+        );
+        assert!(compute_bps_fee(1_000, 25, 5_000, 10_000, 0).is_err());
+
+        // 1% of 150 tokens with 9 decimals is 1.5 tokens, rounded up to 2
+        assert_eq!(
+            compute_bps_fee(150_000_000_000, 100, 0, 0, 9).unwrap(),
+            2_000_000_000
+        );
+        // a fractional minimum is rounded up and a fractional maximum down
+        assert_eq!(
+            compute_bps_fee(40_000_000_000, 100, 500_000_000, 0, 9).unwrap(),
+            1_000_000_000
+        );
+        assert_eq!(
+            compute_bps_fee(900_000_000_000, 100, 0, 2_500_000_000, 9).unwrap(),
+            2_000_000_000
+        );
+        assert_eq!(
+            compute_bps_fee(2_000_000_000, 100, 0, 0, 9).unwrap(),
+            1_000_000_000
+        );
+        assert!(compute_bps_fee(1_000_000_000, 100, 1_500_000_000, 0, 9).is_err());
+    }
+
+    #[test]
+    fn decode_destination_address_test() {
+        let key = decode_destination_address(
+            "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th",
+        )
+        .unwrap();
+
+        assert_eq!(key[..4], [0x01, 0x39, 0x47, 0x2e]);
+        assert_eq!(key[28..], [0xa6, 0x0d, 0x69, 0xe1]);
+
+        // wrong checksum, wrong hrp, bad character and short payload
+        assert!(decode_destination_address(
+            "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6tq"
+        )
+        .is_err());
+        assert!(decode_destination_address(
+            "xrd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th"
+        )
+        .is_err());
+        assert!(decode_destination_address(
+            "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6tb"
+        )
+        .is_err());
+        assert!(decode_destination_address("erd1qyu5wthldzr8wx5c9ucg8kjagg").is_err());
+        assert!(decode_destination_address("erd...").is_err());
+    }
+
+    #[test]
+    fn parse_ed25519_signers_test() {
+        let signer = Pubkey::new_unique();
+        let message = release_attestation_message(
+            &[7u8; 32],
+            &Pubkey::new_unique(),
+            100,
+            &Pubkey::new_unique(),
+            1,
+        );
+
+        // same layout as the Ed25519 program instruction built by the web3 and sdk helpers
+        let pubkey_offset = 2 + 14;
+        let signature_offset = pubkey_offset + 32;
+        let message_offset = signature_offset + 64;
+
+        let mut data = vec![1u8, 0u8];
+        for value in [
+            signature_offset as u16,
+            u16::MAX,
+            pubkey_offset as u16,
+            u16::MAX,
+            message_offset as u16,
+            message.len() as u16,
+            u16::MAX,
+        ] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.extend_from_slice(signer.as_ref());
+        data.extend_from_slice(&[0u8; 64]);
+        data.extend_from_slice(&message);
+
+        assert_eq!(
+            parse_ed25519_signers(&data, &message).unwrap(),
+            vec![signer]
+        );
+        assert!(parse_ed25519_signers(&data, &message[1..])
+            .unwrap()
+            .is_empty());
+
+        data[4] = 0;
+        assert!(parse_ed25519_signers(&data, &message).is_err());
+
+        assert!(parse_ed25519_signers(&data[..10], &message).is_err());
+    }
 }
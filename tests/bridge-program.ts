@@ -4,25 +4,36 @@ import {BridgeProgram} from '../target/types/bridge_program'
 import {
   createAssociatedTokenAccountIdempotentInstruction,
   createInitializeMint2Instruction,
+  createInitializeNonTransferableMintInstruction,
+  createInitializeTransferFeeConfigInstruction,
   createMintToInstruction,
+  createTransferInstruction,
   createWrappedNativeAccount,
+  ExtensionType,
   getAccount,
   getAssociatedTokenAddressSync,
   getMinimumBalanceForRentExemptMint,
+  getMintLen,
   getOrCreateAssociatedTokenAccount,
   MINT_SIZE,
   NATIVE_MINT,
+  TOKEN_2022_PROGRAM_ID,
   TOKEN_PROGRAM_ID,
   TokenAccountNotFoundError,
 } from '@solana/spl-token'
 import {
+  Ed25519Program,
   PublicKey,
   SystemProgram,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
   Transaction,
+  TransactionInstruction,
   LAMPORTS_PER_SOL,
 } from '@solana/web3.js'
 import {Keypair} from '@solana/web3.js'
 import {assert, expect} from 'chai'
+import {createHash} from 'crypto'
+import {keccak_256} from '@noble/hashes/sha3'
 
 require('dotenv').config()
 
@@ -35,6 +46,17 @@ describe('bridge-program', () => {
 
   const connection = provider.connection
 
+  const destinationAddress =
+    'erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th'
+  // MultiversX test wallet alice, the key behind destinationAddress
+  const destinationKeypair = Keypair.fromSeed(
+    Buffer.from(
+      '413f42575f7f26fad3317a778771212fdb80245850981e48b58a4f25e344e8f9',
+      'hex'
+    )
+  )
+  const destinationKey = destinationKeypair.publicKey.toBuffer()
+
   const PRIVATE_KEY_STR = process.env.UNIT_TEST_PRIVATE_KEY
   const privateKeys = PRIVATE_KEY_STR.split(',').map(Number)
 
@@ -77,6 +99,11 @@ describe('bridge-program', () => {
     program.programId
   )[0]
 
+  const programDataPda = PublicKey.findProgramAddressSync(
+    [program.programId.toBuffer()],
+    new PublicKey('BPFLoaderUpgradeab1e11111111111111111111111')
+  )[0]
+
   const user2WhitelistPda = PublicKey.findProgramAddressSync(
     [user2.publicKey.toBuffer(), bridgeStatePda.toBuffer()],
     program.programId
@@ -94,15 +121,228 @@ describe('bridge-program', () => {
     true
   )
 
+  const fee_collector_itheum_ata = getAssociatedTokenAddressSync(
+    itheum_token_mint.publicKey,
+    fee_collector.publicKey,
+    true
+  )
+
+  const feeVaultPda = PublicKey.findProgramAddressSync(
+    [Buffer.from('fee_vault')],
+    program.programId
+  )[0]
+
+  const fee_vault_ata = getAssociatedTokenAddressSync(
+    NATIVE_MINT,
+    feeVaultPda,
+    true
+  )
+
+  const fee_vault_itheum_ata = getAssociatedTokenAddressSync(
+    itheum_token_mint.publicKey,
+    feeVaultPda,
+    true
+  )
+
   const another_token_vault_ata = getAssociatedTokenAddressSync(
     another_token_mint.publicKey,
     bridgeStatePda,
     true
   )
 
+  const wsol_vault_ata = getAssociatedTokenAddressSync(
+    NATIVE_MINT,
+    bridgeStatePda,
+    true
+  )
+
+  const txHash = (id: number): number[] => {
+    const hash = Buffer.alloc(32)
+    hash.writeUInt32BE(id, 28)
+    return Array.from(hash)
+  }
+
+  const transferReceiptPda = (hash: number[]) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('transfer_receipt'), Buffer.from(hash)],
+      program.programId
+    )[0]
+
+  // seeded by the hash of the whole transfer, not only the transaction hash
+  const releaseApprovalPda = (
+    hash: number[],
+    receiver: PublicKey,
+    mint: PublicKey,
+    amount: anchor.BN
+  ) =>
+    PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('release_approval'),
+        createHash('sha256')
+          .update(
+            Buffer.concat([
+              Buffer.from(hash),
+              receiver.toBuffer(),
+              mint.toBuffer(),
+              amount.toArrayLike(Buffer, 'le', 8),
+            ])
+          )
+          .digest(),
+      ],
+      program.programId
+    )[0]
+
+  const releaseAttestation = (
+    signer: Keypair,
+    hash: number[],
+    receiver: PublicKey,
+    amount: anchor.BN,
+    mint: PublicKey,
+    chainId: anchor.BN
+  ) =>
+    Ed25519Program.createInstructionWithPrivateKey({
+      privateKey: signer.secretKey,
+      message: Buffer.concat([
+        Buffer.from(hash),
+        receiver.toBuffer(),
+        amount.toArrayLike(Buffer, 'le', 8),
+        mint.toBuffer(),
+        chainId.toArrayLike(Buffer, 'le', 8),
+      ]),
+    })
+
+  const depositStatusAttestation = (
+    signer: Keypair,
+    depositId: anchor.BN,
+    status: number,
+    refundReason: number,
+    chainId: anchor.BN
+  ) =>
+    Ed25519Program.createInstructionWithPrivateKey({
+      privateKey: signer.secretKey,
+      message: Buffer.concat([
+        depositId.toArrayLike(Buffer, 'le', 8),
+        Buffer.from([status, refundReason]),
+        chainId.toArrayLike(Buffer, 'le', 8),
+      ]),
+    })
+
+  const [relayerCommitteePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('relayer_committee')],
+    program.programId
+  )
+
+  const tokenConfigPda = (mint: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('token_config'), mint.toBuffer()],
+      program.programId
+    )[0]
+
+  const itheumTokenConfigPda = tokenConfigPda(itheum_token_mint.publicKey)
+
+  const multisigPda = PublicKey.findProgramAddressSync(
+    [Buffer.from('multisig')],
+    program.programId
+  )[0]
+
+  const proposalPda = (id: number) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('proposal'), new anchor.BN(id).toArrayLike(Buffer, 'le', 8)],
+      program.programId
+    )[0]
+
+  const queuedChangePda = (kind: number, scope = PublicKey.default) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('config_change'), Buffer.from([kind]), scope.toBuffer()],
+      program.programId
+    )[0]
+
+  // MultiversX wallets sign keccak256 of the prefixed message
+  const signedMessageHash = (message: string) =>
+    Buffer.from(
+      keccak_256(
+        Buffer.concat([
+          Buffer.from('\x17Elrond Signed Message:\n'),
+          Buffer.from(message.length.toString()),
+          Buffer.from(message),
+        ])
+      )
+    )
+
+  const depositRecordPda = async (): Promise<PublicKey> => {
+    const bridgeState = await program.account.bridgeState.fetchNullable(
+      bridgeStatePda
+    )
+    const nonce = bridgeState ? bridgeState.depositNonce : new anchor.BN(0)
+
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from('deposit_record'), nonce.toArrayLike(Buffer, 'le', 8)],
+      program.programId
+    )[0]
+  }
+
+  const depositRecordByIdPda = (depositId: anchor.BN) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('deposit_record'), depositId.toArrayLike(Buffer, 'le', 8)],
+      program.programId
+    )[0]
+
+  const createToken2022Mint = async (
+    extension: ExtensionType,
+    initializeExtension: (mint: PublicKey) => TransactionInstruction
+  ): Promise<PublicKey> => {
+    const mint = Keypair.generate()
+    const space = getMintLen([extension])
+
+    let tx = new Transaction()
+    tx.instructions = [
+      SystemProgram.createAccount({
+        fromPubkey: provider.publicKey,
+        newAccountPubkey: mint.publicKey,
+        lamports: await connection.getMinimumBalanceForRentExemption(space),
+        space,
+        programId: TOKEN_2022_PROGRAM_ID,
+      }),
+      initializeExtension(mint.publicKey),
+      createInitializeMint2Instruction(
+        mint.publicKey,
+        9,
+        admin.publicKey,
+        admin.publicKey,
+        TOKEN_2022_PROGRAM_ID
+      ),
+    ]
+    await provider.sendAndConfirm(tx, [mint])
+
+    return mint.publicKey
+  }
+
+  const feeOverridePda = (
+    address: PublicKey,
+    mint = itheum_token_mint.publicKey
+  ) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('fee_override'), mint.toBuffer(), address.toBuffer()],
+      program.programId
+    )[0]
+
+  const userDepositPda = (address: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('user_deposit'),
+        itheum_token_mint.publicKey.toBuffer(),
+        address.toBuffer(),
+      ],
+      program.programId
+    )[0]
+
   let user_wsol_ata: PublicKey
   let user2_wsol_ata: PublicKey
 
+  let refundDepositId: anchor.BN
+  let committeeDepositId: anchor.BN
+  let userBalanceBeforeDeposit: bigint
+
   const confirm = async (signature: string): Promise<string> => {
     const block = await connection.getLatestBlockhash()
     await connection.confirmTransaction({
@@ -120,6 +360,20 @@ describe('bridge-program', () => {
     return signature
   }
 
+  const eventParser = new anchor.EventParser(
+    program.programId,
+    new anchor.BorshCoder(program.idl)
+  )
+
+  const events = async (signature: string) => {
+    const tx = await connection.getTransaction(signature, {
+      commitment: 'confirmed',
+      maxSupportedTransactionVersion: 0,
+    })
+
+    return Array.from(eventParser.parseLogs(tx.meta.logMessages))
+  }
+
   before('Airdrop and create mints', async () => {
     let lamports = await getMinimumBalanceForRentExemptMint(connection)
 
@@ -217,19 +471,26 @@ describe('bridge-program', () => {
   it('Send to liquidity by user - bridge state not initialized (should fail)', async () => {
     try {
       await program.methods
-        .sendToLiquidity(new anchor.BN(100e9), 'erd', 'signature')
+        .sendToLiquidity(new anchor.BN(100e9), 'erd')
         .signers([user])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
           vault: vault_ata,
           mintOfTokenSent: itheum_token_mint.publicKey,
           whitelist: null,
+          feeOverride: null,
           authority: user.publicKey,
           authorityTokenAccount: itheum_token_user_ata,
-          feeCollector: null,
+          depositRecord: await depositRecordPda(),
+          userDeposit: null,
+          feeVault: null,
           mintOfFeeTokenSent: null,
           authorityFeeTokenAccount: null,
-          feeCollectorAta: null,
+          feeVaultAta: null,
+          feeVaultTokenAta: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc()
       assert(false, 'Should have thrown error')
@@ -245,6 +506,7 @@ describe('bridge-program', () => {
     try {
       await program.methods
         .initializeContract(
+          user.publicKey,
           user.publicKey,
           fee_collector.publicKey,
           new anchor.BN(0),
@@ -254,16 +516,21 @@ describe('bridge-program', () => {
         .signers([user])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
           vault: vault_ata,
+          feeVault: feeVaultPda,
           mintOfTokenWhitelisted: itheum_token_mint.publicKey,
           authority: user.publicKey,
+          program: program.programId,
+          programData: programDataPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc()
       assert(false, 'Should have thrown error')
     } catch (err) {
-      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(2012)
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6004)
       expect((err as anchor.AnchorError).error.errorMessage).to.equal(
-        'An address constraint was violated'
+        'Not privileged'
       )
     }
   })
@@ -271,103 +538,167 @@ describe('bridge-program', () => {
   it('Initialize contract by admin', async () => {
     await program.methods
       .initializeContract(
+        admin.publicKey,
         admin.publicKey,
         fee_collector.publicKey,
         new anchor.BN(0),
         new anchor.BN(0),
         new anchor.BN(1000e10)
       )
-      .signers([admin])
       .accounts({
         bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
         vault: vault_ata,
+        feeVault: feeVaultPda,
         mintOfTokenWhitelisted: itheum_token_mint.publicKey,
-        authority: admin.publicKey,
+        authority: provider.publicKey,
+        program: program.programId,
+        programData: programDataPda,
+        tokenProgram: TOKEN_PROGRAM_ID,
       })
       .rpc()
 
     let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
 
+    assert(bridgeState.admin.equals(admin.publicKey))
+
     assert(bridgeState.relayerState === 0)
     assert(bridgeState.publicState === 0)
     assert(bridgeState.relayerPubkey.equals(admin.publicKey))
-    assert(bridgeState.vault.equals(vault_ata))
-    assert(bridgeState.vaultAmount.toNumber() === 0)
-    assert(
-      bridgeState.mintOfTokenWhitelisted.equals(itheum_token_mint.publicKey)
+
+    let tokenConfig = await program.account.tokenConfig.fetch(
+      itheumTokenConfigPda
     )
-    assert(bridgeState.relayerState === 0)
-    assert(bridgeState.publicState === 0)
+
+    assert(tokenConfig.vault.equals(vault_ata))
+    assert(tokenConfig.vaultAmount.toNumber() === 0)
+    assert(tokenConfig.mint.equals(itheum_token_mint.publicKey))
+    assert(tokenConfig.relayerState === 1)
+    assert(tokenConfig.publicState === 1)
 
     let vault = await getAccount(connection, vault_ata)
 
     assert(vault.mint.equals(itheum_token_mint.publicKey))
     assert(vault.owner.equals(bridgeStatePda))
+
+    // funded up front, so a flat fee below the rent minimum can be paid in
+    assert(
+      (await connection.getBalance(feeVaultPda)) ==
+        (await connection.getMinimumBalanceForRentExemption(0))
+    )
   })
 
-  it('Change whitelist by user (should fail)', async () => {
+  it('Migrate bridge state - already migrated (should fail)', async () => {
     try {
       await program.methods
-        .updateWhitelistedMint()
-        .signers([user])
+        .migrateBridgeState(admin.publicKey)
         .accounts({
           bridgeState: bridgeStatePda,
-          authority: user.publicKey,
-          vault: another_token_vault_ata,
+          tokenConfig: tokenConfigPda(another_token_mint.publicKey),
+          vault: vault_ata,
           mintOfTokenWhitelisted: another_token_mint.publicKey,
+          feeVault: feeVaultPda,
+          authority: provider.publicKey,
+          program: program.programId,
+          programData: programDataPda,
         })
         .rpc()
       assert(false, 'Should have thrown error')
     } catch (err) {
-      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(2012)
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6053)
       expect((err as anchor.AnchorError).error.errorMessage).to.equal(
-        'An address constraint was violated'
+        'Bridge state already migrated'
       )
     }
   })
 
-  it('Change relayer whitelist by user (should fail)', async () => {
+  it('Propose admin by user (should fail)', async () => {
     try {
       await program.methods
-        .updateRelayer(user2.publicKey)
-        .signers([user2])
+        .proposeAdmin(user.publicKey)
+        .signers([user])
         .accounts({
           bridgeState: bridgeStatePda,
-          authority: user2.publicKey,
+          authority: user.publicKey,
         })
         .rpc()
       assert(false, 'Should have thrown error')
     } catch (err) {
-      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(2012)
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6004)
       expect((err as anchor.AnchorError).error.errorMessage).to.equal(
-        'An address constraint was violated'
+        'Not privileged'
       )
     }
   })
 
-  it('Change fee collector by user (should fail)', async () => {
+  it('Rotate admin to user and back', async () => {
+    await program.methods
+      .proposeAdmin(user.publicKey)
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        authority: admin.publicKey,
+      })
+      .rpc()
+
     try {
       await program.methods
-        .updateFeeCollector(fee_collector.publicKey)
-        .signers([user])
+        .acceptAdmin()
+        .signers([user2])
         .accounts({
           bridgeState: bridgeStatePda,
-          authority: user.publicKey,
+          authority: user2.publicKey,
         })
         .rpc()
       assert(false, 'Should have thrown error')
     } catch (err) {
-      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(2012)
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6004)
       expect((err as anchor.AnchorError).error.errorMessage).to.equal(
-        'An address constraint was violated'
+        'Not privileged'
       )
     }
+
+    await program.methods
+      .acceptAdmin()
+      .signers([user])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        authority: user.publicKey,
+      })
+      .rpc()
+
+    let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
+
+    assert(bridgeState.admin.equals(user.publicKey))
+    assert(bridgeState.pendingAdmin.equals(PublicKey.default))
+
+    await program.methods
+      .proposeAdmin(admin.publicKey)
+      .signers([user])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        authority: user.publicKey,
+      })
+      .rpc()
+
+    await program.methods
+      .acceptAdmin()
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        authority: admin.publicKey,
+      })
+      .rpc()
+
+    bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
+
+    assert(bridgeState.admin.equals(admin.publicKey))
   })
 
-  it('Change fee amount by user (should fail)', async () => {
+  it('Grant role by user (should fail)', async () => {
     try {
       await program.methods
-        .setFeeAmount(new anchor.BN(1000e9))
+        .grantRole({liquidityManager: {}}, user.publicKey)
         .signers([user])
         .accounts({
           bridgeState: bridgeStatePda,
@@ -376,16 +707,16 @@ describe('bridge-program', () => {
         .rpc()
       assert(false, 'Should have thrown error')
     } catch (err) {
-      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(2012)
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6004)
       expect((err as anchor.AnchorError).error.errorMessage).to.equal(
-        'An address constraint was violated'
+        'Not privileged'
       )
     }
   })
 
-  it('Change fee collector by admin', async () => {
+  it('Whitelist manager role is separate from admin', async () => {
     await program.methods
-      .updateFeeCollector(fee_collector.publicKey)
+      .grantRole({whitelistManager: {}}, user2.publicKey)
       .signers([admin])
       .accounts({
         bridgeState: bridgeStatePda,
@@ -395,150 +726,304 @@ describe('bridge-program', () => {
 
     let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
 
-    assert(bridgeState.feeCollector.equals(fee_collector.publicKey))
-  })
+    assert(bridgeState.whitelistManager.equals(user2.publicKey))
+
+    try {
+      await program.methods
+        .setWhitelistActive()
+        .signers([admin])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          authority: admin.publicKey,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6004)
+      expect((err as anchor.AnchorError).error.errorMessage).to.equal(
+        'Not privileged'
+      )
+    }
 
-  it('Change whitelist and relayer by admin ', async () => {
     await program.methods
-      .updateWhitelistedMint()
+      .revokeRole({whitelistManager: {}})
       .signers([admin])
       .accounts({
         bridgeState: bridgeStatePda,
         authority: admin.publicKey,
-        vault: vault_ata,
-        mintOfTokenWhitelisted: itheum_token_mint.publicKey,
       })
       .rpc()
 
+    bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
+
+    assert(bridgeState.whitelistManager.equals(PublicKey.default))
+
     await program.methods
-      .updateRelayer(admin.publicKey)
+      .grantRole({whitelistManager: {}}, admin.publicKey)
       .signers([admin])
       .accounts({
         bridgeState: bridgeStatePda,
         authority: admin.publicKey,
       })
       .rpc()
-
-    let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
-
-    assert(bridgeState.relayerPubkey.equals(admin.publicKey))
-    assert(bridgeState.vault.equals(vault_ata))
-    assert(bridgeState.vaultAmount.toNumber() === 0)
-    assert(
-      bridgeState.mintOfTokenWhitelisted.equals(itheum_token_mint.publicKey)
-    )
-    assert(bridgeState.relayerState === 0)
-    assert(bridgeState.publicState === 0)
   })
 
-  it('Add liquidity by user (should fail)', async () => {
+  it('Change whitelist by user (should fail)', async () => {
     try {
       await program.methods
-        .addLiquidity(new anchor.BN(100e9))
+        .updateWhitelistedMint()
         .signers([user])
         .accounts({
           bridgeState: bridgeStatePda,
-          vault: vault_ata,
-          mintOfTokenSent: itheum_token_mint.publicKey,
+          tokenConfig: tokenConfigPda(another_token_mint.publicKey),
           authority: user.publicKey,
-          authorityTokenAccount: itheum_token_user_ata,
+          vault: another_token_vault_ata,
+          mintOfTokenWhitelisted: another_token_mint.publicKey,
+          multisig: null,
+          proposal: null,
+          queuedChange: null,
+          previousTokenConfig: null,
+          previousVault: null,
+          previousMint: null,
+          sweepTokenAccount: null,
+          previousTokenProgram: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc()
       assert(false, 'Should have thrown error')
     } catch (err) {
-      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(2012)
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6004)
       expect((err as anchor.AnchorError).error.errorMessage).to.equal(
-        'An address constraint was violated'
+        'Not privileged'
       )
     }
   })
 
-  it('Add liquidity by admin - not whitelisted mint (should fail)', async () => {
+  it('Change relayer whitelist by user (should fail)', async () => {
     try {
       await program.methods
-        .addLiquidity(new anchor.BN(1000e9))
-        .signers([admin])
+        .updateRelayer(user2.publicKey)
+        .signers([user2])
         .accounts({
           bridgeState: bridgeStatePda,
-          vault: vault_ata,
-          mintOfTokenSent: another_token_mint.publicKey,
-          authority: admin.publicKey,
-          authorityTokenAccount: another_token_admin_ata,
+          authority: user2.publicKey,
+          multisig: null,
+          proposal: null,
+          queuedChange: null,
         })
         .rpc()
       assert(false, 'Should have thrown error')
     } catch (err) {
-      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(2009)
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6004)
       expect((err as anchor.AnchorError).error.errorMessage).to.equal(
-        'An associated constraint was violated'
+        'Not privileged'
       )
     }
   })
 
-  it('Add liquidity by admin - wrong(mint) admin ATA (should fail)', async () => {
+  it('Change fee collector by user (should fail)', async () => {
     try {
       await program.methods
-        .addLiquidity(new anchor.BN(1000e9))
-        .signers([admin])
+        .updateFeeCollector(fee_collector.publicKey)
+        .signers([user])
         .accounts({
           bridgeState: bridgeStatePda,
-          vault: vault_ata,
-          mintOfTokenSent: itheum_token_mint.publicKey,
-          authority: admin.publicKey,
-          authorityTokenAccount: another_token_admin_ata,
+          authority: user.publicKey,
+          queuedChange: null,
         })
         .rpc()
       assert(false, 'Should have thrown error')
     } catch (err) {
-      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6007)
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6004)
       expect((err as anchor.AnchorError).error.errorMessage).to.equal(
-        'Mint mismatch'
+        'Not privileged'
       )
     }
   })
 
-  it('Add liquidity by admin - wrong(owner) admin ATA (should fail)', async () => {
+  it('Change fee amount by user (should fail)', async () => {
     try {
       await program.methods
-        .addLiquidity(new anchor.BN(1000e9))
-        .signers([admin])
+        .setFeeAmount(new anchor.BN(1000e9))
+        .signers([user])
         .accounts({
           bridgeState: bridgeStatePda,
-          vault: vault_ata,
-          mintOfTokenSent: itheum_token_mint.publicKey,
-          authority: admin.publicKey,
-          authorityTokenAccount: itheum_token_user_ata,
+          tokenConfig: itheumTokenConfigPda,
+          authority: user.publicKey,
+          queuedChange: null,
         })
         .rpc()
       assert(false, 'Should have thrown error')
     } catch (err) {
-      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6005)
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6004)
       expect((err as anchor.AnchorError).error.errorMessage).to.equal(
-        'Not enough balance'
+        'Not privileged'
       )
     }
   })
 
-  it('Add liquidity by admin - wrong amount (should fail)', async () => {
-    try {
-      await program.methods
-        .addLiquidity(new anchor.BN(3000e9))
-        .signers([admin])
-        .accounts({
-          bridgeState: bridgeStatePda,
-          vault: vault_ata,
-          mintOfTokenSent: itheum_token_mint.publicKey,
-          authority: admin.publicKey,
-          authorityTokenAccount: itheum_token_admin_ata,
-        })
-        .rpc()
-      assert(false, 'Should have thrown error')
-    } catch (err) {
-      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6005)
-      expect((err as anchor.AnchorError).error.errorMessage).to.equal(
-        'Not enough balance'
-      )
-    }
+  it('Change fee collector by admin', async () => {
+    await program.methods
+      .updateFeeCollector(fee_collector.publicKey)
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        authority: admin.publicKey,
+        queuedChange: null,
+      })
+      .rpc()
+
+    let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
+
+    assert(bridgeState.feeCollector.equals(fee_collector.publicKey))
+  })
+
+  it('Change relayer by admin', async () => {
+    await program.methods
+      .updateRelayer(admin.publicKey)
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        authority: admin.publicKey,
+        multisig: null,
+        proposal: null,
+        queuedChange: null,
+      })
+      .rpc()
+
+    let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
+
+    assert(bridgeState.relayerPubkey.equals(admin.publicKey))
+    assert(bridgeState.relayerState === 0)
+    assert(bridgeState.publicState === 0)
+
+    let tokenConfig = await program.account.tokenConfig.fetch(
+      itheumTokenConfigPda
+    )
+
+    assert(tokenConfig.vault.equals(vault_ata))
+    assert(tokenConfig.vaultAmount.toNumber() === 0)
+    assert(tokenConfig.mint.equals(itheum_token_mint.publicKey))
+  })
+
+  it('Add liquidity by user (should fail)', async () => {
+    try {
+      await program.methods
+        .addLiquidity(new anchor.BN(100e9))
+        .signers([user])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
+          vault: vault_ata,
+          mintOfTokenSent: itheum_token_mint.publicKey,
+          authority: user.publicKey,
+          authorityTokenAccount: itheum_token_user_ata,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6004)
+      expect((err as anchor.AnchorError).error.errorMessage).to.equal(
+        'Not privileged'
+      )
+    }
+  })
+
+  it('Add liquidity by admin - not whitelisted mint (should fail)', async () => {
+    try {
+      await program.methods
+        .addLiquidity(new anchor.BN(1000e9))
+        .signers([admin])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          tokenConfig: tokenConfigPda(another_token_mint.publicKey),
+          vault: vault_ata,
+          mintOfTokenSent: another_token_mint.publicKey,
+          authority: admin.publicKey,
+          authorityTokenAccount: another_token_admin_ata,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(3012)
+      expect((err as anchor.AnchorError).error.errorMessage).to.equal(
+        'The program expected this account to be already initialized'
+      )
+    }
+  })
+
+  it('Add liquidity by admin - wrong(mint) admin ATA (should fail)', async () => {
+    try {
+      await program.methods
+        .addLiquidity(new anchor.BN(1000e9))
+        .signers([admin])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
+          vault: vault_ata,
+          mintOfTokenSent: itheum_token_mint.publicKey,
+          authority: admin.publicKey,
+          authorityTokenAccount: another_token_admin_ata,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6007)
+      expect((err as anchor.AnchorError).error.errorMessage).to.equal(
+        'Mint mismatch'
+      )
+    }
+  })
+
+  it('Add liquidity by admin - wrong(owner) admin ATA (should fail)', async () => {
+    try {
+      await program.methods
+        .addLiquidity(new anchor.BN(1000e9))
+        .signers([admin])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
+          vault: vault_ata,
+          mintOfTokenSent: itheum_token_mint.publicKey,
+          authority: admin.publicKey,
+          authorityTokenAccount: itheum_token_user_ata,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6005)
+      expect((err as anchor.AnchorError).error.errorMessage).to.equal(
+        'Not enough balance'
+      )
+    }
+  })
+
+  it('Add liquidity by admin - wrong amount (should fail)', async () => {
+    try {
+      await program.methods
+        .addLiquidity(new anchor.BN(3000e9))
+        .signers([admin])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
+          vault: vault_ata,
+          mintOfTokenSent: itheum_token_mint.publicKey,
+          authority: admin.publicKey,
+          authorityTokenAccount: itheum_token_admin_ata,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6005)
+      expect((err as anchor.AnchorError).error.errorMessage).to.equal(
+        'Not enough balance'
+      )
+    }
   })
 
   it('Add liquidity by admin', async () => {
@@ -547,24 +1032,29 @@ describe('bridge-program', () => {
       .signers([admin])
       .accounts({
         bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
         vault: vault_ata,
         mintOfTokenSent: itheum_token_mint.publicKey,
         authority: admin.publicKey,
         authorityTokenAccount: itheum_token_admin_ata,
+        tokenProgram: TOKEN_PROGRAM_ID,
       })
       .rpc()
 
     let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
 
     assert(bridgeState.relayerPubkey.equals(admin.publicKey))
-    assert(bridgeState.vault.equals(vault_ata))
-    assert(bridgeState.vaultAmount.toNumber() === 1000e9)
-    assert(
-      bridgeState.mintOfTokenWhitelisted.equals(itheum_token_mint.publicKey)
-    )
     assert(bridgeState.relayerState === 0)
     assert(bridgeState.publicState === 0)
 
+    let tokenConfig = await program.account.tokenConfig.fetch(
+      itheumTokenConfigPda
+    )
+
+    assert(tokenConfig.vault.equals(vault_ata))
+    assert(tokenConfig.vaultAmount.toNumber() === 1000e9)
+    assert(tokenConfig.mint.equals(itheum_token_mint.publicKey))
+
     let vault = await getAccount(connection, vault_ata)
 
     assert(Number(vault.amount) == 1000e9)
@@ -577,31 +1067,38 @@ describe('bridge-program', () => {
         .signers([user])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
           vault: vault_ata,
           mintOfTokenSent: itheum_token_mint.publicKey,
           authority: user.publicKey,
           authorityTokenAccount: itheum_token_user_ata,
+          multisig: null,
+          proposal: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc()
       assert(false, 'Should have thrown error')
     } catch (err) {
-      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(2012)
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6004)
       expect((err as anchor.AnchorError).error.errorMessage).to.equal(
-        'An address constraint was violated'
+        'Not privileged'
       )
     }
 
     let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
 
     assert(bridgeState.relayerPubkey.equals(admin.publicKey))
-    assert(bridgeState.vault.equals(vault_ata))
-    assert(bridgeState.vaultAmount.toNumber() === 1000e9)
-    assert(
-      bridgeState.mintOfTokenWhitelisted.equals(itheum_token_mint.publicKey)
-    )
     assert(bridgeState.relayerState === 0)
     assert(bridgeState.publicState === 0)
 
+    let tokenConfig = await program.account.tokenConfig.fetch(
+      itheumTokenConfigPda
+    )
+
+    assert(tokenConfig.vault.equals(vault_ata))
+    assert(tokenConfig.vaultAmount.toNumber() === 1000e9)
+    assert(tokenConfig.mint.equals(itheum_token_mint.publicKey))
+
     let vault = await getAccount(connection, vault_ata)
 
     assert(Number(vault.amount) == 1000e9)
@@ -614,17 +1111,21 @@ describe('bridge-program', () => {
         .signers([admin])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: tokenConfigPda(another_token_mint.publicKey),
           vault: vault_ata,
           mintOfTokenSent: another_token_mint.publicKey,
           authority: admin.publicKey,
           authorityTokenAccount: another_token_admin_ata,
+          multisig: null,
+          proposal: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc()
       assert(false, 'Should have thrown error')
     } catch (err) {
-      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(2009)
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(3012)
       expect((err as anchor.AnchorError).error.errorMessage).to.equal(
-        'An associated constraint was violated'
+        'The program expected this account to be already initialized'
       )
     }
   })
@@ -636,10 +1137,14 @@ describe('bridge-program', () => {
         .signers([admin])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
           vault: vault_ata,
           mintOfTokenSent: itheum_token_mint.publicKey,
           authority: admin.publicKey,
           authorityTokenAccount: another_token_admin_ata,
+          multisig: null,
+          proposal: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc()
       assert(false, 'Should have thrown error')
@@ -658,10 +1163,14 @@ describe('bridge-program', () => {
         .signers([admin])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
           vault: vault_ata,
           mintOfTokenSent: itheum_token_mint.publicKey,
           authority: admin.publicKey,
           authorityTokenAccount: itheum_token_user_ata,
+          multisig: null,
+          proposal: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc()
       assert(false, 'Should have thrown error')
@@ -680,10 +1189,14 @@ describe('bridge-program', () => {
         .signers([admin])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
           vault: vault_ata,
           mintOfTokenSent: itheum_token_mint.publicKey,
           authority: admin.publicKey,
           authorityTokenAccount: itheum_token_admin_ata,
+          multisig: null,
+          proposal: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc()
       assert(false, 'Should have thrown error')
@@ -701,24 +1214,31 @@ describe('bridge-program', () => {
       .signers([admin])
       .accounts({
         bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
         vault: vault_ata,
         mintOfTokenSent: itheum_token_mint.publicKey,
         authority: admin.publicKey,
         authorityTokenAccount: itheum_token_admin_ata,
+        multisig: null,
+        proposal: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
       })
       .rpc()
 
     let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
 
     assert(bridgeState.relayerPubkey.equals(admin.publicKey))
-    assert(bridgeState.vault.equals(vault_ata))
-    assert(bridgeState.vaultAmount.toNumber() === 500e9)
-    assert(
-      bridgeState.mintOfTokenWhitelisted.equals(itheum_token_mint.publicKey)
-    )
     assert(bridgeState.relayerState === 0)
     assert(bridgeState.publicState === 0)
 
+    let tokenConfig = await program.account.tokenConfig.fetch(
+      itheumTokenConfigPda
+    )
+
+    assert(tokenConfig.vault.equals(vault_ata))
+    assert(tokenConfig.vaultAmount.toNumber() === 500e9)
+    assert(tokenConfig.mint.equals(itheum_token_mint.publicKey))
+
     let vault = await getAccount(connection, vault_ata)
 
     assert(Number(vault.amount) == 500e9)
@@ -730,24 +1250,31 @@ describe('bridge-program', () => {
       .signers([admin])
       .accounts({
         bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
         vault: vault_ata,
         mintOfTokenSent: itheum_token_mint.publicKey,
         authority: admin.publicKey,
         authorityTokenAccount: itheum_token_admin_ata,
+        multisig: null,
+        proposal: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
       })
       .rpc()
 
     let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
 
     assert(bridgeState.relayerPubkey.equals(admin.publicKey))
-    assert(bridgeState.vault.equals(vault_ata))
-    assert(bridgeState.vaultAmount.toNumber() === 0)
-    assert(
-      bridgeState.mintOfTokenWhitelisted.equals(itheum_token_mint.publicKey)
-    )
     assert(bridgeState.relayerState === 0)
     assert(bridgeState.publicState === 0)
 
+    let tokenConfig = await program.account.tokenConfig.fetch(
+      itheumTokenConfigPda
+    )
+
+    assert(tokenConfig.vault.equals(vault_ata))
+    assert(tokenConfig.vaultAmount.toNumber() === 0)
+    assert(tokenConfig.mint.equals(itheum_token_mint.publicKey))
+
     let adminAta = await getAccount(connection, itheum_token_admin_ata)
 
     assert(Number(adminAta.amount) == 1000e9)
@@ -757,6 +1284,105 @@ describe('bridge-program', () => {
     assert(Number(vault.amount) == 0)
   })
 
+  it('Add liquidity by admin - tokens sent straight to vault', async () => {
+    const tx = new Transaction().add(
+      createTransferInstruction(
+        itheum_token_admin_ata,
+        vault_ata,
+        admin.publicKey,
+        100e9
+      )
+    )
+
+    await provider.sendAndConfirm(tx, [admin])
+
+    // the surplus keeps the vault solvent, so the token is not blocked
+    await program.methods
+      .addLiquidity(new anchor.BN(100e9))
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
+        vault: vault_ata,
+        mintOfTokenSent: itheum_token_mint.publicKey,
+        authority: admin.publicKey,
+        authorityTokenAccount: itheum_token_admin_ata,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc()
+
+    let tokenConfig = await program.account.tokenConfig.fetch(
+      itheumTokenConfigPda
+    )
+
+    assert(tokenConfig.vaultAmount.toNumber() === 100e9)
+
+    let vault = await getAccount(connection, vault_ata)
+
+    assert(Number(vault.amount) == 200e9)
+  })
+
+  it('Reconcile vault by user (should fail)', async () => {
+    try {
+      await program.methods
+        .reconcile()
+        .signers([user])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
+          vault: vault_ata,
+          authority: user.publicKey,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6004)
+    }
+  })
+
+  it('Reconcile vault by liquidity manager', async () => {
+    await program.methods
+      .reconcile()
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
+        vault: vault_ata,
+        authority: admin.publicKey,
+      })
+      .rpc()
+
+    let tokenConfig = await program.account.tokenConfig.fetch(
+      itheumTokenConfigPda
+    )
+
+    assert(tokenConfig.vaultAmount.toNumber() === 200e9)
+
+    await program.methods
+      .removeLiquidity(new anchor.BN(200e9))
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
+        vault: vault_ata,
+        mintOfTokenSent: itheum_token_mint.publicKey,
+        authority: admin.publicKey,
+        authorityTokenAccount: itheum_token_admin_ata,
+        multisig: null,
+        proposal: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc()
+
+    tokenConfig = await program.account.tokenConfig.fetch(itheumTokenConfigPda)
+
+    assert(tokenConfig.vaultAmount.toNumber() === 0)
+
+    let vault = await getAccount(connection, vault_ata)
+
+    assert(Number(vault.amount) == 0)
+  })
+
   it('Pause contract by user (should fail)', async () => {
     try {
       await program.methods
@@ -764,6 +1390,7 @@ describe('bridge-program', () => {
         .signers([user])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: null,
           authority: user.publicKey,
         })
         .rpc()
@@ -777,20 +1404,31 @@ describe('bridge-program', () => {
   })
 
   it('Pause contract by admin', async () => {
-    await program.methods
+    let signature = await program.methods
       .publicPause()
       .signers([admin])
       .accounts({
         bridgeState: bridgeStatePda,
+        tokenConfig: null,
         authority: admin.publicKey,
       })
-      .rpc()
+      .rpc({commitment: 'confirmed'})
+
+    let [pauseChanged] = await events(signature)
+
+    expect(pauseChanged.name).to.equal('pauseChanged')
+    expect(pauseChanged.data.authority.toBase58()).to.equal(
+      admin.publicKey.toBase58()
+    )
+    expect(pauseChanged.data.mint).to.equal(null)
+    expect(pauseChanged.data.publicState).to.equal(0)
 
     await program.methods
       .relayerPause()
       .signers([admin])
       .accounts({
         bridgeState: bridgeStatePda,
+        tokenConfig: null,
         authority: admin.publicKey,
       })
       .rpc()
@@ -808,6 +1446,7 @@ describe('bridge-program', () => {
         .signers([user])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: null,
           authority: user.publicKey,
         })
         .rpc()
@@ -826,6 +1465,7 @@ describe('bridge-program', () => {
       .signers([admin])
       .accounts({
         bridgeState: bridgeStatePda,
+        tokenConfig: null,
         authority: admin.publicKey,
       })
       .rpc()
@@ -841,6 +1481,7 @@ describe('bridge-program', () => {
       .signers([admin])
       .accounts({
         bridgeState: bridgeStatePda,
+        tokenConfig: null,
         authority: admin.publicKey,
       })
       .rpc()
@@ -856,23 +1497,32 @@ describe('bridge-program', () => {
       .signers([admin])
       .accounts({
         bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
         vault: vault_ata,
         mintOfTokenSent: itheum_token_mint.publicKey,
         authority: admin.publicKey,
         authorityTokenAccount: itheum_token_admin_ata,
+        tokenProgram: TOKEN_PROGRAM_ID,
       })
       .rpc()
 
     try {
       await program.methods
-        .sendFromLiquidity(new anchor.BN(1000e9), user.publicKey)
+        .sendFromLiquidity(new anchor.BN(1000e9), user.publicKey, txHash(1))
         .signers([user])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
           vault: vault_ata,
           mintOfTokenSent: itheum_token_mint.publicKey,
           authority: user.publicKey,
           receiverTokenAccount: itheum_token_user_ata,
+          transferReceipt: transferReceiptPda(txHash(1)),
+          relayerCommittee: null,
+          releaseApproval: null,
+          payer: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc()
       assert(false, 'Should have thrown error')
@@ -887,14 +1537,21 @@ describe('bridge-program', () => {
   it('Send from liquidity by relayer - wrong amount (should fail)', async () => {
     try {
       await program.methods
-        .sendFromLiquidity(new anchor.BN(3000e9), user.publicKey)
+        .sendFromLiquidity(new anchor.BN(3000e9), user.publicKey, txHash(2))
         .signers([admin])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
           vault: vault_ata,
           mintOfTokenSent: itheum_token_mint.publicKey,
           authority: admin.publicKey,
           receiverTokenAccount: itheum_token_user_ata,
+          transferReceipt: transferReceiptPda(txHash(2)),
+          relayerCommittee: null,
+          releaseApproval: null,
+          payer: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc()
       assert(false, 'Should have thrown error')
@@ -909,21 +1566,28 @@ describe('bridge-program', () => {
   it('Send from liquidity by relayer - wrong whitelisted mint (should fail)', async () => {
     try {
       await program.methods
-        .sendFromLiquidity(new anchor.BN(1000e9), user.publicKey)
+        .sendFromLiquidity(new anchor.BN(1000e9), user.publicKey, txHash(3))
         .signers([admin])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: tokenConfigPda(another_token_mint.publicKey),
           vault: vault_ata,
           mintOfTokenSent: another_token_mint.publicKey,
           authority: admin.publicKey,
           receiverTokenAccount: itheum_token_user_ata,
+          transferReceipt: transferReceiptPda(txHash(3)),
+          relayerCommittee: null,
+          releaseApproval: null,
+          payer: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc()
       assert(false, 'Should have thrown error')
     } catch (err) {
-      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(2009)
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(3012)
       expect((err as anchor.AnchorError).error.errorMessage).to.equal(
-        'An associated constraint was violated'
+        'The program expected this account to be already initialized'
       )
     }
   })
@@ -931,14 +1595,21 @@ describe('bridge-program', () => {
   it('Send from liquidity by relayer - wrong(mint) user ATA (should fail)', async () => {
     try {
       await program.methods
-        .sendFromLiquidity(new anchor.BN(1000e9), user.publicKey)
+        .sendFromLiquidity(new anchor.BN(1000e9), user.publicKey, txHash(4))
         .signers([admin])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
           vault: vault_ata,
           mintOfTokenSent: itheum_token_mint.publicKey,
           authority: admin.publicKey,
           receiverTokenAccount: another_token_user_ata,
+          transferReceipt: transferReceiptPda(txHash(4)),
+          relayerCommittee: null,
+          releaseApproval: null,
+          payer: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc()
       assert(false, 'Should have thrown error')
@@ -953,14 +1624,21 @@ describe('bridge-program', () => {
   it('Send from liquidity by relayer - wrong(owner) user ATA (should fail)', async () => {
     try {
       await program.methods
-        .sendFromLiquidity(new anchor.BN(1000e9), user.publicKey)
+        .sendFromLiquidity(new anchor.BN(1000e9), user.publicKey, txHash(5))
         .signers([admin])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
           vault: vault_ata,
           mintOfTokenSent: itheum_token_mint.publicKey,
           authority: admin.publicKey,
           receiverTokenAccount: itheum_token_admin_ata,
+          transferReceipt: transferReceiptPda(txHash(5)),
+          relayerCommittee: null,
+          releaseApproval: null,
+          payer: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc()
       assert(false, 'Should have thrown error')
@@ -979,6 +1657,9 @@ describe('bridge-program', () => {
       .accounts({
         bridgeState: bridgeStatePda,
         authority: admin.publicKey,
+        multisig: null,
+        proposal: null,
+        queuedChange: null,
       })
       .rpc()
 
@@ -994,6 +1675,7 @@ describe('bridge-program', () => {
         .signers([user2])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: null,
           authority: user2.publicKey,
         })
         .rpc()
@@ -1013,6 +1695,7 @@ describe('bridge-program', () => {
         .signers([user2])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: null,
           authority: user2.publicKey,
         })
         .rpc()
@@ -1032,6 +1715,9 @@ describe('bridge-program', () => {
       .accounts({
         bridgeState: bridgeStatePda,
         authority: admin.publicKey,
+        multisig: null,
+        proposal: null,
+        queuedChange: null,
       })
       .rpc()
 
@@ -1046,20 +1732,28 @@ describe('bridge-program', () => {
       .signers([admin])
       .accounts({
         bridgeState: bridgeStatePda,
+        tokenConfig: null,
         authority: admin.publicKey,
       })
       .rpc()
 
     try {
       await program.methods
-        .sendFromLiquidity(new anchor.BN(100e9), user.publicKey)
+        .sendFromLiquidity(new anchor.BN(100e9), user.publicKey, txHash(6))
         .signers([admin])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
           vault: vault_ata,
           mintOfTokenSent: itheum_token_mint.publicKey,
           authority: admin.publicKey,
           receiverTokenAccount: itheum_token_user_ata,
+          transferReceipt: transferReceiptPda(txHash(6)),
+          relayerCommittee: null,
+          releaseApproval: null,
+          payer: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc()
     } catch (err) {
@@ -1076,19 +1770,27 @@ describe('bridge-program', () => {
       .signers([admin])
       .accounts({
         bridgeState: bridgeStatePda,
+        tokenConfig: null,
         authority: admin.publicKey,
       })
       .rpc()
 
     await program.methods
-      .sendFromLiquidity(new anchor.BN(100e9), user.publicKey)
+      .sendFromLiquidity(new anchor.BN(100e9), user.publicKey, txHash(7))
       .signers([admin])
       .accounts({
         bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
         vault: vault_ata,
         mintOfTokenSent: itheum_token_mint.publicKey,
         authority: admin.publicKey,
         receiverTokenAccount: itheum_token_user_ata,
+        transferReceipt: transferReceiptPda(txHash(7)),
+        relayerCommittee: null,
+        releaseApproval: null,
+        payer: null,
+        instructionsSysvar: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
       })
       .rpc()
 
@@ -1100,36 +1802,231 @@ describe('bridge-program', () => {
 
     assert(Number(userAta.amount) == 200e9) // 100e9 was already in user's account
 
-    let bridge = await program.account.bridgeState.fetch(bridgeStatePda)
+    let tokenConfig = await program.account.tokenConfig.fetch(
+      itheumTokenConfigPda
+    )
 
-    assert(bridge.vaultAmount.toNumber() == 900e9)
-  })
+    assert(tokenConfig.vaultAmount.toNumber() == 900e9)
 
-  it('Send to liquidity by user - paused public (should fail)', async () => {
-    await program.methods
-      .publicPause()
-      .signers([admin])
-      .accounts({
-        bridgeState: bridgeStatePda,
-        authority: admin.publicKey,
-      })
-      .rpc()
+    let receipt = await program.account.transferReceipt.fetch(
+      transferReceiptPda(txHash(7))
+    )
+
+    assert(receipt.receiver.equals(user.publicKey))
+    assert(receipt.amount.toNumber() == 100e9)
+  })
 
+  it('Send from liquidity by relayer - same tx hash twice (should fail)', async () => {
     try {
       await program.methods
-        .sendToLiquidity(new anchor.BN(100e9), 'erd...', 'signature')
+        .sendFromLiquidity(new anchor.BN(100e9), user.publicKey, txHash(7))
+        .signers([admin])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
+          vault: vault_ata,
+          mintOfTokenSent: itheum_token_mint.publicKey,
+          authority: admin.publicKey,
+          receiverTokenAccount: itheum_token_user_ata,
+          transferReceipt: transferReceiptPda(txHash(7)),
+          relayerCommittee: null,
+          releaseApproval: null,
+          payer: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6010)
+      expect((err as anchor.AnchorError).error.errorMessage).to.equal(
+        'Transfer already processed'
+      )
+    }
+  })
+
+  it('Set outflow limit by user (should fail)', async () => {
+    try {
+      await program.methods
+        .setOutflowLimit(new anchor.BN(3600), new anchor.BN(50e9))
+        .signers([user])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
+          authority: user.publicKey,
+          queuedChange: null,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6004)
+    }
+  })
+
+  it('Send from liquidity by relayer - outflow cap exceeded (should fail)', async () => {
+    await program.methods
+      .setOutflowLimit(new anchor.BN(3600), new anchor.BN(50e9))
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
+        authority: admin.publicKey,
+        queuedChange: null,
+      })
+      .rpc()
+
+    let tokenConfig = await program.account.tokenConfig.fetch(
+      itheumTokenConfigPda
+    )
+
+    assert(tokenConfig.outflowWindow.toNumber() == 3600)
+    assert(tokenConfig.outflowCap.toNumber() == 50e9)
+
+    try {
+      await program.methods
+        .sendFromLiquidity(new anchor.BN(100e9), user.publicKey, txHash(9))
+        .signers([admin])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
+          vault: vault_ata,
+          mintOfTokenSent: itheum_token_mint.publicKey,
+          authority: admin.publicKey,
+          receiverTokenAccount: itheum_token_user_ata,
+          transferReceipt: transferReceiptPda(txHash(9)),
+          relayerCommittee: null,
+          releaseApproval: null,
+          payer: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6030)
+      expect((err as anchor.AnchorError).error.errorMessage).to.equal(
+        'Outflow cap exceeded for the current window'
+      )
+    }
+
+    await program.methods
+      .setOutflowLimit(new anchor.BN(0), new anchor.BN(0))
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
+        authority: admin.publicKey,
+        queuedChange: null,
+      })
+      .rpc()
+  })
+
+  it('Send from liquidity by relayer - trips circuit breaker', async () => {
+    await program.methods
+      .setCircuitBreaker(5000, new anchor.BN(0), new anchor.BN(0))
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
+        authority: admin.publicKey,
+        queuedChange: null,
+      })
+      .rpc()
+
+    // more than half of the 900e9 in the vault
+    await program.methods
+      .sendFromLiquidity(new anchor.BN(500e9), user.publicKey, txHash(10))
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
+        vault: vault_ata,
+        mintOfTokenSent: itheum_token_mint.publicKey,
+        authority: admin.publicKey,
+        receiverTokenAccount: itheum_token_user_ata,
+        transferReceipt: transferReceiptPda(txHash(10)),
+        relayerCommittee: null,
+        releaseApproval: null,
+        payer: null,
+        instructionsSysvar: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc()
+
+    // only the relayer side of the token is paused
+    let tokenConfig = await program.account.tokenConfig.fetch(
+      itheumTokenConfigPda
+    )
+    let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
+
+    assert(tokenConfig.relayerState === 0)
+    assert(bridgeState.relayerState === 1)
+
+    let vault = await getAccount(connection, vault_ata)
+
+    assert(Number(vault.amount) == 900e9)
+
+    let receipt = await program.account.transferReceipt.fetch(
+      transferReceiptPda(txHash(10))
+    )
+
+    assert(receipt.slot.toNumber() === 0)
+
+    await program.methods
+      .setCircuitBreaker(0, new anchor.BN(0), new anchor.BN(0))
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
+        authority: admin.publicKey,
+        queuedChange: null,
+      })
+      .rpc()
+
+    await program.methods
+      .relayerUnpause()
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
+        authority: admin.publicKey,
+      })
+      .rpc()
+  })
+
+  it('Send to liquidity by user - paused public (should fail)', async () => {
+    await program.methods
+      .publicPause()
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: null,
+        authority: admin.publicKey,
+      })
+      .rpc()
+
+    try {
+      await program.methods
+        .sendToLiquidity(new anchor.BN(100e9), destinationAddress)
         .signers([user])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
           vault: vault_ata,
           mintOfTokenSent: itheum_token_mint.publicKey,
           authority: user.publicKey,
           whitelist: null,
+          feeOverride: null,
           authorityTokenAccount: itheum_token_user_ata,
-          feeCollector: null,
+          depositRecord: await depositRecordPda(),
+          userDeposit: null,
+          feeVault: null,
           mintOfFeeTokenSent: null,
           authorityFeeTokenAccount: null,
-          feeCollectorAta: null,
+          feeVaultAta: null,
+          feeVaultTokenAta: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc()
     } catch (err) {
@@ -1146,25 +2043,33 @@ describe('bridge-program', () => {
       .signers([admin])
       .accounts({
         bridgeState: bridgeStatePda,
+        tokenConfig: null,
         authority: admin.publicKey,
       })
       .rpc()
 
     try {
       await program.methods
-        .sendToLiquidity(new anchor.BN(1000e9), 'erd...', 'signature')
+        .sendToLiquidity(new anchor.BN(1000e9), destinationAddress)
         .signers([user])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
           vault: vault_ata,
           whitelist: null,
+          feeOverride: null,
           mintOfTokenSent: itheum_token_mint.publicKey,
           authority: user.publicKey,
           authorityTokenAccount: itheum_token_user_ata,
-          feeCollector: null,
+          depositRecord: await depositRecordPda(),
+          userDeposit: null,
+          feeVault: null,
           mintOfFeeTokenSent: null,
           authorityFeeTokenAccount: null,
-          feeCollectorAta: null,
+          feeVaultAta: null,
+          feeVaultTokenAta: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc()
     } catch (err) {
@@ -1178,25 +2083,32 @@ describe('bridge-program', () => {
   it('Send to liquidity by user - wrong whitelisted mint (should fail)', async () => {
     try {
       await program.methods
-        .sendToLiquidity(new anchor.BN(100e9), 'erd...', 'signature')
+        .sendToLiquidity(new anchor.BN(100e9), destinationAddress)
         .signers([user])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: tokenConfigPda(another_token_mint.publicKey),
           vault: vault_ata,
           whitelist: null,
+          feeOverride: null,
           mintOfTokenSent: another_token_mint.publicKey,
           authority: user.publicKey,
           authorityTokenAccount: itheum_token_user_ata,
-          feeCollector: null,
+          depositRecord: await depositRecordPda(),
+          userDeposit: null,
+          feeVault: null,
           mintOfFeeTokenSent: null,
           authorityFeeTokenAccount: null,
-          feeCollectorAta: null,
+          feeVaultAta: null,
+          feeVaultTokenAta: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc()
     } catch (err) {
-      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(2009)
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(3012)
       expect((err as anchor.AnchorError).error.errorMessage).to.equal(
-        'An associated constraint was violated'
+        'The program expected this account to be already initialized'
       )
     }
   })
@@ -1204,19 +2116,26 @@ describe('bridge-program', () => {
   it('Send to liquidity by user - wrong(mint) user ATA (should fail)', async () => {
     try {
       await program.methods
-        .sendToLiquidity(new anchor.BN(100e9), 'erd...', 'signature')
+        .sendToLiquidity(new anchor.BN(100e9), destinationAddress)
         .signers([user])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
           vault: vault_ata,
           whitelist: null,
+          feeOverride: null,
           mintOfTokenSent: itheum_token_mint.publicKey,
           authority: user.publicKey,
           authorityTokenAccount: another_token_user_ata,
-          feeCollector: null,
+          depositRecord: await depositRecordPda(),
+          userDeposit: null,
+          feeVault: null,
           mintOfFeeTokenSent: null,
           authorityFeeTokenAccount: null,
-          feeCollectorAta: null,
+          feeVaultAta: null,
+          feeVaultTokenAta: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc()
     } catch (err) {
@@ -1230,19 +2149,26 @@ describe('bridge-program', () => {
   it('Send to liquidity by user - wrong(owner) user ATA (should fail)', async () => {
     try {
       await program.methods
-        .sendToLiquidity(new anchor.BN(100e9), 'erd...', 'signature')
+        .sendToLiquidity(new anchor.BN(100e9), destinationAddress)
         .signers([user])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
           vault: vault_ata,
           whitelist: null,
+          feeOverride: null,
           mintOfTokenSent: itheum_token_mint.publicKey,
           authority: user.publicKey,
           authorityTokenAccount: itheum_token_admin_ata,
-          feeCollector: null,
+          depositRecord: await depositRecordPda(),
+          userDeposit: null,
+          feeVault: null,
           mintOfFeeTokenSent: null,
           authorityFeeTokenAccount: null,
-          feeCollectorAta: null,
+          feeVaultAta: null,
+          feeVaultTokenAta: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc()
     } catch (err) {
@@ -1273,19 +2199,26 @@ describe('bridge-program', () => {
   it('Send to liquidity by user - not whitelisted (should fail)', async () => {
     try {
       await program.methods
-        .sendToLiquidity(new anchor.BN(200e9), 'erd...', 'signature')
+        .sendToLiquidity(new anchor.BN(200e9), destinationAddress)
         .signers([user])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
           vault: vault_ata,
           whitelist: null,
+          feeOverride: null,
           mintOfTokenSent: itheum_token_mint.publicKey,
           authority: user.publicKey,
           authorityTokenAccount: itheum_token_user_ata,
-          feeCollector: null,
+          depositRecord: await depositRecordPda(),
+          userDeposit: null,
+          feeVault: null,
           mintOfFeeTokenSent: null,
           authorityFeeTokenAccount: null,
-          feeCollectorAta: null,
+          feeVaultAta: null,
+          feeVaultTokenAta: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc()
     } catch (err) {
@@ -1313,23 +2246,78 @@ describe('bridge-program', () => {
     assert(bridgeStateFetch.whitelistState == 0)
   })
 
+  it('Send to liquidity by user - invalid destination address (should fail)', async () => {
+    try {
+      await program.methods
+        .sendToLiquidity(
+          new anchor.BN(200e9),
+          'erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6tq'
+        )
+        .signers([user])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
+          vault: vault_ata,
+          whitelist: null,
+          feeOverride: null,
+          mintOfTokenSent: itheum_token_mint.publicKey,
+          authority: user.publicKey,
+          authorityTokenAccount: itheum_token_user_ata,
+          depositRecord: await depositRecordPda(),
+          userDeposit: null,
+          feeVault: null,
+          mintOfFeeTokenSent: null,
+          authorityFeeTokenAccount: null,
+          feeVaultAta: null,
+          feeVaultTokenAta: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6043)
+      expect((err as anchor.AnchorError).error.errorMessage).to.equal(
+        'Invalid MultiversX destination address'
+      )
+    }
+  })
+
   it('Send to liquidity by user', async () => {
-    await program.methods
-      .sendToLiquidity(new anchor.BN(200e9), 'erd...', 'signature')
+    let signature = await program.methods
+      .sendToLiquidity(new anchor.BN(200e9), destinationAddress)
       .signers([user])
       .accounts({
         bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
         vault: vault_ata,
         whitelist: null,
+        feeOverride: null,
         mintOfTokenSent: itheum_token_mint.publicKey,
         authority: user.publicKey,
         authorityTokenAccount: itheum_token_user_ata,
-        feeCollector: null,
+        depositRecord: await depositRecordPda(),
+        userDeposit: null,
+        feeVault: null,
         mintOfFeeTokenSent: null,
         authorityFeeTokenAccount: null,
-        feeCollectorAta: null,
+        feeVaultAta: null,
+        feeVaultTokenAta: null,
+        instructionsSysvar: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
       })
-      .rpc()
+      .rpc({commitment: 'confirmed'})
+
+    let [depositEvent] = await events(signature)
+
+    expect(depositEvent.name).to.equal('depositEvent')
+    expect(depositEvent.data.depositId.toNumber()).to.equal(0)
+    expect(depositEvent.data.depositor.toBase58()).to.equal(
+      user.publicKey.toBase58()
+    )
+    expect(depositEvent.data.amount.toNumber()).to.equal(200e9)
+    expect(depositEvent.data.destinationAddress).to.equal(destinationAddress)
+    expect(depositEvent.data.vaultAmount.toNumber()).to.equal(1100e9)
 
     let vault = await getAccount(connection, vault_ata)
 
@@ -1339,9 +2327,33 @@ describe('bridge-program', () => {
 
     assert(Number(userAta.amount) == 0e9)
 
-    let bridge = await program.account.bridgeState.fetch(bridgeStatePda)
+    let tokenConfig = await program.account.tokenConfig.fetch(
+      itheumTokenConfigPda
+    )
+
+    let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
+
+    assert(tokenConfig.vaultAmount.toNumber() == 1100e9)
+    assert(bridgeState.depositNonce.toNumber() == 1)
 
-    assert(bridge.vaultAmount.toNumber() == 1100e9)
+    let depositRecord = await program.account.depositRecord.fetch(
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('deposit_record'),
+          new anchor.BN(0).toArrayLike(Buffer, 'le', 8),
+        ],
+        program.programId
+      )[0]
+    )
+
+    assert(depositRecord.depositId.toNumber() == 0)
+    assert(depositRecord.depositor.equals(user.publicKey))
+    assert(depositRecord.mint.equals(itheum_token_mint.publicKey))
+    assert(depositRecord.amount.toNumber() == 200e9)
+    assert(depositRecord.fee.toNumber() == 0)
+    assert(
+      Buffer.from(depositRecord.destinationAddress).equals(destinationKey)
+    )
   })
 
   it('Set whitelist active', async () => {
@@ -1373,9 +2385,9 @@ describe('bridge-program', () => {
         })
         .rpc()
     } catch (err) {
-      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(2012)
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6004)
       expect((err as anchor.AnchorError).error.errorMessage).to.equal(
-        'An address constraint was violated'
+        'Not privileged'
       )
     }
   })
@@ -1383,19 +2395,26 @@ describe('bridge-program', () => {
   it('Send to liquidity by user2 - whitelist provided but not whitelisted (should fail)', async () => {
     try {
       await program.methods
-        .sendToLiquidity(new anchor.BN(50e9), 'erd...', 'signature')
+        .sendToLiquidity(new anchor.BN(50e9), destinationAddress)
         .signers([user2])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
           vault: vault_ata,
           whitelist: user2WhitelistPda,
+          feeOverride: null,
           mintOfTokenSent: itheum_token_mint.publicKey,
           authority: user2.publicKey,
           authorityTokenAccount: itheum_token_user2_ata,
-          feeCollector: null,
+          depositRecord: await depositRecordPda(),
+          userDeposit: null,
+          feeVault: null,
           mintOfFeeTokenSent: null,
           authorityFeeTokenAccount: null,
-          feeCollectorAta: null,
+          feeVaultAta: null,
+          feeVaultTokenAta: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc()
     } catch (error) {
@@ -1431,19 +2450,26 @@ describe('bridge-program', () => {
   it('Send to liquidity by user2 - whitelist account not provided (should fail)', async () => {
     try {
       await program.methods
-        .sendToLiquidity(new anchor.BN(50e9), 'erd...', 'signature')
+        .sendToLiquidity(new anchor.BN(50e9), destinationAddress)
         .signers([user2])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
           vault: vault_ata,
           whitelist: null,
+          feeOverride: null,
           mintOfTokenSent: itheum_token_mint.publicKey,
           authority: user2.publicKey,
           authorityTokenAccount: itheum_token_user2_ata,
-          feeCollector: null,
+          depositRecord: await depositRecordPda(),
+          userDeposit: null,
+          feeVault: null,
           mintOfFeeTokenSent: null,
           authorityFeeTokenAccount: null,
-          feeCollectorAta: null,
+          feeVaultAta: null,
+          feeVaultTokenAta: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc()
     } catch (err) {
@@ -1456,19 +2482,26 @@ describe('bridge-program', () => {
 
   it('Send to liquidity by user2', async () => {
     await program.methods
-      .sendToLiquidity(new anchor.BN(50e9), 'erd...', 'signature')
+      .sendToLiquidity(new anchor.BN(50e9), destinationAddress)
       .signers([user2])
       .accounts({
         bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
         vault: vault_ata,
         whitelist: user2WhitelistPda,
+        feeOverride: null,
         mintOfTokenSent: itheum_token_mint.publicKey,
         authority: user2.publicKey,
         authorityTokenAccount: itheum_token_user2_ata,
-        feeCollector: null,
+        depositRecord: await depositRecordPda(),
+        userDeposit: null,
+        feeVault: null,
         mintOfFeeTokenSent: null,
         authorityFeeTokenAccount: null,
-        feeCollectorAta: null,
+        feeVaultAta: null,
+        feeVaultTokenAta: null,
+        instructionsSysvar: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
       })
       .rpc()
 
@@ -1491,9 +2524,9 @@ describe('bridge-program', () => {
         })
         .rpc()
     } catch (err) {
-      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(2012)
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6004)
       expect((err as anchor.AnchorError).error.errorMessage).to.equal(
-        'An address constraint was violated'
+        'Not privileged'
       )
     }
   })
@@ -1517,13 +2550,15 @@ describe('bridge-program', () => {
         .signers([user])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
           authority: user.publicKey,
+          queuedChange: null,
         })
         .rpc()
     } catch (err) {
-      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(2012)
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6004)
       expect((err as anchor.AnchorError).error.errorMessage).to.equal(
-        'An address constraint was violated'
+        'Not privileged'
       )
     }
   })
@@ -1534,7 +2569,9 @@ describe('bridge-program', () => {
       .signers([admin])
       .accounts({
         bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
         authority: admin.publicKey,
+        queuedChange: null,
       })
       .rpc()
 
@@ -1558,19 +2595,26 @@ describe('bridge-program', () => {
 
     try {
       await program.methods
-        .sendToLiquidity(new anchor.BN(50e9), 'erd...', 'signature')
+        .sendToLiquidity(new anchor.BN(50e9), destinationAddress)
         .signers([user2])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
           vault: vault_ata,
           whitelist: null,
+          feeOverride: null,
           mintOfTokenSent: itheum_token_mint.publicKey,
           authority: user2.publicKey,
           authorityTokenAccount: itheum_token_user2_ata,
-          feeCollector: null,
+          depositRecord: await depositRecordPda(),
+          userDeposit: null,
+          feeVault: null,
           mintOfFeeTokenSent: null,
           authorityFeeTokenAccount: null,
-          feeCollectorAta: null,
+          feeVaultAta: null,
+          feeVaultTokenAta: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc()
     } catch (err) {
@@ -1583,32 +2627,46 @@ describe('bridge-program', () => {
 
   it('Send to liquidity by user2 - payment amount not in range (should fail)', async () => {
     await program.methods
-      .sendFromLiquidity(new anchor.BN(300e9), user2.publicKey)
+      .sendFromLiquidity(new anchor.BN(300e9), user2.publicKey, txHash(8))
       .accounts({
         bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
         vault: vault_ata,
         mintOfTokenSent: itheum_token_mint.publicKey,
         authority: admin.publicKey,
         receiverTokenAccount: itheum_token_user2_ata,
+        transferReceipt: transferReceiptPda(txHash(8)),
+        relayerCommittee: null,
+        releaseApproval: null,
+        payer: null,
+        instructionsSysvar: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
       })
       .signers([admin])
       .rpc()
 
     try {
       await program.methods
-        .sendToLiquidity(new anchor.BN(300e9), 'erd...', 'signature')
+        .sendToLiquidity(new anchor.BN(300e9), destinationAddress)
         .signers([user2])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
           vault: vault_ata,
           whitelist: null,
+          feeOverride: null,
           mintOfTokenSent: itheum_token_mint.publicKey,
           authority: user2.publicKey,
           authorityTokenAccount: itheum_token_user2_ata,
-          feeCollector: null,
+          depositRecord: await depositRecordPda(),
+          userDeposit: null,
+          feeVault: null,
           mintOfFeeTokenSent: null,
           authorityFeeTokenAccount: null,
-          feeCollectorAta: null,
+          feeVaultAta: null,
+          feeVaultTokenAta: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc()
     } catch (err) {
@@ -1619,143 +2677,408 @@ describe('bridge-program', () => {
     }
   })
 
-  it('Send to liquidity by user2 - payment amount in range', async () => {
-    await program.methods
-      .sendToLiquidity(new anchor.BN(200e9), 'erd...', 'signature')
-      .signers([user2])
-      .accounts({
-        bridgeState: bridgeStatePda,
-        vault: vault_ata,
-        whitelist: null,
-        mintOfTokenSent: itheum_token_mint.publicKey,
-        authority: user2.publicKey,
-        authorityTokenAccount: itheum_token_user2_ata,
-        feeCollector: null,
-        mintOfFeeTokenSent: null,
-        authorityFeeTokenAccount: null,
-        feeCollectorAta: null,
-      })
-      .rpc()
-  })
-
-  it('Set whitelist inactive', async () => {
-    await program.methods
-      .setWhitelistInactive()
-      .signers([admin])
-      .accounts({
-        bridgeState: bridgeStatePda,
-        authority: admin.publicKey,
-      })
-      .rpc()
-
-    let bridgeStateFetch = await program.account.bridgeState.fetch(
-      bridgeStatePda
-    )
-
-    assert(bridgeStateFetch.whitelistState == 0)
-  })
-
-  it('Set fee amount by admin', async () => {
-    await program.methods
-      .setFeeAmount(new anchor.BN(0.1e9))
-      .signers([admin])
-      .accounts({
-        bridgeState: bridgeStatePda,
-        authority: admin.publicKey,
-      })
-      .rpc()
-
-    let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
-
-    assert(bridgeState.feeAmount.toNumber() === 0.1e9)
-  })
-
-  it('Set deposit limits by admin', async () => {
+  it('Set user deposit limits by admin', async () => {
     await program.methods
-      .setDepositLimits(new anchor.BN(10e9), new anchor.BN(1000e9))
+      .setUserDepositLimits(
+        new anchor.BN(3600),
+        new anchor.BN(250e9),
+        new anchor.BN(3600)
+      )
       .signers([admin])
       .accounts({
         bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
         authority: admin.publicKey,
+        queuedChange: null,
       })
       .rpc()
 
-    let bridgeStateFetch = await program.account.bridgeState.fetch(
-      bridgeStatePda
+    let tokenConfig = await program.account.tokenConfig.fetch(
+      itheumTokenConfigPda
     )
 
-    assert(bridgeStateFetch.minimumDeposit.toNumber() == 10e9)
-    assert(bridgeStateFetch.maximumDeposit.toNumber() == 1000e9)
+    assert(tokenConfig.userDepositCap.toNumber() == 250e9)
   })
 
-  it('Send to liquidity by user2 - required fee - missing feeCollector (should fail)', async () => {
+  it('Send to liquidity by user2 - user deposit account not provided (should fail)', async () => {
     try {
       await program.methods
-        .sendToLiquidity(new anchor.BN(100e9), 'erd...', 'signature')
+        .sendToLiquidity(new anchor.BN(200e9), destinationAddress)
         .signers([user2])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
           vault: vault_ata,
           whitelist: null,
+          feeOverride: null,
           mintOfTokenSent: itheum_token_mint.publicKey,
           authority: user2.publicKey,
           authorityTokenAccount: itheum_token_user2_ata,
-          feeCollector: null,
-          mintOfFeeTokenSent: NATIVE_MINT,
-          authorityFeeTokenAccount: user2_wsol_ata,
-          feeCollectorAta: fee_collector_ata,
+          depositRecord: await depositRecordPda(),
+          userDeposit: null,
+          feeVault: null,
+          mintOfFeeTokenSent: null,
+          authorityFeeTokenAccount: null,
+          feeVaultAta: null,
+          feeVaultTokenAta: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc()
+      assert(false, 'Should have thrown error')
     } catch (err) {
-      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(2020)
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6033)
       expect((err as anchor.AnchorError).error.errorMessage).to.equal(
-        'A required account for the constraint is None'
+        'User deposit account not provided'
       )
     }
   })
 
-  it('Send to liquidity by user2 - required fee - missing mintOfFeeTokenSent (should fail)', async () => {
+  it('Send to liquidity by user2 - user deposit cap exceeded (should fail)', async () => {
     try {
       await program.methods
-        .sendToLiquidity(new anchor.BN(100e9), 'erd...', 'signature')
+        .sendToLiquidity(new anchor.BN(300e9), destinationAddress)
         .signers([user2])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
           vault: vault_ata,
           whitelist: null,
+          feeOverride: null,
           mintOfTokenSent: itheum_token_mint.publicKey,
           authority: user2.publicKey,
           authorityTokenAccount: itheum_token_user2_ata,
-          feeCollector: fee_collector.publicKey,
+          depositRecord: await depositRecordPda(),
+          userDeposit: userDepositPda(user2.publicKey),
+          feeVault: null,
           mintOfFeeTokenSent: null,
-          authorityFeeTokenAccount: user2_wsol_ata,
-          feeCollectorAta: fee_collector_ata,
+          authorityFeeTokenAccount: null,
+          feeVaultAta: null,
+          feeVaultTokenAta: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc()
+      assert(false, 'Should have thrown error')
     } catch (err) {
-      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(2020)
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6034)
       expect((err as anchor.AnchorError).error.errorMessage).to.equal(
-        'A required account for the constraint is None'
+        'User deposit cap exceeded for the current window'
       )
     }
   })
 
-  it('Send to liquidity by user2 - required fee - missing authorityFeeTokenAccount (should fail)', async () => {
-    try {
+  it('Send to liquidity by user2 - payment amount in range', async () => {
+    await program.methods
+      .sendToLiquidity(new anchor.BN(200e9), destinationAddress)
+      .signers([user2])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
+        vault: vault_ata,
+        whitelist: null,
+        feeOverride: null,
+        mintOfTokenSent: itheum_token_mint.publicKey,
+        authority: user2.publicKey,
+        authorityTokenAccount: itheum_token_user2_ata,
+        depositRecord: await depositRecordPda(),
+        userDeposit: userDepositPda(user2.publicKey),
+        feeVault: null,
+        mintOfFeeTokenSent: null,
+        authorityFeeTokenAccount: null,
+        feeVaultAta: null,
+        feeVaultTokenAta: null,
+        instructionsSysvar: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc()
+
+    let userDeposit = await program.account.userDeposit.fetch(
+      userDepositPda(user2.publicKey)
+    )
+
+    assert(userDeposit.depositor.equals(user2.publicKey))
+    assert(userDeposit.depositedInWindow.toNumber() == 200e9)
+  })
+
+  it('Send to liquidity by user2 - deposit cooldown (should fail)', async () => {
+    try {
       await program.methods
-        .sendToLiquidity(new anchor.BN(30e9), 'erd...', 'signature')
+        .sendToLiquidity(new anchor.BN(10e9), destinationAddress)
         .signers([user2])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
           vault: vault_ata,
           whitelist: null,
+          feeOverride: null,
           mintOfTokenSent: itheum_token_mint.publicKey,
           authority: user2.publicKey,
           authorityTokenAccount: itheum_token_user2_ata,
-          feeCollector: fee_collector.publicKey,
+          depositRecord: await depositRecordPda(),
+          userDeposit: userDepositPda(user2.publicKey),
+          feeVault: null,
+          mintOfFeeTokenSent: null,
+          authorityFeeTokenAccount: null,
+          feeVaultAta: null,
+          feeVaultTokenAta: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6035)
+      expect((err as anchor.AnchorError).error.errorMessage).to.equal(
+        'Deposit cooldown has not elapsed'
+      )
+    }
+  })
+
+  it('Disable user deposit limits by admin', async () => {
+    await program.methods
+      .setUserDepositLimits(
+        new anchor.BN(0),
+        new anchor.BN(0),
+        new anchor.BN(0)
+      )
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
+        authority: admin.publicKey,
+        queuedChange: null,
+      })
+      .rpc()
+
+    let tokenConfig = await program.account.tokenConfig.fetch(
+      itheumTokenConfigPda
+    )
+
+    assert(tokenConfig.userDepositCap.toNumber() == 0)
+  })
+
+  it('Set vault cap by admin', async () => {
+    let tokenConfig = await program.account.tokenConfig.fetch(
+      itheumTokenConfigPda
+    )
+
+    await program.methods
+      .setVaultCap(tokenConfig.vaultAmount.add(new anchor.BN(5e9)))
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
+        authority: admin.publicKey,
+        queuedChange: null,
+      })
+      .rpc()
+
+    tokenConfig = await program.account.tokenConfig.fetch(itheumTokenConfigPda)
+
+    assert(
+      tokenConfig.vaultCap.eq(tokenConfig.vaultAmount.add(new anchor.BN(5e9)))
+    )
+  })
+
+  it('Send to liquidity by user2 - vault cap exceeded (should fail)', async () => {
+    try {
+      await program.methods
+        .sendToLiquidity(new anchor.BN(10e9), destinationAddress)
+        .signers([user2])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
+          vault: vault_ata,
+          whitelist: null,
+          feeOverride: null,
+          mintOfTokenSent: itheum_token_mint.publicKey,
+          authority: user2.publicKey,
+          authorityTokenAccount: itheum_token_user2_ata,
+          depositRecord: await depositRecordPda(),
+          userDeposit: null,
+          feeVault: null,
+          mintOfFeeTokenSent: null,
+          authorityFeeTokenAccount: null,
+          feeVaultAta: null,
+          feeVaultTokenAta: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6036)
+      expect((err as anchor.AnchorError).error.errorMessage).to.equal(
+        'Deposit would exceed the vault cap'
+      )
+    }
+  })
+
+  it('Disable vault cap by admin', async () => {
+    await program.methods
+      .setVaultCap(new anchor.BN(0))
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
+        authority: admin.publicKey,
+        queuedChange: null,
+      })
+      .rpc()
+
+    let tokenConfig = await program.account.tokenConfig.fetch(
+      itheumTokenConfigPda
+    )
+
+    assert(tokenConfig.vaultCap.toNumber() == 0)
+  })
+
+  it('Set whitelist inactive', async () => {
+    await program.methods
+      .setWhitelistInactive()
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        authority: admin.publicKey,
+      })
+      .rpc()
+
+    let bridgeStateFetch = await program.account.bridgeState.fetch(
+      bridgeStatePda
+    )
+
+    assert(bridgeStateFetch.whitelistState == 0)
+  })
+
+  it('Set fee amount by admin', async () => {
+    await program.methods
+      .setFeeAmount(new anchor.BN(0.1e9))
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
+        authority: admin.publicKey,
+        queuedChange: null,
+      })
+      .rpc()
+
+    let tokenConfig = await program.account.tokenConfig.fetch(
+      itheumTokenConfigPda
+    )
+
+    assert(tokenConfig.feeAmount.toNumber() === 0.1e9)
+  })
+
+  it('Set deposit limits by admin', async () => {
+    await program.methods
+      .setDepositLimits(new anchor.BN(10e9), new anchor.BN(1000e9))
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
+        authority: admin.publicKey,
+        queuedChange: null,
+      })
+      .rpc()
+
+    let bridgeStateFetch = await program.account.bridgeState.fetch(
+      bridgeStatePda
+    )
+
+    assert(bridgeStateFetch.minimumDeposit.toNumber() == 10e9)
+    assert(bridgeStateFetch.maximumDeposit.toNumber() == 1000e9)
+  })
+
+  it('Send to liquidity by user2 - required fee - missing feeVault (should fail)', async () => {
+    try {
+      await program.methods
+        .sendToLiquidity(new anchor.BN(100e9), destinationAddress)
+        .signers([user2])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
+          vault: vault_ata,
+          whitelist: null,
+          feeOverride: null,
+          mintOfTokenSent: itheum_token_mint.publicKey,
+          authority: user2.publicKey,
+          authorityTokenAccount: itheum_token_user2_ata,
+          depositRecord: await depositRecordPda(),
+          userDeposit: null,
+          feeVault: null,
+          mintOfFeeTokenSent: NATIVE_MINT,
+          authorityFeeTokenAccount: user2_wsol_ata,
+          feeVaultAta: fee_vault_ata,
+          feeVaultTokenAta: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc()
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(2020)
+      expect((err as anchor.AnchorError).error.errorMessage).to.equal(
+        'A required account for the constraint is None'
+      )
+    }
+  })
+
+  it('Send to liquidity by user2 - required fee - missing mintOfFeeTokenSent (should fail)', async () => {
+    try {
+      await program.methods
+        .sendToLiquidity(new anchor.BN(100e9), destinationAddress)
+        .signers([user2])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
+          vault: vault_ata,
+          whitelist: null,
+          feeOverride: null,
+          mintOfTokenSent: itheum_token_mint.publicKey,
+          authority: user2.publicKey,
+          authorityTokenAccount: itheum_token_user2_ata,
+          depositRecord: await depositRecordPda(),
+          userDeposit: null,
+          feeVault: feeVaultPda,
+          mintOfFeeTokenSent: null,
+          authorityFeeTokenAccount: user2_wsol_ata,
+          feeVaultAta: fee_vault_ata,
+          feeVaultTokenAta: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc()
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(2020)
+      expect((err as anchor.AnchorError).error.errorMessage).to.equal(
+        'A required account for the constraint is None'
+      )
+    }
+  })
+
+  it('Send to liquidity by user2 - required fee - missing authorityFeeTokenAccount (should fail)', async () => {
+    try {
+      await program.methods
+        .sendToLiquidity(new anchor.BN(30e9), destinationAddress)
+        .signers([user2])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
+          vault: vault_ata,
+          whitelist: null,
+          feeOverride: null,
+          mintOfTokenSent: itheum_token_mint.publicKey,
+          authority: user2.publicKey,
+          authorityTokenAccount: itheum_token_user2_ata,
+          depositRecord: await depositRecordPda(),
+          userDeposit: null,
+          feeVault: feeVaultPda,
           mintOfFeeTokenSent: NATIVE_MINT,
           authorityFeeTokenAccount: null,
-          feeCollectorAta: fee_collector_ata,
+          feeVaultAta: fee_vault_ata,
+          feeVaultTokenAta: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc()
     } catch (err) {
@@ -1771,31 +3094,42 @@ describe('bridge-program', () => {
       .signers([admin])
       .accounts({
         bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
         authority: admin.publicKey,
+        queuedChange: null,
       })
       .rpc()
 
-    let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
+    let tokenConfig = await program.account.tokenConfig.fetch(
+      itheumTokenConfigPda
+    )
 
-    assert(bridgeState.feeAmount.toNumber() === 10e9)
+    assert(tokenConfig.feeAmount.toNumber() === 10e9)
   })
 
   it('Send to liquidity by user2 - required fee - authorityFeeTokenAccount mismatch(balance) (should fail)', async () => {
     try {
       await program.methods
-        .sendToLiquidity(new anchor.BN(30e9), 'erd...', 'signature')
+        .sendToLiquidity(new anchor.BN(30e9), destinationAddress)
         .signers([user2])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
           vault: vault_ata,
           whitelist: null,
+          feeOverride: null,
           mintOfTokenSent: itheum_token_mint.publicKey,
           authority: user2.publicKey,
           authorityTokenAccount: itheum_token_user2_ata,
-          feeCollector: fee_collector.publicKey,
+          depositRecord: await depositRecordPda(),
+          userDeposit: null,
+          feeVault: feeVaultPda,
           mintOfFeeTokenSent: NATIVE_MINT,
           authorityFeeTokenAccount: user2_wsol_ata,
-          feeCollectorAta: fee_collector_ata,
+          feeVaultAta: fee_vault_ata,
+          feeVaultTokenAta: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc()
     } catch (err) {
@@ -1812,31 +3146,42 @@ describe('bridge-program', () => {
       .signers([admin])
       .accounts({
         bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
         authority: admin.publicKey,
+        queuedChange: null,
       })
       .rpc()
 
-    let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
+    let tokenConfig = await program.account.tokenConfig.fetch(
+      itheumTokenConfigPda
+    )
 
-    assert(bridgeState.feeAmount.toNumber() === 0.1e9)
+    assert(tokenConfig.feeAmount.toNumber() === 0.1e9)
   })
 
   it('Send to liquidity by user2 - required fee - authorityFeeTokenAccount mismatch(Owner) (should fail)', async () => {
     try {
       await program.methods
-        .sendToLiquidity(new anchor.BN(30e9), 'erd...', 'signature')
+        .sendToLiquidity(new anchor.BN(30e9), destinationAddress)
         .signers([user2])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
           vault: vault_ata,
           whitelist: null,
+          feeOverride: null,
           mintOfTokenSent: itheum_token_mint.publicKey,
           authority: user2.publicKey,
           authorityTokenAccount: itheum_token_user2_ata,
-          feeCollector: fee_collector.publicKey,
+          depositRecord: await depositRecordPda(),
+          userDeposit: null,
+          feeVault: feeVaultPda,
           mintOfFeeTokenSent: NATIVE_MINT,
           authorityFeeTokenAccount: user_wsol_ata,
-          feeCollectorAta: fee_collector_ata,
+          feeVaultAta: fee_vault_ata,
+          feeVaultTokenAta: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc()
     } catch (err) {
@@ -1850,19 +3195,26 @@ describe('bridge-program', () => {
   it('Send to liquidity by user2 - required fee - authorityFeeTokenAccount mismatch(Mint) (should fail)', async () => {
     try {
       await program.methods
-        .sendToLiquidity(new anchor.BN(30e9), 'erd...', 'signature')
+        .sendToLiquidity(new anchor.BN(30e9), destinationAddress)
         .signers([user2])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
           vault: vault_ata,
           whitelist: null,
+          feeOverride: null,
           mintOfTokenSent: itheum_token_mint.publicKey,
           authority: user2.publicKey,
           authorityTokenAccount: itheum_token_user2_ata,
-          feeCollector: fee_collector.publicKey,
+          depositRecord: await depositRecordPda(),
+          userDeposit: null,
+          feeVault: feeVaultPda,
           mintOfFeeTokenSent: NATIVE_MINT,
           authorityFeeTokenAccount: another_token_user2_ata,
-          feeCollectorAta: fee_collector_ata,
+          feeVaultAta: fee_vault_ata,
+          feeVaultTokenAta: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc()
     } catch (err) {
@@ -1873,22 +3225,29 @@ describe('bridge-program', () => {
     }
   })
 
-  it('Send to liquidity by user2 - required fee - missing tempFeeCollector (should fail)', async () => {
+  it('Send to liquidity by user2 - required fee - missing feeVaultAta (should fail)', async () => {
     try {
       await program.methods
-        .sendToLiquidity(new anchor.BN(30e9), 'erd...', 'signature')
+        .sendToLiquidity(new anchor.BN(30e9), destinationAddress)
         .signers([user2])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
           vault: vault_ata,
           whitelist: null,
+          feeOverride: null,
           mintOfTokenSent: itheum_token_mint.publicKey,
           authority: user2.publicKey,
           authorityTokenAccount: itheum_token_user2_ata,
-          feeCollector: fee_collector.publicKey,
+          depositRecord: await depositRecordPda(),
+          userDeposit: null,
+          feeVault: feeVaultPda,
           mintOfFeeTokenSent: NATIVE_MINT,
           authorityFeeTokenAccount: user2_wsol_ata,
-          feeCollectorAta: null,
+          feeVaultAta: null,
+          feeVaultTokenAta: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc()
     } catch (err) {
@@ -1902,19 +3261,26 @@ describe('bridge-program', () => {
   it('Send to liquidity by user2 - required fee - tempFeeCollector mismatch(Mint) (should fail)', async () => {
     try {
       await program.methods
-        .sendToLiquidity(new anchor.BN(30e9), 'erd...', 'signature')
+        .sendToLiquidity(new anchor.BN(30e9), destinationAddress)
         .signers([user2])
         .accounts({
           bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
           vault: vault_ata,
           whitelist: null,
+          feeOverride: null,
           mintOfTokenSent: itheum_token_mint.publicKey,
           authority: user2.publicKey,
           authorityTokenAccount: itheum_token_user2_ata,
-          feeCollector: fee_collector.publicKey,
+          depositRecord: await depositRecordPda(),
+          userDeposit: null,
+          feeVault: feeVaultPda,
           mintOfFeeTokenSent: NATIVE_MINT,
           authorityFeeTokenAccount: user2_wsol_ata,
-          feeCollectorAta: vault_ata,
+          feeVaultAta: vault_ata,
+          feeVaultTokenAta: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc()
     } catch (err) {
@@ -1925,40 +3291,2096 @@ describe('bridge-program', () => {
     }
   })
 
-  it('Send to liquidity by user2 - required fee ', async () => {
+  it('Set bps fee by admin - more than 100% (should fail)', async () => {
+    try {
+      await program.methods
+        .setBpsFee(10001, new anchor.BN(0), new anchor.BN(0))
+        .signers([admin])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
+          authority: admin.publicKey,
+          queuedChange: null,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6027)
+      expect((err as anchor.AnchorError).error.errorMessage).to.equal(
+        'Invalid fee configuration'
+      )
+    }
+  })
+
+  it('Set bps fee by admin', async () => {
     await program.methods
-      .sendToLiquidity(new anchor.BN(150e9), 'erd...', 'signature')
-      .signers([user2])
+      .setBpsFee(100, new anchor.BN(0.5e9), new anchor.BN(0))
+      .signers([admin])
       .accounts({
         bridgeState: bridgeStatePda,
-        vault: vault_ata,
-        whitelist: null,
-        mintOfTokenSent: itheum_token_mint.publicKey,
-        authority: user2.publicKey,
-        authorityTokenAccount: itheum_token_user2_ata,
-        feeCollector: fee_collector.publicKey,
-        mintOfFeeTokenSent: NATIVE_MINT,
-        authorityFeeTokenAccount: user2_wsol_ata,
-        feeCollectorAta: fee_collector_ata,
+        tokenConfig: itheumTokenConfigPda,
+        authority: admin.publicKey,
+        queuedChange: null,
       })
       .rpc()
 
-    let fee_collector_account_balance = await connection.getTokenAccountBalance(
-      fee_collector_ata
-    )
-
-    const expected_balance = 0.1e9 //  0.1e9 fee
-
-    assert(
-      Number(fee_collector_account_balance.value.amount) == expected_balance
+    let tokenConfig = await program.account.tokenConfig.fetch(
+      itheumTokenConfigPda
     )
 
-    let user2Ata = await getAccount(connection, itheum_token_user2_ata)
-
-    assert(Number(user2Ata.amount) == 0e9)
+    assert(tokenConfig.feeBps === 100)
+    assert(tokenConfig.minBpsFee.toNumber() === 0.5e9)
+    assert(tokenConfig.maxBpsFee.toNumber() === 0)
+  })
 
-    let bridge = await program.account.bridgeState.fetch(bridgeStatePda)
+  it('Send to liquidity by user2 - required bps fee - missing feeVaultTokenAta (should fail)', async () => {
+    try {
+      await program.methods
+        .sendToLiquidity(new anchor.BN(150e9), destinationAddress)
+        .signers([user2])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
+          vault: vault_ata,
+          whitelist: null,
+          feeOverride: null,
+          mintOfTokenSent: itheum_token_mint.publicKey,
+          authority: user2.publicKey,
+          authorityTokenAccount: itheum_token_user2_ata,
+          depositRecord: await depositRecordPda(),
+          userDeposit: null,
+          feeVault: feeVaultPda,
+          mintOfFeeTokenSent: NATIVE_MINT,
+          authorityFeeTokenAccount: user2_wsol_ata,
+          feeVaultAta: fee_vault_ata,
+          feeVaultTokenAta: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6008)
+    }
+  })
+
+  it('Send to liquidity by user2 - required fee ', async () => {
+    const depositRecord = await depositRecordPda()
+
+    await program.methods
+      .sendToLiquidity(new anchor.BN(100e9), destinationAddress)
+      .signers([user2])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
+        vault: vault_ata,
+        whitelist: null,
+        feeOverride: null,
+        mintOfTokenSent: itheum_token_mint.publicKey,
+        authority: user2.publicKey,
+        authorityTokenAccount: itheum_token_user2_ata,
+        depositRecord,
+        userDeposit: null,
+        feeVault: feeVaultPda,
+        mintOfFeeTokenSent: NATIVE_MINT,
+        authorityFeeTokenAccount: user2_wsol_ata,
+        feeVaultAta: fee_vault_ata,
+        feeVaultTokenAta: fee_vault_itheum_ata,
+        instructionsSysvar: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc()
+
+    let fee_vault_account_balance = await connection.getTokenAccountBalance(
+      fee_vault_ata
+    )
+
+    const expected_balance = 0.1e9 //  0.1e9 fee
+
+    assert(Number(fee_vault_account_balance.value.amount) == expected_balance)
+
+    let tokenConfig = await program.account.tokenConfig.fetch(
+      itheumTokenConfigPda
+    )
+
+    assert(tokenConfig.vaultAmount.toNumber() == 1149e9)
+
+    let feeVaultItheumAta = await getAccount(connection, fee_vault_itheum_ata)
+
+    assert(Number(feeVaultItheumAta.amount) == 1e9) // 1% of 100e9
+
+    let depositRecordFetch = await program.account.depositRecord.fetch(
+      depositRecord
+    )
+
+    assert(depositRecordFetch.amount.toNumber() == 99e9)
+    assert(depositRecordFetch.tokenFee.toNumber() == 1e9)
+  })
+
+  it('Send to liquidity by user2 - required fee in native SOL', async () => {
+    const feeVaultLamports = await connection.getBalance(feeVaultPda)
+    const depositRecord = await depositRecordPda()
+
+    await program.methods
+      .sendToLiquidity(new anchor.BN(40e9), destinationAddress)
+      .signers([user2])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
+        vault: vault_ata,
+        whitelist: null,
+        feeOverride: null,
+        mintOfTokenSent: itheum_token_mint.publicKey,
+        authority: user2.publicKey,
+        authorityTokenAccount: itheum_token_user2_ata,
+        depositRecord,
+        userDeposit: null,
+        feeVault: feeVaultPda,
+        mintOfFeeTokenSent: null,
+        authorityFeeTokenAccount: null,
+        feeVaultAta: null,
+        feeVaultTokenAta: fee_vault_itheum_ata,
+        instructionsSysvar: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc()
+
+    assert(
+      (await connection.getBalance(feeVaultPda)) == feeVaultLamports + 0.1e9
+    )
+
+    let tokenConfig = await program.account.tokenConfig.fetch(
+      itheumTokenConfigPda
+    )
+
+    assert(tokenConfig.vaultAmount.toNumber() == 1188e9)
+
+    let feeVaultItheumAta = await getAccount(connection, fee_vault_itheum_ata)
+
+    // 1% of 40e9 is below the min fee of 0.5e9, which is rounded up to a whole token
+    assert(Number(feeVaultItheumAta.amount) == 2e9)
+
+    let depositRecordFetch = await program.account.depositRecord.fetch(
+      depositRecord
+    )
+
+    assert(depositRecordFetch.amount.toNumber() == 39e9)
+    assert(depositRecordFetch.tokenFee.toNumber() == 1e9)
+  })
+
+  it('Set fee override by user (should fail)', async () => {
+    try {
+      await program.methods
+        .setFeeOverride(
+          itheum_token_mint.publicKey,
+          user2.publicKey,
+          new anchor.BN(0),
+          0
+        )
+        .signers([user])
+        .accounts({
+          feeOverride: feeOverridePda(user2.publicKey),
+          authority: user.publicKey,
+          bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6004)
+    }
+  })
+
+  it('Set fee override by admin', async () => {
+    await program.methods
+      .setFeeOverride(
+          itheum_token_mint.publicKey,
+          user2.publicKey,
+          new anchor.BN(0),
+          0
+        )
+      .signers([admin])
+      .accounts({
+        feeOverride: feeOverridePda(user2.publicKey),
+        authority: admin.publicKey,
+        bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
+      })
+      .rpc()
+
+    let feeOverride = await program.account.feeOverride.fetch(
+      feeOverridePda(user2.publicKey)
+    )
+
+    assert(feeOverride.mint.equals(itheum_token_mint.publicKey))
+    assert(feeOverride.address.equals(user2.publicKey))
+    assert(feeOverride.feeAmount.toNumber() === 0)
+    assert(feeOverride.feeBps === 0)
+  })
+
+  it('Send to liquidity by user2 - fee override without fee accounts', async () => {
+    await program.methods
+      .sendToLiquidity(new anchor.BN(10e9), destinationAddress)
+      .signers([user2])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
+        vault: vault_ata,
+        whitelist: null,
+        feeOverride: feeOverridePda(user2.publicKey),
+        mintOfTokenSent: itheum_token_mint.publicKey,
+        authority: user2.publicKey,
+        authorityTokenAccount: itheum_token_user2_ata,
+        depositRecord: await depositRecordPda(),
+        userDeposit: null,
+        feeVault: null,
+        mintOfFeeTokenSent: null,
+        authorityFeeTokenAccount: null,
+        feeVaultAta: null,
+        feeVaultTokenAta: null,
+        instructionsSysvar: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc()
+
+    let user2Ata = await getAccount(connection, itheum_token_user2_ata)
+
+    assert(Number(user2Ata.amount) == 0e9)
+
+    let tokenConfig = await program.account.tokenConfig.fetch(
+      itheumTokenConfigPda
+    )
+
+    assert(tokenConfig.vaultAmount.toNumber() == 1198e9)
+  })
+
+  it('Remove fee override by admin', async () => {
+    await program.methods
+      .removeFeeOverride(itheum_token_mint.publicKey, user2.publicKey)
+      .signers([admin])
+      .accounts({
+        feeOverride: feeOverridePda(user2.publicKey),
+        authority: admin.publicKey,
+        bridgeState: bridgeStatePda,
+      })
+      .rpc()
+
+    let feeOverride = await program.account.feeOverride.fetchNullable(
+      feeOverridePda(user2.publicKey)
+    )
+
+    assert(feeOverride === null)
+  })
+
+  it('Withdraw fees by user (should fail)', async () => {
+    try {
+      await program.methods
+        .withdrawFees(new anchor.BN(0.1e9))
+        .signers([user])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          authority: user.publicKey,
+          feeVault: feeVaultPda,
+          feeCollector: fee_collector.publicKey,
+          mint: null,
+          feeVaultAta: null,
+          feeCollectorAta: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6004)
+    }
+  })
+
+  it('Withdraw fees by admin', async () => {
+    let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
+
+    assert(bridgeState.feesCollected.toNumber() == 0.2e9) // wSOL and native SOL fee
+
+    let tokenConfig = await program.account.tokenConfig.fetch(
+      itheumTokenConfigPda
+    )
+
+    assert(tokenConfig.feesCollected.toNumber() == 2e9)
+
+    const feeCollectorLamports = await connection.getBalance(
+      fee_collector.publicKey
+    )
+
+    await program.methods
+      .withdrawFees(new anchor.BN(0.1e9))
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        authority: admin.publicKey,
+        feeVault: feeVaultPda,
+        feeCollector: fee_collector.publicKey,
+        mint: null,
+        feeVaultAta: null,
+        feeCollectorAta: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc()
+
+    assert(
+      (await connection.getBalance(fee_collector.publicKey)) ==
+        feeCollectorLamports + 0.1e9
+    )
+
+    await program.methods
+      .withdrawFees(new anchor.BN(0.1e9))
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        authority: admin.publicKey,
+        feeVault: feeVaultPda,
+        feeCollector: fee_collector.publicKey,
+        mint: NATIVE_MINT,
+        feeVaultAta: fee_vault_ata,
+        feeCollectorAta: fee_collector_ata,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc()
+
+    let feeCollectorAta = await getAccount(connection, fee_collector_ata)
+
+    assert(Number(feeCollectorAta.amount) == 0.1e9)
+
+    await program.methods
+      .withdrawFees(new anchor.BN(2e9))
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        authority: admin.publicKey,
+        feeVault: feeVaultPda,
+        feeCollector: fee_collector.publicKey,
+        mint: itheum_token_mint.publicKey,
+        feeVaultAta: fee_vault_itheum_ata,
+        feeCollectorAta: fee_collector_itheum_ata,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc()
+
+    let feeCollectorItheumAta = await getAccount(
+      connection,
+      fee_collector_itheum_ata
+    )
+
+    assert(Number(feeCollectorItheumAta.amount) == 2e9)
+
+    let feeVaultItheumAta = await getAccount(connection, fee_vault_itheum_ata)
+
+    assert(Number(feeVaultItheumAta.amount) == 0)
+
+    // the counters keep the running total for reconciliation
+    tokenConfig = await program.account.tokenConfig.fetch(itheumTokenConfigPda)
+
+    assert(tokenConfig.feesCollected.toNumber() == 2e9)
+  })
+
+  it('Whitelist another mint by admin', async () => {
+    await program.methods
+      .updateWhitelistedMint()
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: tokenConfigPda(another_token_mint.publicKey),
+        authority: admin.publicKey,
+        vault: another_token_vault_ata,
+        mintOfTokenWhitelisted: another_token_mint.publicKey,
+        multisig: null,
+        proposal: null,
+        queuedChange: null,
+        previousTokenConfig: null,
+        previousVault: null,
+        previousMint: null,
+        sweepTokenAccount: null,
+        previousTokenProgram: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc()
+
+    let tokenConfig = await program.account.tokenConfig.fetch(
+      tokenConfigPda(another_token_mint.publicKey)
+    )
+
+    assert(tokenConfig.mint.equals(another_token_mint.publicKey))
+    assert(tokenConfig.vault.equals(another_token_vault_ata))
+    assert(tokenConfig.vaultAmount.toNumber() === 0)
+    assert(tokenConfig.relayerState === 0)
+    assert(tokenConfig.publicState === 0)
+
+    let itheumTokenConfig = await program.account.tokenConfig.fetch(
+      itheumTokenConfigPda
+    )
+
+    assert(itheumTokenConfig.vault.equals(vault_ata))
+  })
+
+  it('Add liquidity for another mint by admin', async () => {
+    await program.methods
+      .addLiquidity(new anchor.BN(100e9))
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: tokenConfigPda(another_token_mint.publicKey),
+        vault: another_token_vault_ata,
+        mintOfTokenSent: another_token_mint.publicKey,
+        authority: admin.publicKey,
+        authorityTokenAccount: another_token_admin_ata,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc()
+
+    let tokenConfig = await program.account.tokenConfig.fetch(
+      tokenConfigPda(another_token_mint.publicKey)
+    )
+    let itheumTokenConfig = await program.account.tokenConfig.fetch(
+      itheumTokenConfigPda
+    )
+
+    assert(tokenConfig.vaultAmount.toNumber() === 100e9)
+    assert(itheumTokenConfig.vaultAmount.toNumber() === 1198e9)
+  })
+
+  it('Unpause relayer for another mint by admin', async () => {
+    await program.methods
+      .relayerUnpause()
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        authority: admin.publicKey,
+        tokenConfig: tokenConfigPda(another_token_mint.publicKey),
+      })
+      .rpc()
+
+    let tokenConfig = await program.account.tokenConfig.fetch(
+      tokenConfigPda(another_token_mint.publicKey)
+    )
+
+    assert(tokenConfig.relayerState === 1)
+    assert(tokenConfig.publicState === 0)
+  })
+
+  it('Unpause public for another mint by admin - deposit limits not set (should fail)', async () => {
+    try {
+      await program.methods
+        .publicUnpause()
+        .signers([admin])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          authority: admin.publicKey,
+          tokenConfig: tokenConfigPda(another_token_mint.publicKey),
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6057)
+    }
+  })
+
+  it('Set deposit limits for another mint by admin - zero minimum (should fail)', async () => {
+    try {
+      await program.methods
+        .setDepositLimits(new anchor.BN(0), new anchor.BN(100e9))
+        .signers([admin])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          tokenConfig: tokenConfigPda(another_token_mint.publicKey),
+          authority: admin.publicKey,
+          queuedChange: null,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6057)
+    }
+  })
+
+  it('Migrate another mint to wSOL - vault not empty (should fail)', async () => {
+    try {
+      await program.methods
+        .updateWhitelistedMint()
+        .signers([admin])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          tokenConfig: tokenConfigPda(NATIVE_MINT),
+          authority: admin.publicKey,
+          vault: wsol_vault_ata,
+          mintOfTokenWhitelisted: NATIVE_MINT,
+          multisig: null,
+          proposal: null,
+          queuedChange: null,
+          previousTokenConfig: tokenConfigPda(another_token_mint.publicKey),
+          previousVault: another_token_vault_ata,
+          previousMint: another_token_mint.publicKey,
+          sweepTokenAccount: null,
+          previousTokenProgram: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6024)
+      expect((err as anchor.AnchorError).error.errorMessage).to.equal(
+        'Vault not empty'
+      )
+    }
+  })
+
+  it('Migrate another mint to wSOL with sweep to liquidity manager', async () => {
+    await program.methods
+      .updateWhitelistedMint()
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: tokenConfigPda(NATIVE_MINT),
+        authority: admin.publicKey,
+        vault: wsol_vault_ata,
+        mintOfTokenWhitelisted: NATIVE_MINT,
+        multisig: null,
+        proposal: null,
+        queuedChange: null,
+        previousTokenConfig: tokenConfigPda(another_token_mint.publicKey),
+        previousVault: another_token_vault_ata,
+        previousMint: another_token_mint.publicKey,
+        sweepTokenAccount: another_token_admin_ata,
+        previousTokenProgram: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc()
+
+    let previousTokenConfig = await program.account.tokenConfig.fetch(
+      tokenConfigPda(another_token_mint.publicKey)
+    )
+    let tokenConfig = await program.account.tokenConfig.fetch(
+      tokenConfigPda(NATIVE_MINT)
+    )
+
+    assert(previousTokenConfig.vaultAmount.toNumber() === 0)
+    assert(previousTokenConfig.relayerState === 0)
+    assert(previousTokenConfig.publicState === 0)
+    assert(tokenConfig.mint.equals(NATIVE_MINT))
+    assert(tokenConfig.previousMint.equals(another_token_mint.publicKey))
+    assert(tokenConfig.previousVault.equals(another_token_vault_ata))
+
+    let previousVault = await getAccount(connection, another_token_vault_ata)
+    let adminAta = await getAccount(connection, another_token_admin_ata)
+
+    assert(Number(previousVault.amount) == 0)
+    assert(Number(adminAta.amount) == 1000e9)
+  })
+
+  it('Set relayer committee by admin', async () => {
+    await program.methods
+      .setRelayerCommittee([admin.publicKey, user.publicKey], 2)
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        relayerCommittee: relayerCommitteePda,
+        authority: admin.publicKey,
+        multisig: null,
+        proposal: null,
+        queuedChange: null,
+      })
+      .rpc()
+
+    let relayerCommittee = await program.account.relayerCommittee.fetch(
+      relayerCommitteePda
+    )
+    let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
+
+    assert(relayerCommittee.threshold === 2)
+    assert(relayerCommittee.relayers.length === 2)
+    assert(bridgeState.relayerCommitteeState === 1)
+  })
+
+  it('Send from liquidity by relayer - release approval not provided (should fail)', async () => {
+    try {
+      await program.methods
+        .sendFromLiquidity(new anchor.BN(100e9), user.publicKey, txHash(11))
+        .signers([admin])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
+          vault: vault_ata,
+          mintOfTokenSent: itheum_token_mint.publicKey,
+          authority: admin.publicKey,
+          receiverTokenAccount: itheum_token_user_ata,
+          transferReceipt: transferReceiptPda(txHash(11)),
+          relayerCommittee: relayerCommitteePda,
+          releaseApproval: null,
+          payer: null,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6038)
+      expect((err as anchor.AnchorError).error.errorMessage).to.equal(
+        'Release approval not provided'
+      )
+    }
+  })
+
+  it('Send from liquidity by relayer - not enough approvals (should fail)', async () => {
+    await program.methods
+      .approveRelease(
+        new anchor.BN(100e9),
+        user.publicKey,
+        txHash(11),
+        itheum_token_mint.publicKey
+      )
+      .signers([admin])
+      .accounts({
+        relayerCommittee: relayerCommitteePda,
+        releaseApproval: releaseApprovalPda(
+          txHash(11),
+          user.publicKey,
+          itheum_token_mint.publicKey,
+          new anchor.BN(100e9)
+        ),
+        authority: admin.publicKey,
+      })
+      .rpc()
+
+    try {
+      await program.methods
+        .sendFromLiquidity(new anchor.BN(100e9), user.publicKey, txHash(11))
+        .signers([admin])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
+          vault: vault_ata,
+          mintOfTokenSent: itheum_token_mint.publicKey,
+          authority: admin.publicKey,
+          receiverTokenAccount: itheum_token_user_ata,
+          transferReceipt: transferReceiptPda(txHash(11)),
+          relayerCommittee: relayerCommitteePda,
+          releaseApproval: releaseApprovalPda(
+            txHash(11),
+            user.publicKey,
+            itheum_token_mint.publicKey,
+            new anchor.BN(100e9)
+          ),
+          payer: admin.publicKey,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6039)
+      expect((err as anchor.AnchorError).error.errorMessage).to.equal(
+        'Release not approved by enough relayers'
+      )
+    }
+  })
+
+  it('Approve release by relayer - different amount is a separate approval', async () => {
+    const otherApprovalPda = releaseApprovalPda(
+      txHash(11),
+      user.publicKey,
+      itheum_token_mint.publicKey,
+      new anchor.BN(1000e9)
+    )
+
+    await program.methods
+      .approveRelease(
+        new anchor.BN(1000e9),
+        user.publicKey,
+        txHash(11),
+        itheum_token_mint.publicKey
+      )
+      .signers([user])
+      .accounts({
+        relayerCommittee: relayerCommitteePda,
+        releaseApproval: otherApprovalPda,
+        authority: user.publicKey,
+      })
+      .rpc()
+
+    // a conflicting approval cannot take over the approval of the real transfer
+    let otherApproval = await program.account.releaseApproval.fetch(
+      otherApprovalPda
+    )
+    let releaseApproval = await program.account.releaseApproval.fetch(
+      releaseApprovalPda(
+        txHash(11),
+        user.publicKey,
+        itheum_token_mint.publicKey,
+        new anchor.BN(100e9)
+      )
+    )
+
+    assert(otherApproval.amount.toNumber() === 1000e9)
+    assert(otherApproval.approvals.length === 1)
+    assert(releaseApproval.amount.toNumber() === 100e9)
+    assert(releaseApproval.approvals.length === 1)
+  })
+
+  it('Approve release by relayer - approved twice (should fail)', async () => {
+    try {
+      await program.methods
+        .approveRelease(
+          new anchor.BN(100e9),
+          user.publicKey,
+          txHash(11),
+          itheum_token_mint.publicKey
+        )
+        .signers([admin])
+        .accounts({
+          relayerCommittee: relayerCommitteePda,
+          releaseApproval: releaseApprovalPda(
+            txHash(11),
+            user.publicKey,
+            itheum_token_mint.publicKey,
+            new anchor.BN(100e9)
+          ),
+          authority: admin.publicKey,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6041)
+      expect((err as anchor.AnchorError).error.errorMessage).to.equal(
+        'Release already approved by this relayer'
+      )
+    }
+  })
+
+  it('Send from liquidity by relayer - approved by the committee', async () => {
+    await program.methods
+      .approveRelease(
+        new anchor.BN(100e9),
+        user.publicKey,
+        txHash(11),
+        itheum_token_mint.publicKey
+      )
+      .signers([user])
+      .accounts({
+        relayerCommittee: relayerCommitteePda,
+        releaseApproval: releaseApprovalPda(
+          txHash(11),
+          user.publicKey,
+          itheum_token_mint.publicKey,
+          new anchor.BN(100e9)
+        ),
+        authority: user.publicKey,
+      })
+      .rpc()
+
+    const approvalPda = releaseApprovalPda(
+      txHash(11),
+      user.publicKey,
+      itheum_token_mint.publicKey,
+      new anchor.BN(100e9)
+    )
+
+    // the rent of the approval goes back to the relayer that paid for it
+    try {
+      await program.methods
+        .sendFromLiquidity(new anchor.BN(100e9), user.publicKey, txHash(11))
+        .signers([user])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
+          vault: vault_ata,
+          mintOfTokenSent: itheum_token_mint.publicKey,
+          authority: user.publicKey,
+          receiverTokenAccount: itheum_token_user_ata,
+          transferReceipt: transferReceiptPda(txHash(11)),
+          relayerCommittee: relayerCommitteePda,
+          releaseApproval: approvalPda,
+          payer: user.publicKey,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(2001)
+    }
+
+    let vaultBefore = await getAccount(connection, vault_ata)
+    let adminLamportsBefore = await connection.getBalance(admin.publicKey)
+    let approvalLamports = await connection.getBalance(approvalPda)
+
+    await program.methods
+      .sendFromLiquidity(new anchor.BN(100e9), user.publicKey, txHash(11))
+      .signers([user])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
+        vault: vault_ata,
+        mintOfTokenSent: itheum_token_mint.publicKey,
+        authority: user.publicKey,
+        receiverTokenAccount: itheum_token_user_ata,
+        transferReceipt: transferReceiptPda(txHash(11)),
+        relayerCommittee: relayerCommitteePda,
+        releaseApproval: releaseApprovalPda(
+          txHash(11),
+          user.publicKey,
+          itheum_token_mint.publicKey,
+          new anchor.BN(100e9)
+        ),
+        payer: admin.publicKey,
+        instructionsSysvar: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc()
+
+    let vault = await getAccount(connection, vault_ata)
+    let releaseApproval = await program.account.releaseApproval.fetchNullable(
+      releaseApprovalPda(
+        txHash(11),
+        user.publicKey,
+        itheum_token_mint.publicKey,
+        new anchor.BN(100e9)
+      )
+    )
+
+    let adminLamports = await connection.getBalance(admin.publicKey)
+
+    assert(Number(vaultBefore.amount) - Number(vault.amount) == 100e9)
+    assert(releaseApproval === null)
+    assert(adminLamports - adminLamportsBefore === approvalLamports)
+  })
+
+  it('Disable relayer committee by admin', async () => {
+    await program.methods
+      .setRelayerCommittee([], 0)
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        relayerCommittee: relayerCommitteePda,
+        authority: admin.publicKey,
+        multisig: null,
+        proposal: null,
+        queuedChange: null,
+      })
+      .rpc()
+
+    let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
+
+    assert(bridgeState.relayerCommitteeState === 0)
+  })
+
+  it('Set chain id by admin', async () => {
+    await program.methods
+      .setChainId(new anchor.BN(1))
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        authority: admin.publicKey,
+        queuedChange: null,
+      })
+      .rpc()
+
+    let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
+
+    assert(bridgeState.chainId.toNumber() === 1)
+  })
+
+  it('Send from liquidity by user2 - attestation not signed by the relayer (should fail)', async () => {
+    try {
+      await program.methods
+        .sendFromLiquidity(new anchor.BN(100e9), user.publicKey, txHash(12))
+        .preInstructions([
+          releaseAttestation(
+            user,
+            txHash(12),
+            user.publicKey,
+            new anchor.BN(100e9),
+            itheum_token_mint.publicKey,
+            new anchor.BN(1)
+          ),
+        ])
+        .signers([user2])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
+          vault: vault_ata,
+          mintOfTokenSent: itheum_token_mint.publicKey,
+          authority: user2.publicKey,
+          receiverTokenAccount: itheum_token_user_ata,
+          transferReceipt: transferReceiptPda(txHash(12)),
+          relayerCommittee: null,
+          releaseApproval: null,
+          payer: null,
+          instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6039)
+      expect((err as anchor.AnchorError).error.errorMessage).to.equal(
+        'Release not approved by enough relayers'
+      )
+    }
+  })
+
+  it('Send from liquidity by user2 - attested by the relayer', async () => {
+    let vaultBefore = await getAccount(connection, vault_ata)
+
+    await program.methods
+      .sendFromLiquidity(new anchor.BN(100e9), user.publicKey, txHash(12))
+      .preInstructions([
+        releaseAttestation(
+          admin,
+          txHash(12),
+          user.publicKey,
+          new anchor.BN(100e9),
+          itheum_token_mint.publicKey,
+          new anchor.BN(1)
+        ),
+      ])
+      .signers([user2])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
+        vault: vault_ata,
+        mintOfTokenSent: itheum_token_mint.publicKey,
+        authority: user2.publicKey,
+        receiverTokenAccount: itheum_token_user_ata,
+        transferReceipt: transferReceiptPda(txHash(12)),
+        relayerCommittee: null,
+        releaseApproval: null,
+        payer: null,
+        instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc()
+
+    let vault = await getAccount(connection, vault_ata)
+    let receipt = await program.account.transferReceipt.fetch(
+      transferReceiptPda(txHash(12))
+    )
+
+    assert(Number(vaultBefore.amount) - Number(vault.amount) == 100e9)
+    assert(receipt.receiver.equals(user.publicKey))
+  })
+
+  it('Set destination signature active by admin', async () => {
+    await program.methods
+      .setDestinationSignatureActive()
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        authority: admin.publicKey,
+        queuedChange: null,
+      })
+      .rpc()
+
+    let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
+
+    assert(bridgeState.destinationSignatureState === 1)
+  })
+
+  it('Send to liquidity by user - destination signature missing (should fail)', async () => {
+    try {
+      await program.methods
+        .sendToLiquidity(new anchor.BN(100e9), destinationAddress)
+        .signers([user])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
+          vault: vault_ata,
+          whitelist: null,
+          feeOverride: null,
+          mintOfTokenSent: itheum_token_mint.publicKey,
+          authority: user.publicKey,
+          authorityTokenAccount: itheum_token_user_ata,
+          depositRecord: await depositRecordPda(),
+          userDeposit: userDepositPda(user.publicKey),
+          feeVault: feeVaultPda,
+          mintOfFeeTokenSent: null,
+          authorityFeeTokenAccount: null,
+          feeVaultAta: null,
+          feeVaultTokenAta: fee_vault_itheum_ata,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6044)
+      expect((err as anchor.AnchorError).error.errorMessage).to.equal(
+        'Destination address signature not verified'
+      )
+    }
+  })
+
+  it('Send to liquidity by user - destination signature verified', async () => {
+    const depositRecord = await depositRecordPda()
+    const userDeposit = await program.account.userDeposit.fetchNullable(
+      userDepositPda(user.publicKey)
+    )
+    const nonce = userDeposit ? userDeposit.depositNonce : new anchor.BN(0)
+
+    await program.methods
+      .sendToLiquidity(new anchor.BN(100e9), destinationAddress)
+      .preInstructions([
+        Ed25519Program.createInstructionWithPrivateKey({
+          privateKey: destinationKeypair.secretKey,
+          message: signedMessageHash(
+            `${user.publicKey.toBase58()}:${itheum_token_mint.publicKey.toBase58()}:${100e9}:${nonce}`
+          ),
+        }),
+      ])
+      .signers([user])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
+        vault: vault_ata,
+        whitelist: null,
+        feeOverride: null,
+        mintOfTokenSent: itheum_token_mint.publicKey,
+        authority: user.publicKey,
+        authorityTokenAccount: itheum_token_user_ata,
+        depositRecord: await depositRecordPda(),
+        userDeposit: userDepositPda(user.publicKey),
+        feeVault: feeVaultPda,
+        mintOfFeeTokenSent: null,
+        authorityFeeTokenAccount: null,
+        feeVaultAta: null,
+        feeVaultTokenAta: fee_vault_itheum_ata,
+        instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc()
+
+    let record = await program.account.depositRecord.fetch(depositRecord)
+
+    let userDepositAfter = await program.account.userDeposit.fetch(
+      userDepositPda(user.publicKey)
+    )
+
+    assert(record.depositor.equals(user.publicKey))
+    assert(Buffer.from(record.destinationAddress).equals(destinationKey))
+    assert(userDepositAfter.depositNonce.eq(nonce.addn(1)))
+  })
+
+  it('Set destination signature inactive by admin', async () => {
+    await program.methods
+      .setDestinationSignatureInactive()
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        authority: admin.publicKey,
+        queuedChange: null,
+      })
+      .rpc()
+
+    let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
+
+    assert(bridgeState.destinationSignatureState === 0)
+  })
+
+  it('Set refund policy by admin', async () => {
+    await program.methods
+      .setRefundPolicy(new anchor.BN(2), true)
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        authority: admin.publicKey,
+        queuedChange: null,
+      })
+      .rpc()
+
+    let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
+
+    assert(bridgeState.refundTimeout.toNumber() === 2)
+    assert(bridgeState.refundFee === true)
+  })
+
+  it('Claim refund by user - timeout not elapsed (should fail)', async () => {
+    let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
+    refundDepositId = bridgeState.depositNonce
+    userBalanceBeforeDeposit = (
+      await getAccount(connection, itheum_token_user_ata)
+    ).amount
+
+    await program.methods
+      .sendToLiquidity(new anchor.BN(100e9), destinationAddress)
+      .signers([user])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
+        vault: vault_ata,
+        whitelist: null,
+        feeOverride: null,
+        mintOfTokenSent: itheum_token_mint.publicKey,
+        authority: user.publicKey,
+        authorityTokenAccount: itheum_token_user_ata,
+        depositRecord: await depositRecordPda(),
+        userDeposit: null,
+        feeVault: feeVaultPda,
+        mintOfFeeTokenSent: null,
+        authorityFeeTokenAccount: null,
+        feeVaultAta: null,
+        feeVaultTokenAta: fee_vault_itheum_ata,
+        instructionsSysvar: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc()
+
+    let record = await program.account.depositRecord.fetch(
+      depositRecordByIdPda(refundDepositId)
+    )
+
+    assert(record.status === 0)
+
+    try {
+      await program.methods
+        .claimRefund(refundDepositId)
+        .signers([user])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
+          vault: vault_ata,
+          depositRecord: depositRecordByIdPda(refundDepositId),
+          authority: user.publicKey,
+          mintOfTokenSent: itheum_token_mint.publicKey,
+          authorityTokenAccount: itheum_token_user_ata,
+          feeVault: feeVaultPda,
+          feeVaultTokenAta: fee_vault_itheum_ata,
+          mintOfFeeTokenSent: null,
+          feeVaultAta: null,
+          authorityFeeTokenAccount: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6048)
+      expect((err as anchor.AnchorError).error.errorMessage).to.equal(
+        'Refund timeout has not elapsed'
+      )
+    }
+  })
+
+  it('Claim refund by user - timeout elapsed', async () => {
+    await new Promise((resolve) => setTimeout(resolve, 3000))
+
+    const bridgeStateBefore = await program.account.bridgeState.fetch(
+      bridgeStatePda
+    )
+    const tokenConfigBefore = await program.account.tokenConfig.fetch(
+      itheumTokenConfigPda
+    )
+
+    await program.methods
+      .claimRefund(refundDepositId)
+      .signers([user])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
+        vault: vault_ata,
+        depositRecord: depositRecordByIdPda(refundDepositId),
+        authority: user.publicKey,
+        mintOfTokenSent: itheum_token_mint.publicKey,
+        authorityTokenAccount: itheum_token_user_ata,
+        feeVault: feeVaultPda,
+        feeVaultTokenAta: fee_vault_itheum_ata,
+        mintOfFeeTokenSent: null,
+        feeVaultAta: null,
+        authorityFeeTokenAccount: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc()
+
+    let record = await program.account.depositRecord.fetch(
+      depositRecordByIdPda(refundDepositId)
+    )
+    let userAta = await getAccount(connection, itheum_token_user_ata)
+
+    let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
+    let tokenConfig = await program.account.tokenConfig.fetch(
+      itheumTokenConfigPda
+    )
+
+    assert(record.status === 2)
+    assert(userAta.amount === userBalanceBeforeDeposit)
+    // lifetime totals, refunds do not reduce them
+    assert(bridgeState.feesCollected.eq(bridgeStateBefore.feesCollected))
+    assert(tokenConfig.feesCollected.eq(tokenConfigBefore.feesCollected))
+  })
+
+  it('Claim refund by user - fee accounts missing, amount still refunded', async () => {
+    let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
+    const depositId = bridgeState.depositNonce
+    const balanceBefore = (await getAccount(connection, itheum_token_user_ata))
+      .amount
+
+    await program.methods
+      .sendToLiquidity(new anchor.BN(100e9), destinationAddress)
+      .signers([user])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
+        vault: vault_ata,
+        whitelist: null,
+        feeOverride: null,
+        mintOfTokenSent: itheum_token_mint.publicKey,
+        authority: user.publicKey,
+        authorityTokenAccount: itheum_token_user_ata,
+        depositRecord: await depositRecordPda(),
+        userDeposit: null,
+        feeVault: feeVaultPda,
+        mintOfFeeTokenSent: null,
+        authorityFeeTokenAccount: null,
+        feeVaultAta: null,
+        feeVaultTokenAta: fee_vault_itheum_ata,
+        instructionsSysvar: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc()
+
+    const deposit = await program.account.depositRecord.fetch(
+      depositRecordByIdPda(depositId)
+    )
+    const feesCollectedBefore = (
+      await program.account.bridgeState.fetch(bridgeStatePda)
+    ).feesCollected
+
+    await new Promise((resolve) => setTimeout(resolve, 3000))
+
+    let signature = await program.methods
+      .claimRefund(depositId)
+      .signers([user])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
+        vault: vault_ata,
+        depositRecord: depositRecordByIdPda(depositId),
+        authority: user.publicKey,
+        mintOfTokenSent: itheum_token_mint.publicKey,
+        authorityTokenAccount: itheum_token_user_ata,
+        feeVault: null,
+        feeVaultTokenAta: null,
+        mintOfFeeTokenSent: null,
+        feeVaultAta: null,
+        authorityFeeTokenAccount: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc({commitment: 'confirmed'})
+
+    let [refundEvent] = await events(signature)
+    let record = await program.account.depositRecord.fetch(
+      depositRecordByIdPda(depositId)
+    )
+    let userAta = await getAccount(connection, itheum_token_user_ata)
+    bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
+
+    assert(record.status === 2)
+    assert(
+      userAta.amount === balanceBefore - BigInt(deposit.tokenFee.toString())
+    )
+    expect(refundEvent.name).to.equal('depositRefunded')
+    expect(refundEvent.data.amount.eq(deposit.amount)).to.be.true
+    expect(refundEvent.data.fee.toNumber()).to.equal(0)
+    expect(refundEvent.data.tokenFee.toNumber()).to.equal(0)
+    assert(bridgeState.feesCollected.eq(feesCollectedBefore))
+  })
+
+  it('Claim refund by user - already refunded (should fail)', async () => {
+    try {
+      await program.methods
+        .claimRefund(refundDepositId)
+        .signers([user])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
+          vault: vault_ata,
+          depositRecord: depositRecordByIdPda(refundDepositId),
+          authority: user.publicKey,
+          mintOfTokenSent: itheum_token_mint.publicKey,
+          authorityTokenAccount: itheum_token_user_ata,
+          feeVault: feeVaultPda,
+          feeVaultTokenAta: fee_vault_itheum_ata,
+          mintOfFeeTokenSent: null,
+          feeVaultAta: null,
+          authorityFeeTokenAccount: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6046)
+      expect((err as anchor.AnchorError).error.errorMessage).to.equal(
+        'Deposit is not pending'
+      )
+    }
+  })
+
+  it('Mark deposit processed by relayer', async () => {
+    let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
+    const depositId = bridgeState.depositNonce.subn(3)
+
+    await program.methods
+      .markDepositProcessed(depositId)
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        depositRecord: depositRecordByIdPda(depositId),
+        tokenConfig: itheumTokenConfigPda,
+        relayerCommittee: null,
+        authority: admin.publicKey,
+        instructionsSysvar: null,
+      })
+      .rpc()
+
+    let record = await program.account.depositRecord.fetch(
+      depositRecordByIdPda(depositId)
+    )
+
+    assert(record.status === 1)
+
+    try {
+      await program.methods
+        .claimRefund(depositId)
+        .signers([user])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
+          vault: vault_ata,
+          depositRecord: depositRecordByIdPda(depositId),
+          authority: user.publicKey,
+          mintOfTokenSent: itheum_token_mint.publicKey,
+          authorityTokenAccount: itheum_token_user_ata,
+          feeVault: null,
+          feeVaultTokenAta: null,
+          mintOfFeeTokenSent: null,
+          feeVaultAta: null,
+          authorityFeeTokenAccount: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6046)
+    }
+  })
+
+  it('Mark deposit processed by user (should fail)', async () => {
+    let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
+    const depositId = bridgeState.depositNonce.subn(4)
+
+    try {
+      await program.methods
+        .markDepositProcessed(depositId)
+        .signers([user])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          depositRecord: depositRecordByIdPda(depositId),
+          tokenConfig: itheumTokenConfigPda,
+          relayerCommittee: null,
+          authority: user.publicKey,
+          instructionsSysvar: null,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6004)
+    }
+  })
+
+  it('Claim refund by user - deposit in flight (should fail)', async () => {
+    let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
+    const depositId = bridgeState.depositNonce
+
+    await program.methods
+      .sendToLiquidity(new anchor.BN(100e9), destinationAddress)
+      .signers([user])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
+        vault: vault_ata,
+        whitelist: null,
+        feeOverride: null,
+        mintOfTokenSent: itheum_token_mint.publicKey,
+        authority: user.publicKey,
+        authorityTokenAccount: itheum_token_user_ata,
+        depositRecord: await depositRecordPda(),
+        userDeposit: null,
+        feeVault: feeVaultPda,
+        mintOfFeeTokenSent: null,
+        authorityFeeTokenAccount: null,
+        feeVaultAta: null,
+        feeVaultTokenAta: fee_vault_itheum_ata,
+        instructionsSysvar: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc()
+
+    await program.methods
+      .markDepositInFlight(depositId)
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        depositRecord: depositRecordByIdPda(depositId),
+        relayerCommittee: null,
+        authority: admin.publicKey,
+        instructionsSysvar: null,
+      })
+      .rpc()
+
+    let record = await program.account.depositRecord.fetch(
+      depositRecordByIdPda(depositId)
+    )
+
+    assert(record.status === 3)
+
+    await new Promise((resolve) => setTimeout(resolve, 3000))
+
+    try {
+      await program.methods
+        .claimRefund(depositId)
+        .signers([user])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
+          vault: vault_ata,
+          depositRecord: depositRecordByIdPda(depositId),
+          authority: user.publicKey,
+          mintOfTokenSent: itheum_token_mint.publicKey,
+          authorityTokenAccount: itheum_token_user_ata,
+          feeVault: feeVaultPda,
+          feeVaultTokenAta: fee_vault_itheum_ata,
+          mintOfFeeTokenSent: null,
+          feeVaultAta: null,
+          authorityFeeTokenAccount: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6046)
+      expect((err as anchor.AnchorError).error.errorMessage).to.equal(
+        'Deposit is not pending'
+      )
+    }
+
+    await program.methods
+      .markDepositProcessed(depositId)
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        depositRecord: depositRecordByIdPda(depositId),
+        tokenConfig: itheumTokenConfigPda,
+        relayerCommittee: null,
+        authority: admin.publicKey,
+        instructionsSysvar: null,
+      })
+      .rpc()
+
+    record = await program.account.depositRecord.fetch(
+      depositRecordByIdPda(depositId)
+    )
+
+    assert(record.status === 1)
+  })
+
+  it('Refund deposit by relayer', async () => {
+    let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
+    refundDepositId = bridgeState.depositNonce
+
+    await program.methods
+      .sendToLiquidity(new anchor.BN(100e9), destinationAddress)
+      .signers([user])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
+        vault: vault_ata,
+        whitelist: null,
+        feeOverride: null,
+        mintOfTokenSent: itheum_token_mint.publicKey,
+        authority: user.publicKey,
+        authorityTokenAccount: itheum_token_user_ata,
+        depositRecord: await depositRecordPda(),
+        userDeposit: null,
+        feeVault: feeVaultPda,
+        mintOfFeeTokenSent: null,
+        authorityFeeTokenAccount: null,
+        feeVaultAta: null,
+        feeVaultTokenAta: fee_vault_itheum_ata,
+        instructionsSysvar: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc()
+
+    let userAtaBefore = await getAccount(connection, itheum_token_user_ata)
+
+    await program.methods
+      .refundDeposit(refundDepositId, { blacklistedDestination: {} })
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
+        vault: vault_ata,
+        depositRecord: depositRecordByIdPda(refundDepositId),
+        relayerCommittee: null,
+        authority: admin.publicKey,
+        mintOfTokenSent: itheum_token_mint.publicKey,
+        depositorTokenAccount: itheum_token_user_ata,
+        instructionsSysvar: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc()
+
+    let record = await program.account.depositRecord.fetch(
+      depositRecordByIdPda(refundDepositId)
+    )
+    let userAta = await getAccount(connection, itheum_token_user_ata)
+
+    assert(record.status === 2)
+    assert(record.refundReason === 1)
+    assert(
+      userAta.amount - userAtaBefore.amount ===
+        BigInt(record.amount.toString())
+    )
+  })
+
+  it('Refund deposit by relayer - already refunded (should fail)', async () => {
+    try {
+      await program.methods
+        .refundDeposit(refundDepositId, { insufficientLiquidity: {} })
+        .signers([admin])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
+          vault: vault_ata,
+          depositRecord: depositRecordByIdPda(refundDepositId),
+          relayerCommittee: null,
+          authority: admin.publicKey,
+          mintOfTokenSent: itheum_token_mint.publicKey,
+          depositorTokenAccount: itheum_token_user_ata,
+          instructionsSysvar: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6046)
+      expect((err as anchor.AnchorError).error.errorMessage).to.equal(
+        'Deposit is not pending'
+      )
+    }
+  })
+
+  it('Refund deposit by relayer - committee signatures missing (should fail)', async () => {
+    await program.methods
+      .setRelayerCommittee([admin.publicKey, user.publicKey], 2)
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        relayerCommittee: relayerCommitteePda,
+        authority: admin.publicKey,
+        multisig: null,
+        proposal: null,
+        queuedChange: null,
+      })
+      .rpc()
+
+    let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
+    committeeDepositId = bridgeState.depositNonce
+
+    await program.methods
+      .sendToLiquidity(new anchor.BN(100e9), destinationAddress)
+      .signers([user])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: itheumTokenConfigPda,
+        vault: vault_ata,
+        whitelist: null,
+        feeOverride: null,
+        mintOfTokenSent: itheum_token_mint.publicKey,
+        authority: user.publicKey,
+        authorityTokenAccount: itheum_token_user_ata,
+        depositRecord: await depositRecordPda(),
+        userDeposit: null,
+        feeVault: feeVaultPda,
+        mintOfFeeTokenSent: null,
+        authorityFeeTokenAccount: null,
+        feeVaultAta: null,
+        feeVaultTokenAta: fee_vault_itheum_ata,
+        instructionsSysvar: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc()
+
+    try {
+      await program.methods
+        .refundDeposit(committeeDepositId, { blacklistedDestination: {} })
+        .preInstructions([
+          depositStatusAttestation(
+            admin,
+            committeeDepositId,
+            2,
+            1,
+            bridgeState.chainId
+          ),
+        ])
+        .signers([admin])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
+          vault: vault_ata,
+          depositRecord: depositRecordByIdPda(committeeDepositId),
+          relayerCommittee: relayerCommitteePda,
+          authority: admin.publicKey,
+          mintOfTokenSent: itheum_token_mint.publicKey,
+          depositorTokenAccount: itheum_token_user_ata,
+          instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6055)
+      expect((err as anchor.AnchorError).error.errorMessage).to.equal(
+        'Deposit status change not approved by enough relayers'
+      )
+    }
+
+    try {
+      await program.methods
+        .markDepositProcessed(committeeDepositId)
+        .signers([admin])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          depositRecord: depositRecordByIdPda(committeeDepositId),
+          tokenConfig: itheumTokenConfigPda,
+          relayerCommittee: relayerCommitteePda,
+          authority: admin.publicKey,
+          instructionsSysvar: null,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6055)
+    }
+  })
+
+  it('Mark deposit processed by relayer - approved by the committee', async () => {
+    let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
+
+    await program.methods
+      .markDepositProcessed(committeeDepositId)
+      .preInstructions([
+        depositStatusAttestation(
+          admin,
+          committeeDepositId,
+          1,
+          0,
+          bridgeState.chainId
+        ),
+        depositStatusAttestation(
+          user,
+          committeeDepositId,
+          1,
+          0,
+          bridgeState.chainId
+        ),
+      ])
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        depositRecord: depositRecordByIdPda(committeeDepositId),
+        tokenConfig: itheumTokenConfigPda,
+        relayerCommittee: relayerCommitteePda,
+        authority: admin.publicKey,
+        instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+      })
+      .rpc()
+
+    let record = await program.account.depositRecord.fetch(
+      depositRecordByIdPda(committeeDepositId)
+    )
+
+    assert(record.status === 1)
+
+    await program.methods
+      .setRelayerCommittee([], 0)
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        relayerCommittee: relayerCommitteePda,
+        authority: admin.publicKey,
+        multisig: null,
+        proposal: null,
+        queuedChange: null,
+      })
+      .rpc()
+
+    bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
+
+    assert(bridgeState.relayerCommitteeState === 0)
+  })
+
+  it('Set refund policy by admin - disable refunds', async () => {
+    await program.methods
+      .setRefundPolicy(new anchor.BN(0), false)
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        authority: admin.publicKey,
+        queuedChange: null,
+      })
+      .rpc()
+
+    let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
+
+    assert(bridgeState.refundTimeout.toNumber() === 0)
+    assert(bridgeState.refundFee === false)
+  })
+
+  it('Whitelist Token-2022 mint by admin - non-transferable (should fail)', async () => {
+    const mint = await createToken2022Mint(
+      ExtensionType.NonTransferable,
+      (m) =>
+        createInitializeNonTransferableMintInstruction(m, TOKEN_2022_PROGRAM_ID)
+    )
+
+    try {
+      await program.methods
+        .updateWhitelistedMint()
+        .signers([admin])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          tokenConfig: tokenConfigPda(mint),
+          authority: admin.publicKey,
+          vault: getAssociatedTokenAddressSync(
+            mint,
+            bridgeStatePda,
+            true,
+            TOKEN_2022_PROGRAM_ID
+          ),
+          mintOfTokenWhitelisted: mint,
+          multisig: null,
+          proposal: null,
+          queuedChange: null,
+          previousTokenConfig: null,
+          previousVault: null,
+          previousMint: null,
+          sweepTokenAccount: null,
+          previousTokenProgram: null,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6051)
+      expect((err as anchor.AnchorError).error.errorMessage).to.equal(
+        'Mint is non-transferable'
+      )
+    }
+  })
+
+  it('Add liquidity of a Token-2022 mint with transfer fee by admin', async () => {
+    // 1% transfer fee, withheld by the token program on every transfer
+    const mint = await createToken2022Mint(
+      ExtensionType.TransferFeeConfig,
+      (m) =>
+        createInitializeTransferFeeConfigInstruction(
+          m,
+          admin.publicKey,
+          admin.publicKey,
+          100,
+          BigInt(1000e9),
+          TOKEN_2022_PROGRAM_ID
+        )
+    )
+    const vault = getAssociatedTokenAddressSync(
+      mint,
+      bridgeStatePda,
+      true,
+      TOKEN_2022_PROGRAM_ID
+    )
+    const adminAta = getAssociatedTokenAddressSync(
+      mint,
+      admin.publicKey,
+      false,
+      TOKEN_2022_PROGRAM_ID
+    )
+
+    let tx = new Transaction()
+    tx.instructions = [
+      createAssociatedTokenAccountIdempotentInstruction(
+        provider.publicKey,
+        adminAta,
+        admin.publicKey,
+        mint,
+        TOKEN_2022_PROGRAM_ID
+      ),
+      createMintToInstruction(
+        mint,
+        adminAta,
+        admin.publicKey,
+        1000e9,
+        [],
+        TOKEN_2022_PROGRAM_ID
+      ),
+    ]
+    await provider.sendAndConfirm(tx, [admin])
+
+    await program.methods
+      .updateWhitelistedMint()
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: tokenConfigPda(mint),
+        authority: admin.publicKey,
+        vault,
+        mintOfTokenWhitelisted: mint,
+        multisig: null,
+        proposal: null,
+        queuedChange: null,
+        previousTokenConfig: null,
+        previousVault: null,
+        previousMint: null,
+        sweepTokenAccount: null,
+        previousTokenProgram: null,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc()
+
+    await program.methods
+      .addLiquidity(new anchor.BN(100e9))
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        tokenConfig: tokenConfigPda(mint),
+        vault,
+        mintOfTokenSent: mint,
+        authority: admin.publicKey,
+        authorityTokenAccount: adminAta,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc()
+
+    let tokenConfig = await program.account.tokenConfig.fetch(
+      tokenConfigPda(mint)
+    )
+    let vaultAccount = await getAccount(
+      connection,
+      vault,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    )
+
+    // the vault is only credited what arrives after the transfer fee
+    assert(tokenConfig.vaultAmount.toNumber() === 99e9)
+    assert(Number(vaultAccount.amount) === 99e9)
+  })
+
+  it('Create multisig by admin', async () => {
+    await program.methods
+      .createMultisig([admin.publicKey, user.publicKey], 2)
+      .signers([admin])
+      .accounts({
+        multisig: multisigPda,
+        bridgeState: bridgeStatePda,
+        authority: admin.publicKey,
+      })
+      .rpc()
+
+    let multisig = await program.account.multisig.fetch(multisigPda)
+
+    assert(multisig.threshold === 2)
+    assert(multisig.signers.length === 2)
+  })
+
+  it('Change relayer by admin without proposal (should fail)', async () => {
+    try {
+      await program.methods
+        .updateRelayer(user2.publicKey)
+        .signers([admin])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          authority: admin.publicKey,
+          multisig: null,
+          proposal: null,
+          queuedChange: null,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6013)
+      expect((err as anchor.AnchorError).error.errorMessage).to.equal(
+        'Multisig proposal required'
+      )
+    }
+  })
+
+  it('Change relayer through multisig proposal', async () => {
+    await program.methods
+      .createProposal({updateRelayer: {relayerPubkey: user2.publicKey}})
+      .signers([admin])
+      .accounts({
+        proposal: proposalPda(0),
+        multisig: multisigPda,
+        authority: admin.publicKey,
+      })
+      .rpc()
+
+    try {
+      await program.methods
+        .updateRelayer(user2.publicKey)
+        .signers([admin])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          authority: admin.publicKey,
+          multisig: multisigPda,
+          proposal: proposalPda(0),
+          queuedChange: null,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6016)
+      expect((err as anchor.AnchorError).error.errorMessage).to.equal(
+        'Proposal not approved'
+      )
+    }
+
+    await program.methods
+      .approveProposal()
+      .signers([user])
+      .accounts({
+        proposal: proposalPda(0),
+        multisig: multisigPda,
+        authority: user.publicKey,
+      })
+      .rpc()
+
+    await program.methods
+      .updateRelayer(user2.publicKey)
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        authority: admin.publicKey,
+        multisig: multisigPda,
+        proposal: proposalPda(0),
+        queuedChange: null,
+      })
+      .rpc()
+
+    let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
+    let proposal = await program.account.proposal.fetch(proposalPda(0))
+
+    assert(bridgeState.relayerPubkey.equals(user2.publicKey))
+    assert(proposal.executed)
+  })
+
+  it('Set fee amount through timelock', async () => {
+    await program.methods
+      .setTimelockDelay(new anchor.BN(3600))
+      .signers([admin])
+      .accounts({
+        bridgeState: bridgeStatePda,
+        authority: admin.publicKey,
+        queuedChange: null,
+      })
+      .rpc()
+
+    try {
+      await program.methods
+        .setFeeAmount(new anchor.BN(0.2e9))
+        .signers([admin])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
+          authority: admin.publicKey,
+          queuedChange: null,
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6018)
+      expect((err as anchor.AnchorError).error.errorMessage).to.equal(
+        'Config change not queued'
+      )
+    }
+
+    await program.methods
+      .queueConfigChange({
+        feeAmount: {
+          mint: itheum_token_mint.publicKey,
+          feeAmount: new anchor.BN(0.2e9),
+        },
+      })
+      .signers([admin])
+      .accounts({
+        queuedChange: queuedChangePda(0, itheum_token_mint.publicKey),
+        bridgeState: bridgeStatePda,
+        authority: admin.publicKey,
+      })
+      .rpc()
+
+    try {
+      await program.methods
+        .setFeeAmount(new anchor.BN(0.3e9))
+        .signers([admin])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
+          authority: admin.publicKey,
+          queuedChange: queuedChangePda(0, itheum_token_mint.publicKey),
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6019)
+      expect((err as anchor.AnchorError).error.errorMessage).to.equal(
+        'Config change mismatch'
+      )
+    }
+
+    try {
+      await program.methods
+        .setFeeAmount(new anchor.BN(0.2e9))
+        .signers([admin])
+        .accounts({
+          bridgeState: bridgeStatePda,
+          tokenConfig: itheumTokenConfigPda,
+          authority: admin.publicKey,
+          queuedChange: queuedChangePda(0, itheum_token_mint.publicKey),
+        })
+        .rpc()
+      assert(false, 'Should have thrown error')
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.number).to.equal(6020)
+      expect((err as anchor.AnchorError).error.errorMessage).to.equal(
+        'Timelock not expired'
+      )
+    }
+
+    await program.methods
+      .cancelConfigChange()
+      .signers([admin])
+      .accounts({
+        queuedChange: queuedChangePda(0, itheum_token_mint.publicKey),
+        bridgeState: bridgeStatePda,
+        authority: admin.publicKey,
+      })
+      .rpc()
+
+    let queuedChange = await program.account.queuedConfigChange.fetchNullable(
+      queuedChangePda(0, itheum_token_mint.publicKey)
+    )
+    let bridgeState = await program.account.bridgeState.fetch(bridgeStatePda)
+    let tokenConfig = await program.account.tokenConfig.fetch(
+      itheumTokenConfigPda
+    )
 
-    assert(bridge.vaultAmount.toNumber() == 1200e9)
+    assert(queuedChange === null)
+    assert(bridgeState.timelockDelay.toNumber() === 3600)
+    assert(tokenConfig.feeAmount.toNumber() === 0.1e9)
   })
 })